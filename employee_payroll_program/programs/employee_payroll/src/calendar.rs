@@ -0,0 +1,41 @@
+//! Civil calendar helpers for year-to-date accounting. Timestamps are unix
+//! seconds and dates are proleptic Gregorian in UTC.
+
+/// `(year, month, day)` of a unix timestamp, months and days from 1.
+pub fn civil_date(unix_timestamp: i64) -> (i32, u32, u32) {
+	// Howard Hinnant's `civil_from_days`
+	let z = unix_timestamp.div_euclid(86_400) + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z - era * 146_097;
+	let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+	(year as i32, month as u32, day as u32)
+}
+
+/// Unix timestamp of midnight UTC on the given date.
+pub fn civil_to_timestamp(year: i32, month: u32, day: u32) -> i64 {
+	// Howard Hinnant's `days_from_civil`
+	let year = year as i64 - if month <= 2 { 1 } else { 0 };
+	let era = year.div_euclid(400);
+	let yoe = year - era * 400;
+	let mp = (month as i64 + 9) % 12;
+	let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+	(era * 146_097 + doe - 719_468) * 86_400
+}
+
+/// Start of the fiscal year containing `unix_timestamp`, for a fiscal year
+/// beginning on the first of `start_month` (1-12, 0 is read as January).
+pub fn fiscal_year_start(unix_timestamp: i64, start_month: u8) -> i64 {
+	let start_month = start_month.clamp(1, 12) as u32;
+	let (year, month, _) = civil_date(unix_timestamp);
+	let start_year = if month >= start_month { year } else { year - 1 };
+
+	civil_to_timestamp(start_year, start_month, 1)
+}
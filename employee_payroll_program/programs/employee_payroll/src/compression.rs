@@ -0,0 +1,152 @@
+//! Raw CPI bindings for the SPL account-compression program, used to keep
+//! closed work sessions as leaves of a per-employer concurrent Merkle tree.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::pubkey;
+
+use crate::state::{PaymentRecord, WorkSession};
+
+pub const SPL_ACCOUNT_COMPRESSION_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+pub const SPL_NOOP_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Leaf schema of a work session stored in an employer's session tree.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct CompressedWorkSession {
+	pub employer: Pubkey,
+	pub employee: Pubkey,
+	pub session_id: u64,
+	pub check_in_time: i64,
+	pub check_out_time: i64,
+	pub duration: u64,
+	pub is_paid: bool,
+	/// Batch that paid the session, default while unpaid.
+	pub batch: Pubkey,
+	pub hourly_rate: u64,
+}
+
+impl CompressedWorkSession {
+	pub fn from_session(employer: Pubkey, work_session: &WorkSession) -> Self {
+		Self {
+			employer,
+			employee: work_session.employee,
+			session_id: work_session.session_id,
+			check_in_time: work_session.check_in_time,
+			check_out_time: work_session.check_out_time,
+			duration: work_session.duration,
+			is_paid: work_session.is_paid,
+			batch: work_session.batch,
+			hourly_rate: work_session.hourly_rate,
+		}
+	}
+
+	/// The live session the leaf was made from, as far as the leaf records
+	/// it, for `pay_work_session` to pay.
+	pub fn to_session(&self) -> WorkSession {
+		WorkSession {
+			employee: self.employee,
+			check_in_time: self.check_in_time,
+			check_out_time: self.check_out_time,
+			duration: self.duration,
+			is_paid: self.is_paid,
+			authority: Pubkey::default(),
+			session_id: self.session_id,
+			batch: self.batch,
+			rent_payer: Pubkey::default(),
+			version: WorkSession::VERSION,
+			exchange_rate: 0,
+			project_id: 0,
+			credited: 0,
+			is_approved: false,
+			payment: PaymentRecord::default(),
+			hourly_rate: self.hourly_rate,
+			_reserved: [0; 7],
+		}
+	}
+
+	pub fn leaf(&self) -> [u8; 32] {
+		solana_keccak_hasher::hashv(&[
+			self.employer.as_ref(),
+			self.employee.as_ref(),
+			&self.session_id.to_le_bytes(),
+			&self.check_in_time.to_le_bytes(),
+			&self.check_out_time.to_le_bytes(),
+			&self.duration.to_le_bytes(),
+			&[self.is_paid as u8],
+			self.batch.as_ref(),
+			&self.hourly_rate.to_le_bytes(),
+		])
+		.to_bytes()
+	}
+}
+
+/// Accounts shared by every call into the compression program. The tree
+/// authority is the employer's `[b"session_tree", employer]` PDA.
+pub struct SessionTreeCpi<'a, 'info> {
+	pub compression_program: AccountInfo<'info>,
+	pub merkle_tree: AccountInfo<'info>,
+	pub tree_authority: AccountInfo<'info>,
+	pub noop: AccountInfo<'info>,
+	pub signer_seeds: &'a [&'a [&'a [u8]]],
+}
+
+impl<'a, 'info> SessionTreeCpi<'a, 'info> {
+	pub fn init_empty_merkle_tree(&self, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+		let mut args = max_depth.to_le_bytes().to_vec();
+		args.extend_from_slice(&max_buffer_size.to_le_bytes());
+		self.invoke("init_empty_merkle_tree", args, &[])
+	}
+
+	pub fn append(&self, leaf: [u8; 32]) -> Result<()> {
+		self.invoke("append", leaf.to_vec(), &[])
+	}
+
+	/// Verifies `proof` against `root` and swaps `previous_leaf` at `index`
+	/// for `new_leaf` in one step.
+	pub fn replace_leaf(
+		&self,
+		proof: &[AccountInfo<'info>],
+		root: [u8; 32],
+		previous_leaf: [u8; 32],
+		new_leaf: [u8; 32],
+		index: u32,
+	) -> Result<()> {
+		let mut args = root.to_vec();
+		args.extend_from_slice(&previous_leaf);
+		args.extend_from_slice(&new_leaf);
+		args.extend_from_slice(&index.to_le_bytes());
+		self.invoke("replace_leaf", args, proof)
+	}
+
+	fn invoke(&self, name: &str, args: Vec<u8>, proof: &[AccountInfo<'info>]) -> Result<()> {
+		let mut data = hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec();
+		data.extend_from_slice(&args);
+
+		let mut accounts = vec![
+			AccountMeta::new(self.merkle_tree.key(), false),
+			AccountMeta::new_readonly(self.tree_authority.key(), true),
+			AccountMeta::new_readonly(self.noop.key(), false),
+		];
+		accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+
+		let mut account_infos = vec![
+			self.merkle_tree.clone(),
+			self.tree_authority.clone(),
+			self.noop.clone(),
+		];
+		account_infos.extend_from_slice(proof);
+
+		invoke_signed(
+			&Instruction {
+				program_id: self.compression_program.key(),
+				accounts,
+				data,
+			},
+			&account_infos,
+			self.signer_seeds,
+		)
+		.map_err(Into::into)
+	}
+}
@@ -2,3 +2,73 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+/// Max UTF-8 bytes of `Employee::name`.
+#[constant]
+pub const MAX_NAME_LEN: usize = 64;
+
+/// Max UTF-8 bytes of `Employee::position`.
+#[constant]
+pub const MAX_POSITION_LEN: usize = 64;
+
+/// Max UTF-8 bytes of `DeductionRule::name`.
+#[constant]
+pub const MAX_DEDUCTION_NAME_LEN: usize = 32;
+
+/// Max UTF-8 bytes of `Department::name`.
+#[constant]
+pub const MAX_DEPARTMENT_NAME_LEN: usize = 32;
+
+/// Max UTF-8 bytes of `Project::name`.
+#[constant]
+pub const MAX_PROJECT_NAME_LEN: usize = 32;
+
+#[constant]
+pub const MAX_TAX_BRACKETS: usize = 8;
+
+/// Max deduction rules attached to one employee.
+#[constant]
+pub const MAX_EMPLOYEE_DEDUCTIONS: usize = 4;
+
+/// Max wallets an employee's payouts can be split across, besides the
+/// payout wallet.
+#[constant]
+pub const MAX_PAYOUT_SPLITS: usize = 4;
+
+/// Seconds of a single work session past which time counts as overtime.
+#[constant]
+pub const OVERTIME_THRESHOLD: u64 = 8 * 3_600;
+
+#[constant]
+pub const DEFAULT_RATE_INCREASE_THRESHOLD_BPS: u16 = 1_000;
+
+#[constant]
+pub const DEFAULT_RATE_INCREASE_DELAY: i64 = 2 * 86_400;
+
+/// Decimals of `hourly_rate` when it is priced in a reference currency, so
+/// a rate of 25_000_000 is 25 units of that currency per hour.
+#[constant]
+pub const REFERENCE_RATE_DECIMALS: u32 = 6;
+
+#[constant]
+pub const DEFAULT_MAX_PRICE_AGE: i64 = 60;
+
+#[constant]
+pub const DEFAULT_MAX_PRICE_CONF_BPS: u16 = 200;
+
+/// Max lamports an employer may tip the caller of `crank_payroll` per session.
+#[constant]
+pub const MAX_CRANK_TIP: u64 = 1_000_000;
+
+/// Longest `Employer::reversal_window` an owner may set.
+#[constant]
+pub const MAX_REVERSAL_WINDOW: i64 = 7 * 86_400;
+
+/// Max UTF-8 bytes of the reason recorded with a clawback.
+#[constant]
+pub const MAX_CLAWBACK_REASON_LEN: usize = 128;
+
+/// Credits a `ClaimableBalance` holds apart while they may be clawed back.
+/// Further credits inside the reversal window wait for the latest of them.
+#[constant]
+pub const MAX_CREDIT_LOCKS: usize = 8;
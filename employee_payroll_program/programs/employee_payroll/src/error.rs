@@ -2,3 +2,243 @@
 // Editing this file directly is not recommended as it may be overwritten.
 //
 // Docs: https://docs.codigo.ai/c%C3%B3digo-interface-description-language/specification#errors
+
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum EmployeePayrollError {
+	#[msg("Signer is not allowed to perform this action")]
+	Unauthorized,
+	#[msg("Payout wallet cannot be the default pubkey")]
+	InvalidPayoutWallet,
+	#[msg("No payout wallet change is pending for this employee")]
+	NoPendingPayoutWallet,
+	#[msg("Signer does not match the pending payout wallet")]
+	PayoutWalletMismatch,
+	#[msg("Payroll program is paused")]
+	ProgramPaused,
+	#[msg("Employer is paused")]
+	EmployerPaused,
+	#[msg("Employer is not paused")]
+	EmployerNotPaused,
+	#[msg("Payroll program is not paused")]
+	ProgramNotPaused,
+	#[msg("Employee is not active")]
+	EmployeeInactive,
+	#[msg("Work session has already been paid")]
+	SessionAlreadyPaid,
+	#[msg("Vault balance is insufficient")]
+	InsufficientVaultBalance,
+	#[msg("Vault outflow exceeds the spending limit without owner co-signature")]
+	SpendingLimitExceeded,
+	#[msg("Arithmetic overflow")]
+	ArithmeticOverflow,
+	#[msg("Work session has not been checked out")]
+	SessionNotCheckedOut,
+	#[msg("Work session has already been checked out")]
+	SessionAlreadyCheckedOut,
+	#[msg("Timestamp is invalid")]
+	InvalidTimestamp,
+	#[msg("Pay period duration must be positive when a period limit is set")]
+	InvalidPeriodDuration,
+	#[msg("Payroll batch has already been processed")]
+	BatchAlreadyProcessed,
+	#[msg("No hourly rate change is pending for this employee")]
+	NoPendingRateChange,
+	#[msg("Pending hourly rate change is still timelocked")]
+	RateChangeTimelocked,
+	#[msg("Rate change delay cannot be negative")]
+	InvalidRateChangeDelay,
+	#[msg("Work session has not been paid")]
+	SessionNotPaid,
+	#[msg("Payroll batch has not been completed")]
+	BatchNotCompleted,
+	#[msg("Payroll batch does not match the work session")]
+	BatchMismatch,
+	#[msg("Rent payer does not match the work session")]
+	RentPayerMismatch,
+	#[msg("Remaining accounts must be (work_session, rent_payer) pairs")]
+	InvalidRemainingAccounts,
+	#[msg("Session tree is already initialized")]
+	SessionTreeAlreadyInitialized,
+	#[msg("Session tree is not initialized")]
+	SessionTreeNotInitialized,
+	#[msg("Compressed session does not belong to this employer and employee")]
+	CompressedSessionMismatch,
+	#[msg("Employee name must not be blank")]
+	EmptyName,
+	#[msg("Employee name exceeds 64 bytes")]
+	NameTooLong,
+	#[msg("Employee position exceeds 64 bytes")]
+	PositionTooLong,
+	#[msg("Employee name and position must not contain control characters")]
+	InvalidProfileCharacters,
+	#[msg("Account is already at the current version")]
+	AccountAlreadyMigrated,
+	#[msg("Account layout cannot be migrated")]
+	UnsupportedAccountLayout,
+	#[msg("Deduction rule is invalid")]
+	InvalidDeductionRule,
+	#[msg("Employee already has the maximum number of deduction rules")]
+	TooManyDeductionRules,
+	#[msg("Deduction rule is already attached to this employee")]
+	DeductionRuleAlreadyAttached,
+	#[msg("Deduction rule is not attached to this employee")]
+	DeductionRuleNotAttached,
+	#[msg("Deduction accounts do not match the employee's deduction rules")]
+	DeductionAccountsMismatch,
+	#[msg("Fiscal year must start in a month from 1 to 12")]
+	InvalidFiscalYearStart,
+	#[msg("Instruction does not apply to the employee's compensation type")]
+	CompensationMismatch,
+	#[msg("Fixed pay requires the employer to set a pay period")]
+	PayPeriodNotSet,
+	#[msg("Fixed pay for this pay period has already been processed")]
+	SalaryAlreadyPaid,
+	#[msg("Pay increases above the threshold must be co-signed by the rate approver")]
+	RateApproverRequired,
+	#[msg("Pay item amount cannot be zero")]
+	InvalidPayItemAmount,
+	#[msg("Pay item has already been paid")]
+	PayItemAlreadyPaid,
+	#[msg("Pay item does not belong to this employee and payroll batch")]
+	PayItemMismatch,
+	#[msg("Expense amount cannot be zero")]
+	InvalidExpenseAmount,
+	#[msg("Expense has already been reviewed")]
+	ExpenseAlreadyReviewed,
+	#[msg("Expense has not been approved for payment")]
+	ExpenseNotApproved,
+	#[msg("Expense does not belong to this employee")]
+	ExpenseMismatch,
+	#[msg("Pay stream rate must be greater than zero")]
+	InvalidStreamRate,
+	#[msg("Pay stream is paused")]
+	StreamPaused,
+	#[msg("Pay stream is not paused")]
+	StreamNotPaused,
+	#[msg("Pay stream has been cancelled")]
+	StreamCancelled,
+	#[msg("Nothing has accrued on the pay stream")]
+	NothingToWithdraw,
+	#[msg("Advance limit must be at most 10000 basis points")]
+	InvalidAdvanceLimit,
+	#[msg("Employer does not allow advances")]
+	AdvancesDisabled,
+	#[msg("Advance amount must be greater than zero")]
+	InvalidAdvanceAmount,
+	#[msg("Advance exceeds the allowed share of earned, unpaid wages")]
+	AdvanceLimitExceeded,
+	#[msg("Too many payout splits")]
+	TooManyPayoutSplits,
+	#[msg("Payout splits need distinct wallets other than the payout wallet and shares adding up to at most 10000 basis points")]
+	InvalidPayoutSplits,
+	#[msg("Payout split wallets do not match the employee's payout splits")]
+	PayoutSplitMismatch,
+	#[msg("Employer pays by claim; pass the employee's claimable balance")]
+	ClaimableBalanceRequired,
+	#[msg("Nothing to claim")]
+	NothingToClaim,
+	#[msg("Max price age must be positive and confidence at most 10000 basis points")]
+	InvalidPricePolicy,
+	#[msg("Employee's rate is priced in a reference currency; pass its price feed")]
+	PriceFeedRequired,
+	#[msg("Price feed does not match the employee's rate currency")]
+	PriceFeedMismatch,
+	#[msg("Price feed is not a verified price update")]
+	InvalidPriceFeed,
+	#[msg("Price is older than the employer allows")]
+	StalePrice,
+	#[msg("Price confidence interval is wider than the employer allows")]
+	PriceConfidenceTooWide,
+	#[msg("Not supported for rates priced in a reference currency")]
+	PricedRateUnsupported,
+	#[msg("Leave accrual must be at most 10000 basis points")]
+	InvalidPtoAccrual,
+	#[msg("Leave duration must be greater than zero")]
+	InvalidLeaveDuration,
+	#[msg("Not enough paid leave accrued")]
+	InsufficientLeaveBalance,
+	#[msg("Leave request has already been reviewed")]
+	LeaveAlreadyReviewed,
+	#[msg("Department id must be nonzero")]
+	InvalidDepartment,
+	#[msg("Employee belongs to a department; pass it")]
+	DepartmentRequired,
+	#[msg("Department does not match the employee's department")]
+	DepartmentMismatch,
+	#[msg("Payout exceeds the department's budget for the pay period")]
+	DepartmentBudgetExceeded,
+	#[msg("Project id must be nonzero")]
+	InvalidProject,
+	#[msg("Project is not accepting new work sessions")]
+	ProjectInactive,
+	#[msg("Session was worked on a project; pass it")]
+	ProjectRequired,
+	#[msg("Project does not match the session's project")]
+	ProjectMismatch,
+	#[msg("Sessions worked on a project must be paid before they are compressed")]
+	ProjectSessionUnpaid,
+	#[msg("Pay schedule anchor does not fit the pay frequency")]
+	InvalidPaySchedule,
+	#[msg("Employer has no pay schedule")]
+	PayScheduleNotSet,
+	#[msg("Next pay period has not started yet")]
+	PayPeriodNotStarted,
+	#[msg("Session was not worked within the batch's pay period")]
+	SessionOutsidePayPeriod,
+	#[msg("Pay date must not be before the end of the pay period")]
+	InvalidPayDate,
+	#[msg("Batch has not been approved for cranking")]
+	BatchNotApproved,
+	#[msg("Batch's pay date has not been reached")]
+	PayDateNotReached,
+	#[msg("Crank tip exceeds the maximum")]
+	CrankTipTooHigh,
+	#[msg("Batch has been cancelled")]
+	BatchCancelled,
+	#[msg("Batch has already paid out and cannot be cancelled")]
+	BatchHasPayments,
+	#[msg("Reversal window exceeds the maximum")]
+	InvalidReversalWindow,
+	#[msg("Reversal window for the batch has closed")]
+	ReversalWindowClosed,
+	#[msg("Session has no credit left to claw back")]
+	NothingToClawBack,
+	#[msg("Credit has already been claimed")]
+	CreditAlreadyClaimed,
+	#[msg("Clawback reason is too long")]
+	ReasonTooLong,
+	#[msg("Employee has been terminated")]
+	EmployeeTerminated,
+	#[msg("Employee has not been terminated")]
+	EmployeeNotTerminated,
+	#[msg("Final settlements can only be created by terminating the employee")]
+	InvalidPayItemKind,
+	#[msg("Final settlement has not been paid")]
+	SettlementNotPaid,
+	#[msg("Claimable balance still holds unclaimed pay")]
+	ClaimableBalanceNotEmpty,
+	#[msg("Batch was not opened for a pay period")]
+	BatchHasNoPayPeriod,
+	#[msg("Work session has already been approved")]
+	SessionAlreadyApproved,
+	#[msg("Work session has not been approved")]
+	SessionNotApproved,
+	#[msg("Pay items and expenses do not match those paid with the session")]
+	PaymentAccountsMismatch,
+	#[msg("Session's credit can still be clawed back")]
+	CreditStillReversible,
+	#[msg("Price update is for another feed than the employee's rate currency")]
+	PriceFeedIdMismatch,
+	#[msg("Employee has no approved leave left to pay")]
+	NoUnpaidLeave,
+	#[msg("Employee still has work sessions to be paid")]
+	SessionsStillUnpaid,
+	#[msg("Withdraw amount must be greater than zero and at most what has accrued")]
+	InvalidWithdrawAmount,
+	#[msg("Pay schedule cannot restart before the next pay period once one was opened")]
+	PayScheduleAnchorInPast,
+	#[msg("Rate approver must be someone other than the employer owner")]
+	RateApproverIsAuthority,
+}
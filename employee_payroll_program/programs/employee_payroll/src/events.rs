@@ -0,0 +1,309 @@
+use anchor_lang::prelude::*;
+
+use crate::compression::CompressedWorkSession;
+use crate::state::{ExpenseCategory, PayItemKind, PayoutSplit};
+
+#[event]
+pub struct PayoutWalletProposed {
+	pub employee: Pubkey,
+	pub current_payout_wallet: Pubkey,
+	pub proposed_payout_wallet: Pubkey,
+}
+
+#[event]
+pub struct PayoutWalletChanged {
+	pub employee: Pubkey,
+	pub previous_payout_wallet: Pubkey,
+	pub new_payout_wallet: Pubkey,
+}
+
+#[event]
+pub struct EmployerPaused {
+	pub employer: Pubkey,
+	pub paused_by: Pubkey,
+	pub by_guardian: bool,
+}
+
+#[event]
+pub struct EmployerUnpaused {
+	pub employer: Pubkey,
+	pub unpaused_by: Pubkey,
+}
+
+#[event]
+pub struct ProgramPaused {
+	pub guardian: Pubkey,
+}
+
+#[event]
+pub struct ProgramUnpaused {
+	pub guardian: Pubkey,
+}
+
+#[event]
+pub struct SpendingLimitOverridden {
+	pub employer: Pubkey,
+	pub amount: u64,
+	pub owner: Pubkey,
+}
+
+#[event]
+pub struct RateChangeQueued {
+	pub employee: Pubkey,
+	pub current_hourly_rate: u64,
+	pub pending_hourly_rate: u64,
+	pub effective_at: i64,
+}
+
+#[event]
+pub struct RateChangeApplied {
+	pub employee: Pubkey,
+	pub previous_hourly_rate: u64,
+	pub new_hourly_rate: u64,
+}
+
+#[event]
+pub struct RateChangeVetoed {
+	pub employee: Pubkey,
+	pub vetoed_hourly_rate: u64,
+	pub approver: Pubkey,
+}
+
+#[event]
+pub struct WorkSessionArchived {
+	pub employee: Pubkey,
+	pub session_id: u64,
+	pub check_in_time: i64,
+	pub check_out_time: i64,
+	pub batch: Pubkey,
+}
+
+#[event]
+pub struct DeductionWithheld {
+	pub employee: Pubkey,
+	pub deduction_rule: Pubkey,
+	pub remittance_vault: Pubkey,
+	pub amount: u64,
+}
+
+#[event]
+pub struct WorkSessionCompressed {
+	pub merkle_tree: Pubkey,
+	pub leaf_index: u32,
+	pub session: CompressedWorkSession,
+}
+
+#[event]
+pub struct CompressedSessionPaid {
+	pub merkle_tree: Pubkey,
+	pub leaf_index: u32,
+	pub session: CompressedWorkSession,
+	pub amount: u64,
+	pub exchange_rate: u64,
+}
+
+#[event]
+pub struct PayItemPaid {
+	pub employee: Pubkey,
+	pub pay_item: Pubkey,
+	pub kind: PayItemKind,
+	pub amount: i64,
+	pub batch: Pubkey,
+}
+
+#[event]
+pub struct ExpenseSubmitted {
+	pub employee: Pubkey,
+	pub expense: Pubkey,
+	pub category: ExpenseCategory,
+	pub amount: u64,
+}
+
+#[event]
+pub struct ExpenseReviewed {
+	pub employee: Pubkey,
+	pub expense: Pubkey,
+	pub approved: bool,
+	pub reviewer: Pubkey,
+}
+
+#[event]
+pub struct ExpenseReimbursed {
+	pub employee: Pubkey,
+	pub expense: Pubkey,
+	pub amount: u64,
+	pub batch: Pubkey,
+}
+
+#[event]
+pub struct PayStreamUpdated {
+	pub employee: Pubkey,
+	pub pay_stream: Pubkey,
+	pub rate_per_second: u64,
+	pub is_paused: bool,
+	pub is_cancelled: bool,
+	pub accrued: u64,
+}
+
+#[event]
+pub struct StreamWithdrawn {
+	pub employee: Pubkey,
+	pub pay_stream: Pubkey,
+	pub amount: u64,
+	pub withheld: u64,
+}
+
+#[event]
+pub struct AdvanceIssued {
+	pub employee: Pubkey,
+	pub amount: u64,
+	pub outstanding_advance: u64,
+}
+
+#[event]
+pub struct AdvanceRecovered {
+	pub employee: Pubkey,
+	pub amount: u64,
+	pub outstanding_advance: u64,
+}
+
+#[event]
+pub struct PayoutSplitsChanged {
+	pub employee: Pubkey,
+	pub payout_splits: Vec<PayoutSplit>,
+}
+
+#[event]
+pub struct ClaimableBalanceCredited {
+	pub employee: Pubkey,
+	pub claimable_balance: Pubkey,
+	pub amount: u64,
+	pub balance: u64,
+}
+
+#[event]
+pub struct BalanceClaimed {
+	pub employee: Pubkey,
+	pub claimable_balance: Pubkey,
+	pub amount: u64,
+}
+
+#[event]
+pub struct RateCurrencyChanged {
+	pub employee: Pubkey,
+	pub rate_price_feed: Pubkey,
+	pub rate_feed_id: [u8; 32],
+	pub hourly_rate: u64,
+}
+
+#[event]
+pub struct LeaveRequested {
+	pub employee: Pubkey,
+	pub leave_request: Pubkey,
+	pub start_time: i64,
+	pub duration: u64,
+}
+
+#[event]
+pub struct LeaveApproved {
+	pub employee: Pubkey,
+	pub leave_request: Pubkey,
+	pub duration: u64,
+	pub reviewer: Pubkey,
+	pub pto_balance: u64,
+}
+
+#[event]
+pub struct LeavePaid {
+	pub employee: Pubkey,
+	pub duration: u64,
+	pub batch: Pubkey,
+}
+
+#[event]
+pub struct DepartmentBudgetOverridden {
+	pub department: Pubkey,
+	pub amount: u64,
+	pub owner: Pubkey,
+}
+
+#[event]
+pub struct DepartmentAssigned {
+	pub employee: Pubkey,
+	pub previous_department_id: u64,
+	pub department_id: u64,
+}
+
+#[event]
+pub struct PayPeriodOpened {
+	pub employer: Pubkey,
+	pub payroll_batch: Pubkey,
+	pub batch_id: u64,
+	pub period_start: i64,
+	pub period_end: i64,
+}
+
+#[event]
+pub struct PayrollBatchApproved {
+	pub payroll_batch: Pubkey,
+	pub pay_date: i64,
+	pub approved_by: Pubkey,
+}
+
+#[event]
+pub struct PayrollCranked {
+	pub payroll_batch: Pubkey,
+	pub work_session: Pubkey,
+	pub cranker: Pubkey,
+	pub tip: u64,
+}
+
+#[event]
+pub struct PayrollBatchCancelled {
+	pub payroll_batch: Pubkey,
+	pub cancelled_by: Pubkey,
+	pub released_pay_items: u32,
+	pub released_work_sessions: u32,
+}
+
+#[event]
+pub struct CreditClawedBack {
+	pub payroll_batch: Pubkey,
+	pub employee: Pubkey,
+	pub work_session: Pubkey,
+	pub amount: u64,
+	/// Gross pay of the reversed payment.
+	pub gross: u64,
+	/// Withholding already sent to remittance vaults, which stays there and
+	/// is netted off the employee's next withholding.
+	pub withheld: u64,
+	pub reason: String,
+}
+
+#[event]
+pub struct EmployeeTerminated {
+	pub employee: Pubkey,
+	pub terminated_by: Pubkey,
+	pub final_settlement: Pubkey,
+	/// Negative when salary already paid for the rest of the pay period is
+	/// more than what is owed.
+	pub amount: i64,
+	/// Seconds of leave paid out, both approved and remaining balance.
+	pub leave_paid: u64,
+	pub timestamp: i64,
+}
+
+#[event]
+pub struct EmployeeClosed {
+	pub employee: Pubkey,
+	/// Advance and negative adjustments never recovered from payroll.
+	pub written_off: u64,
+}
+
+#[event]
+pub struct WorkSessionApproved {
+	pub employee: Pubkey,
+	pub work_session: Pubkey,
+	pub duration: u64,
+	pub approver: Pubkey,
+}
@@ -0,0 +1,55 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct AcceptPayoutWallet<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub employee: Account<'info, Employee>,
+	}
+
+/// Proposed payout wallet signs to accept becoming the payout destination
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The pending payout wallet
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+pub fn handler(
+	ctx: Context<AcceptPayoutWallet>,
+	_employee_wallet: Pubkey,
+) -> Result<()> {
+	let employee = &mut ctx.accounts.employee;
+
+	let pending = employee
+		.pending_payout_wallet
+		.ok_or(EmployeePayrollError::NoPendingPayoutWallet)?;
+	require_keys_eq!(pending, ctx.accounts.authority.key(), EmployeePayrollError::PayoutWalletMismatch);
+
+	let previous_payout_wallet = employee.payout_wallet;
+	employee.payout_wallet = pending;
+	employee.pending_payout_wallet = None;
+
+	emit!(PayoutWalletChanged {
+		employee: employee.key(),
+		previous_payout_wallet,
+		new_payout_wallet: pending,
+	});
+
+	Ok(())
+}
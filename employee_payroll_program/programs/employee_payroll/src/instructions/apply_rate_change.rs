@@ -0,0 +1,57 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct ApplyRateChange<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employee: Account<'info, Employee>,
+	}
+
+/// Apply a timelocked hourly rate increase once its delay has passed
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+pub fn handler(
+	ctx: Context<ApplyRateChange>,
+	_employee_wallet: Pubkey,
+) -> Result<()> {
+	let employee = &mut ctx.accounts.employee;
+	require!(employee.pending_rate_effective_at != 0, EmployeePayrollError::NoPendingRateChange);
+	require!(
+		Clock::get()?.unix_timestamp >= employee.pending_rate_effective_at,
+		EmployeePayrollError::RateChangeTimelocked
+	);
+
+	let previous_hourly_rate = employee.hourly_rate;
+	employee.hourly_rate = employee.pending_hourly_rate;
+	employee.pending_hourly_rate = 0;
+	employee.pending_rate_effective_at = 0;
+
+	emit!(RateChangeApplied {
+		employee: employee.key(),
+		previous_hourly_rate,
+		new_hourly_rate: employee.hourly_rate,
+	});
+
+	Ok(())
+}
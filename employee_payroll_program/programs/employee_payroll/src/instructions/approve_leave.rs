@@ -0,0 +1,90 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		request_id: u64,
+	)]
+	pub struct ApproveLeave<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = !employee.is_terminated @ EmployeePayrollError::EmployeeTerminated,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"leave_request",
+				employee.key().as_ref(),
+				request_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			constraint = leave_request.status == LeaveStatus::Requested @ EmployeePayrollError::LeaveAlreadyReviewed,
+		)]
+		pub leave_request: Account<'info, LeaveRequest>,
+	}
+
+/// Manager approves a leave request, taking it from the employee's leave balance
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[writable]` leave_request: [LeaveRequest] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - request_id: [u64] 
+pub fn handler(
+	ctx: Context<ApproveLeave>,
+	_employee_wallet: Pubkey,
+	_request_id: u64,
+) -> Result<()> {
+	let employee = &mut ctx.accounts.employee;
+	let leave_request = &mut ctx.accounts.leave_request;
+
+	employee.pto_balance = employee
+		.pto_balance
+		.checked_sub(leave_request.duration)
+		.ok_or(EmployeePayrollError::InsufficientLeaveBalance)?;
+	// Salaries keep being paid through leave; hourly pay needs the hours.
+	if employee.compensation == Compensation::Hourly {
+		employee.unpaid_leave = employee
+			.unpaid_leave
+			.checked_add(leave_request.duration)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	}
+
+	leave_request.status = LeaveStatus::Approved;
+	leave_request.reviewed_by = ctx.accounts.authority.key();
+	leave_request.reviewed_at = Clock::get()?.unix_timestamp;
+
+	emit!(LeaveApproved {
+		employee: employee.key(),
+		leave_request: leave_request.key(),
+		duration: leave_request.duration,
+		reviewer: leave_request.reviewed_by,
+		pto_balance: employee.pto_balance,
+	});
+
+	Ok(())
+}
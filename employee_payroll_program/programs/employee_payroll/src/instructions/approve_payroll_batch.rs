@@ -0,0 +1,66 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		batch_id: u64,
+	)]
+	pub struct ApprovePayrollBatch<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = !payroll_batch.is_processed @ EmployeePayrollError::BatchAlreadyProcessed,
+			constraint = !payroll_batch.is_cancelled @ EmployeePayrollError::BatchCancelled,
+			constraint = payroll_batch.has_pay_period() @ EmployeePayrollError::BatchHasNoPayPeriod,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+	}
+
+/// Approve a payroll batch so anyone can crank its payouts from the pay date on
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - pay_date: [i64] When `crank_payroll` may start paying the batch, no earlier than the batch's period end
+pub fn handler(
+	ctx: Context<ApprovePayrollBatch>,
+	_batch_id: u64,
+	pay_date: i64,
+) -> Result<()> {
+	require!(
+		pay_date >= ctx.accounts.payroll_batch.period_end,
+		EmployeePayrollError::InvalidPayDate,
+	);
+
+	let payroll_batch = &mut ctx.accounts.payroll_batch;
+	payroll_batch.pay_date = pay_date;
+
+	emit!(PayrollBatchApproved {
+		payroll_batch: payroll_batch.key(),
+		pay_date,
+		approved_by: ctx.accounts.authority.key(),
+	});
+
+	Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		session_id: u64,
+	)]
+	pub struct ApproveWorkSession<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"work_session",
+				employee_wallet.as_ref(),
+				session_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employee,
+			constraint = work_session.check_out_time != 0 @ EmployeePayrollError::SessionNotCheckedOut,
+			constraint = !work_session.is_paid @ EmployeePayrollError::SessionAlreadyPaid,
+			constraint = !work_session.is_approved @ EmployeePayrollError::SessionAlreadyApproved,
+		)]
+		pub work_session: Account<'info, WorkSession>,
+	}
+
+/// Manager approves the hours of a checked-out work session
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+pub fn handler(
+	ctx: Context<ApproveWorkSession>,
+	_employee_wallet: Pubkey,
+	_session_id: u64,
+) -> Result<()> {
+	let work_session = &mut ctx.accounts.work_session;
+	work_session.is_approved = true;
+
+	emit!(WorkSessionApproved {
+		employee: work_session.employee,
+		work_session: work_session.key(),
+		duration: work_session.duration,
+		approver: ctx.accounts.authority.key(),
+	});
+
+	Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct AssignDepartment<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			has_one = employer,
+		)]
+		pub department: Option<Account<'info, Department>>,
+	}
+
+/// Move an employee into a department, or out of any when none is given
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[]` department: [Department] Optional
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+pub fn handler(
+	ctx: Context<AssignDepartment>,
+	_employee_wallet: Pubkey,
+) -> Result<()> {
+	let department_id = ctx
+		.accounts
+		.department
+		.as_ref()
+		.map_or(0, |department| department.department_id);
+
+	let employee = &mut ctx.accounts.employee;
+	let previous_department_id = employee.department_id;
+	employee.department_id = department_id;
+
+	emit!(DepartmentAssigned {
+		employee: employee.key(),
+		previous_department_id,
+		department_id,
+	});
+
+	Ok(())
+}
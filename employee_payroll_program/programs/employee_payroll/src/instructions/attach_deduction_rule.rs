@@ -0,0 +1,67 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		rule_id: u64,
+	)]
+	pub struct AttachDeductionRule<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			seeds = [
+				b"deduction_rule",
+				employer.key().as_ref(),
+				rule_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub deduction_rule: Account<'info, DeductionRule>,
+	}
+
+/// Attach a deduction rule to an employee, after any rules already attached
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[]` deduction_rule: [DeductionRule] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - rule_id: [u64] 
+pub fn handler(
+	ctx: Context<AttachDeductionRule>,
+	_employee_wallet: Pubkey,
+	_rule_id: u64,
+) -> Result<()> {
+	let rule_key = ctx.accounts.deduction_rule.key();
+	let deduction_rules = &mut ctx.accounts.employee.deduction_rules;
+
+	require!(!deduction_rules.contains(&rule_key), EmployeePayrollError::DeductionRuleAlreadyAttached);
+	require!(deduction_rules.len() < MAX_EMPLOYEE_DEDUCTIONS, EmployeePayrollError::TooManyDeductionRules);
+	deduction_rules.push(rule_key);
+
+	Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		item_id: u64,
+		batch_id: u64,
+	)]
+	pub struct AttachPayItem<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"pay_item",
+				employee.key().as_ref(),
+				item_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			constraint = !pay_item.is_paid @ EmployeePayrollError::PayItemAlreadyPaid,
+		)]
+		pub pay_item: Account<'info, PayItem>,
+
+		#[account(
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = !payroll_batch.is_processed @ EmployeePayrollError::BatchAlreadyProcessed,
+			constraint = !payroll_batch.is_cancelled @ EmployeePayrollError::BatchCancelled,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+	}
+
+/// Schedule an unpaid pay item into a payroll batch, replacing any earlier batch
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` pay_item: [PayItem] 
+/// 4. `[]` payroll_batch: [PayrollBatch] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - item_id: [u64] 
+/// - batch_id: [u64] 
+pub fn handler(
+	ctx: Context<AttachPayItem>,
+	_employee_wallet: Pubkey,
+	_item_id: u64,
+	_batch_id: u64,
+) -> Result<()> {
+	ctx.accounts.pay_item.batch = ctx.accounts.payroll_batch.key();
+
+	Ok(())
+}
@@ -0,0 +1,89 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		session_id: u64,
+		batch_id: u64,
+	)]
+	pub struct AttachWorkSession<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"work_session",
+				employee_wallet.as_ref(),
+				session_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employee,
+			constraint = work_session.check_out_time != 0 @ EmployeePayrollError::SessionNotCheckedOut,
+			constraint = !work_session.is_paid @ EmployeePayrollError::SessionAlreadyPaid,
+		)]
+		pub work_session: Account<'info, WorkSession>,
+
+		#[account(
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = !payroll_batch.is_processed @ EmployeePayrollError::BatchAlreadyProcessed,
+			constraint = !payroll_batch.is_cancelled @ EmployeePayrollError::BatchCancelled,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+	}
+
+/// Schedule a checked-out work session into a payroll batch, approving its hours and replacing any earlier batch
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+/// 4. `[]` payroll_batch: [PayrollBatch] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+/// - batch_id: [u64] 
+pub fn handler(
+	ctx: Context<AttachWorkSession>,
+	_employee_wallet: Pubkey,
+	_session_id: u64,
+	_batch_id: u64,
+) -> Result<()> {
+	let work_session = &mut ctx.accounts.work_session;
+	require!(
+		ctx.accounts.payroll_batch.covers(work_session.check_in_time, work_session.check_out_time),
+		EmployeePayrollError::SessionOutsidePayPeriod
+	);
+
+	work_session.batch = ctx.accounts.payroll_batch.key();
+	work_session.is_approved = true;
+
+	Ok(())
+}
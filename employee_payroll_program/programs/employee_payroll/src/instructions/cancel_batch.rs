@@ -0,0 +1,85 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		batch_id: u64,
+	)]
+	pub struct CancelBatch<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = !payroll_batch.is_processed @ EmployeePayrollError::BatchAlreadyProcessed,
+			constraint = !payroll_batch.is_cancelled @ EmployeePayrollError::BatchCancelled,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+	}
+
+/// Cancel a payroll batch so it pays nothing more, releasing the pay items and work sessions attached to it
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+///    3..n. `[writable]` remaining accounts: unpaid `PayItem`s and `WorkSession`s attached to the batch, to attach to another one
+///
+/// Data:
+/// - batch_id: [u64] 
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, CancelBatch<'info>>,
+	_batch_id: u64,
+) -> Result<()> {
+	let payroll_batch_key = ctx.accounts.payroll_batch.key();
+
+	// Sessions the batch already paid stay paid; `claw_back_credit` reverses
+	// them while their credit is unclaimed.
+	let mut released_pay_items = 0u32;
+	let mut released_work_sessions = 0u32;
+	for info in ctx.remaining_accounts {
+		if info.try_borrow_data()?.starts_with(WorkSession::DISCRIMINATOR) {
+			let mut work_session = Account::<WorkSession>::try_from(info)?;
+			require_keys_eq!(work_session.batch, payroll_batch_key, EmployeePayrollError::BatchMismatch);
+			require!(!work_session.is_paid, EmployeePayrollError::SessionAlreadyPaid);
+
+			work_session.batch = Pubkey::default();
+			work_session.exit(&crate::ID)?;
+			released_work_sessions += 1;
+		} else {
+			let mut pay_item = Account::<PayItem>::try_from(info)?;
+			require_keys_eq!(pay_item.batch, payroll_batch_key, EmployeePayrollError::PayItemMismatch);
+			require!(!pay_item.is_paid, EmployeePayrollError::PayItemAlreadyPaid);
+
+			pay_item.batch = Pubkey::default();
+			pay_item.exit(&crate::ID)?;
+			released_pay_items += 1;
+		}
+	}
+
+	ctx.accounts.payroll_batch.is_cancelled = true;
+
+	emit!(PayrollBatchCancelled {
+		payroll_batch: payroll_batch_key,
+		cancelled_by: ctx.accounts.authority.key(),
+		released_pay_items,
+		released_work_sessions,
+	});
+
+	Ok(())
+}
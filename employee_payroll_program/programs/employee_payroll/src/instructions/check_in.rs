@@ -11,20 +11,42 @@ use std::str::FromStr;
 		timestamp: i64,
 	)]
 	pub struct CheckIn<'info> {
+		#[account(
+			mut,
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
 		pub authority: Signer<'info>,
 
 		#[account(
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
 			seeds = [
 				b"employee",
 				employee_wallet.as_ref(),
 			],
 			bump,
+			has_one = employer,
+			constraint = employee.is_active @ EmployeePayrollError::EmployeeInactive,
+			constraint = employee.compensation == Compensation::Hourly @ EmployeePayrollError::CompensationMismatch,
 		)]
 		pub employee: Account<'info, Employee>,
 
 		#[account(
 			init,
-			space=105,
+			space=8 + WorkSession::INIT_SPACE,
 			payer=authority,
 			seeds = [
 				b"work_session",
@@ -35,28 +57,63 @@ use std::str::FromStr;
 		)]
 		pub work_session: Account<'info, WorkSession>,
 
+		#[account(
+			has_one = employer,
+			constraint = project.is_active @ EmployeePayrollError::ProjectInactive,
+		)]
+		pub project: Option<Account<'info, Project>>,
+
 		pub system_program: Program<'info, System>,
 	}
 
 /// Employee checks in to start work
 ///
 /// Accounts:
-/// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[]` employee: [Employee] 
-/// 2. `[writable]` work_session: [WorkSession] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 0. `[writable, signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` employee: [Employee] Hourly, as only hourly pay is paid per work session
+/// 4. `[writable]` work_session: [WorkSession] 
+/// 5. `[]` project: [Project] Optional, the project the session is worked on
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - employee_wallet: [Pubkey] 
 /// - session_id: [u64] 
-/// - timestamp: [i64] 
+/// - timestamp: [i64] Unused, the check-in time is taken from the clock
 pub fn handler(
 	ctx: Context<CheckIn>,
-	employee_wallet: Pubkey,
+	_employee_wallet: Pubkey,
 	session_id: u64,
-	timestamp: i64,
+	_timestamp: i64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	let employee = &mut ctx.accounts.employee;
+	let work_session = &mut ctx.accounts.work_session;
+
+	employee.unpaid_sessions = employee
+		.unpaid_sessions
+		.checked_add(1)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+	work_session.employee = employee.key();
+	work_session.check_in_time = Clock::get()?.unix_timestamp;
+	work_session.check_out_time = 0;
+	work_session.duration = 0;
+	work_session.is_paid = false;
+	work_session.authority = employee.authority;
+	work_session.session_id = session_id;
+	work_session.batch = Pubkey::default();
+	work_session.rent_payer = ctx.accounts.authority.key();
+	work_session.version = WorkSession::VERSION;
+	work_session.exchange_rate = 0;
+	work_session.credited = 0;
+	work_session.is_approved = false;
+	work_session.payment = PaymentRecord::default();
+	work_session.project_id = ctx
+		.accounts
+		.project
+		.as_ref()
+		.map_or(0, |project| project.project_id);
+
 	Ok(())
 }
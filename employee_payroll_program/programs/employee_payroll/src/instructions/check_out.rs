@@ -11,8 +11,13 @@ use std::str::FromStr;
 		timestamp: i64,
 	)]
 	pub struct CheckOut<'info> {
+		#[account(
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
 		pub authority: Signer<'info>,
 
+		pub employer: Account<'info, Employer>,
+
 		#[account(
 			mut,
 			seeds = [
@@ -20,6 +25,7 @@ use std::str::FromStr;
 				employee_wallet.as_ref(),
 			],
 			bump,
+			has_one = employer,
 		)]
 		pub employee: Account<'info, Employee>,
 
@@ -31,28 +37,85 @@ use std::str::FromStr;
 				session_id.to_le_bytes().as_ref(),
 			],
 			bump,
+			has_one = employee,
 		)]
 		pub work_session: Account<'info, WorkSession>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = project.project_id == work_session.project_id @ EmployeePayrollError::ProjectMismatch,
+		)]
+		pub project: Option<Account<'info, Project>>,
 	}
 
 /// Employee checks out to end work
 ///
 /// Accounts:
-/// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` employee: [Employee] 
-/// 2. `[writable]` work_session: [WorkSession] 
+/// 0. `[signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+/// 4. `[writable]` project: [Project] Optional, required when the session was worked on a project
 ///
 /// Data:
 /// - employee_wallet: [Pubkey] 
 /// - session_id: [u64] 
-/// - timestamp: [i64] 
+/// - timestamp: [i64] Unused, the check-out time is taken from the clock
 pub fn handler(
 	ctx: Context<CheckOut>,
-	employee_wallet: Pubkey,
-	session_id: u64,
-	timestamp: i64,
+	_employee_wallet: Pubkey,
+	_session_id: u64,
+	_timestamp: i64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
-	Ok(())
+	record_check_out(
+		&ctx.accounts.employer,
+		&mut ctx.accounts.employee,
+		&mut ctx.accounts.work_session,
+		ctx.accounts.project.as_mut(),
+		Clock::get()?.unix_timestamp,
+	)
+}
+
+/// Checks `work_session` out at `now` at the employee's current rate, adding
+/// the time worked to the employee's totals and leave balance and to the
+/// session's project.
+pub(crate) fn record_check_out(
+	employer: &Employer,
+	employee: &mut Employee,
+	work_session: &mut WorkSession,
+	project: Option<&mut Account<Project>>,
+	now: i64,
+) -> Result<()> {
+	require!(work_session.check_out_time == 0, EmployeePayrollError::SessionAlreadyCheckedOut);
+	require!(now > work_session.check_in_time, EmployeePayrollError::InvalidTimestamp);
+
+	let duration = (now - work_session.check_in_time) as u64;
+	work_session.check_out_time = now;
+	work_session.duration = duration;
+	work_session.hourly_rate = employee.hourly_rate;
+
+	if work_session.project_id != 0 {
+		project
+			.ok_or(EmployeePayrollError::ProjectRequired)?
+			.record_hours(duration)?;
+	}
+
+	employee.total_hours_worked = employee
+		.total_hours_worked
+		.checked_add(duration)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	employee.pto_balance = employee
+		.pto_balance
+		.checked_add(((duration as u128) * (employee.pto_accrual_bps as u128) / 10_000) as u64)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	employee.record_earnings(
+		employer,
+		now,
+		&EarningsTotals {
+			worked: duration,
+			overtime: duration.saturating_sub(OVERTIME_THRESHOLD),
+			..Default::default()
+		},
+	)
 }
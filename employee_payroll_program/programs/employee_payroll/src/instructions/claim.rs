@@ -0,0 +1,111 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct Claim<'info> {
+		#[account(
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+			has_one = employee,
+		)]
+		pub claimable_balance: Account<'info, ClaimableBalance>,
+
+		#[account(
+			mut,
+			address = employee.payout_wallet @ EmployeePayrollError::PayoutWalletMismatch,
+		)]
+		pub payout_wallet: SystemAccount<'info>,
+	}
+
+/// Employee withdraws their claimable balance to their payout wallets
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[]` employee: [Employee] 
+/// 4. `[writable]` claimable_balance: [ClaimableBalance] 
+/// 5. `[writable]` payout_wallet: [AccountInfo] 
+///    6..n. `[writable]` remaining accounts: a wallet for each of the employee's payout splits
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, Claim<'info>>,
+	_employee_wallet: Pubkey,
+) -> Result<()> {
+	// Credits that may still be clawed back stay in the balance.
+	let amount = ctx.accounts.claimable_balance.claimable(Clock::get()?.unix_timestamp);
+	require!(amount > 0, EmployeePayrollError::NothingToClaim);
+
+	let mut payouts = Vec::with_capacity(ctx.accounts.employee.payout_splits.len() + 1);
+	push_net_payouts(
+		&ctx.accounts.employee,
+		ctx.remaining_accounts,
+		ctx.accounts.payout_wallet.to_account_info(),
+		amount,
+		&mut payouts,
+	)?;
+
+	// The balance is owned by this program, so lamports above its rent can be
+	// moved out directly.
+	let claimable_balance_info = ctx.accounts.claimable_balance.to_account_info();
+	for (destination, payout) in payouts.iter().filter(|(_, payout)| *payout > 0) {
+		claimable_balance_info.sub_lamports(*payout)?;
+		destination.add_lamports(*payout)?;
+	}
+
+	let claimable_balance = &mut ctx.accounts.claimable_balance;
+	claimable_balance.amount -= amount;
+	claimable_balance.total_claimed = claimable_balance
+		.total_claimed
+		.checked_add(amount)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+	emit!(BalanceClaimed {
+		employee: ctx.accounts.employee.key(),
+		claimable_balance: claimable_balance.key(),
+		amount,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,252 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		session_id: u64,
+		batch_id: u64,
+	)]
+	pub struct ClawBackCredit<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"work_session",
+				employee_wallet.as_ref(),
+				session_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employee,
+			constraint = work_session.is_paid @ EmployeePayrollError::SessionNotPaid,
+			constraint = work_session.batch == payroll_batch.key() @ EmployeePayrollError::BatchMismatch,
+		)]
+		pub work_session: Account<'info, WorkSession>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub claimable_balance: Account<'info, ClaimableBalance>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = department.department_id == work_session.payment.department_id @ EmployeePayrollError::DepartmentMismatch,
+		)]
+		pub department: Option<Account<'info, Department>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = project.project_id == work_session.project_id @ EmployeePayrollError::ProjectMismatch,
+		)]
+		pub project: Option<Account<'info, Project>>,
+	}
+
+/// Reverse a session's payment while its credit is still unclaimed, returning the credit to the vault and recording why
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+/// 3. `[writable]` employee: [Employee] 
+/// 4. `[writable]` work_session: [WorkSession] 
+/// 5. `[writable]` claimable_balance: [ClaimableBalance] 
+/// 6. `[writable]` vault: [AccountInfo] 
+/// 7. `[writable]` department: [Department] Optional, required when the payment was charged to a department
+/// 8. `[writable]` project: [Project] Optional, required when the session was worked on a project
+///    9..n. `[writable]` remaining accounts: the `PayItem`s then the `Expense`s paid with the session, in the order they were paid
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+/// - batch_id: [u64] 
+/// - reason: [String] At most `MAX_CLAWBACK_REASON_LEN` bytes, recorded in the `CreditClawedBack` event
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, ClawBackCredit<'info>>,
+	_employee_wallet: Pubkey,
+	_session_id: u64,
+	_batch_id: u64,
+	reason: String,
+) -> Result<()> {
+	require!(reason.len() <= MAX_CLAWBACK_REASON_LEN, EmployeePayrollError::ReasonTooLong);
+
+	let payment = std::mem::take(&mut ctx.accounts.work_session.payment);
+	require_gt!(
+		payment.reversible_until,
+		Clock::get()?.unix_timestamp,
+		EmployeePayrollError::ReversalWindowClosed
+	);
+
+	let amount = ctx.accounts.work_session.credited;
+	require!(amount > 0, EmployeePayrollError::NothingToClawBack);
+	require_gte!(ctx.accounts.claimable_balance.amount, amount, EmployeePayrollError::CreditAlreadyClaimed);
+
+	let employee_key = ctx.accounts.employee.key();
+	let payroll_batch_key = ctx.accounts.payroll_batch.key();
+	let pay_item_count = ctx
+		.remaining_accounts
+		.iter()
+		.take_while(|info| !info.try_borrow_data().is_ok_and(|data| data.starts_with(Expense::DISCRIMINATOR)))
+		.count();
+	let (pay_items, expenses) = ctx.remaining_accounts.split_at(pay_item_count);
+	require!(
+		paid_with_hash(pay_items, expenses) == payment.paid_with,
+		EmployeePayrollError::PaymentAccountsMismatch
+	);
+
+	// Pay items go back to the batch and expenses back to approved, to be paid
+	// again with the session.
+	for pay_item_info in pay_items {
+		let mut pay_item = Account::<PayItem>::try_from(pay_item_info)?;
+		require_keys_eq!(pay_item.employee, employee_key, EmployeePayrollError::PayItemMismatch);
+		require_keys_eq!(pay_item.batch, payroll_batch_key, EmployeePayrollError::PayItemMismatch);
+
+		pay_item.is_paid = false;
+		pay_item.exit(&crate::ID)?;
+	}
+	for expense_info in expenses {
+		let mut expense = Account::<Expense>::try_from(expense_info)?;
+		require_keys_eq!(expense.employee, employee_key, EmployeePayrollError::ExpenseMismatch);
+		require_keys_eq!(expense.batch, payroll_batch_key, EmployeePayrollError::ExpenseMismatch);
+
+		expense.status = ExpenseStatus::Approved;
+		expense.batch = Pubkey::default();
+		expense.exit(&crate::ID)?;
+	}
+
+	// The balance is owned by this program, so lamports above its rent can be
+	// moved out directly.
+	ctx.accounts.claimable_balance.to_account_info().sub_lamports(amount)?;
+	ctx.accounts.vault.add_lamports(amount)?;
+	let claimable_balance = &mut ctx.accounts.claimable_balance;
+	claimable_balance.amount -= amount;
+	claimable_balance.unlock(amount, payment.reversible_until);
+
+	// Withholding has left for the remittance vaults and the crank tip for
+	// the cranker, so only the credit comes off the employer's outflow.
+	let employer = &mut ctx.accounts.employer;
+	employer.reverse_outflow(amount, payment.paid_at);
+	if payment.department_id != 0 {
+		ctx.accounts
+			.department
+			.as_mut()
+			.ok_or(EmployeePayrollError::DepartmentRequired)?
+			.reverse_spend(employer, payment.gross, payment.paid_at);
+	}
+	if payment.labor_cost > 0 {
+		ctx.accounts
+			.project
+			.as_mut()
+			.ok_or(EmployeePayrollError::ProjectRequired)?
+			.reverse_labor_cost(payment.labor_cost);
+	}
+
+	let employee = &mut ctx.accounts.employee;
+	employee.unpaid_sessions = employee
+		.unpaid_sessions
+		.checked_add(1)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	employee.total_paid = employee.total_paid.saturating_sub(payment.gross);
+	employee.total_reimbursed = employee.total_reimbursed.saturating_sub(payment.reimbursed);
+	employee.outstanding_advance = employee
+		.outstanding_advance
+		.checked_add(payment.advance_recovered)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	let outstanding_adjustment = employee.outstanding_adjustment as i128 + payment.adjustment_recovered as i128;
+	employee.outstanding_adjustment = u64::try_from(outstanding_adjustment.max(0))
+		.map_err(|_| EmployeePayrollError::ArithmeticOverflow)?;
+	employee.unpaid_leave = employee
+		.unpaid_leave
+		.checked_add(payment.leave_paid)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	// The withholding stays with the remittance vaults; paying the session
+	// again withholds it as usual but sends that much less to them.
+	employee.withholding_credit = employee
+		.withholding_credit
+		.checked_add(payment.withheld)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	employee.reverse_earnings(
+		employer,
+		payment.paid_at,
+		&EarningsTotals {
+			gross: payment.gross,
+			net: payment.gross - payment.withheld,
+			deductions: payment.withheld,
+			..Default::default()
+		},
+	);
+
+	let payroll_batch = &mut ctx.accounts.payroll_batch;
+	payroll_batch.total_amount = payroll_batch
+		.total_amount
+		.saturating_sub(payment.gross)
+		.saturating_sub(payment.reimbursed);
+
+	// The session can be attached to a batch and paid again.
+	let work_session = &mut ctx.accounts.work_session;
+	work_session.is_paid = false;
+	work_session.batch = Pubkey::default();
+	work_session.exchange_rate = 0;
+	work_session.credited = 0;
+
+	emit!(CreditClawedBack {
+		payroll_batch: payroll_batch_key,
+		employee: employee_key,
+		work_session: work_session.key(),
+		amount,
+		gross: payment.gross,
+		withheld: payment.withheld,
+		reason,
+	});
+
+	Ok(())
+}
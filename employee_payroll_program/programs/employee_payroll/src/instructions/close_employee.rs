@@ -0,0 +1,103 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		item_id: u64,
+	)]
+	pub struct CloseEmployee<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			close = authority,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = employee.is_terminated @ EmployeePayrollError::EmployeeNotTerminated,
+			constraint = employee.unpaid_sessions == 0 @ EmployeePayrollError::SessionsStillUnpaid,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			close = authority,
+			seeds = [
+				b"pay_item",
+				employee.key().as_ref(),
+				item_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			constraint = final_settlement.kind == PayItemKind::FinalSettlement @ EmployeePayrollError::PayItemMismatch,
+			constraint = final_settlement.is_paid @ EmployeePayrollError::SettlementNotPaid,
+		)]
+		pub final_settlement: Account<'info, PayItem>,
+
+		/// CHECK: the employee's claimable balance, which may never have been opened
+		#[account(
+			mut,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub claimable_balance: UncheckedAccount<'info>,
+	}
+
+/// Close a terminated employee whose final settlement and work sessions have been paid, returning the rent to the owner
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[writable]` final_settlement: [PayItem] 
+/// 4. `[writable]` claimable_balance: [AccountInfo] Closed along with the employee, and must be empty, when it exists
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - item_id: [u64] Id of the final settlement created by `terminate_employee`
+pub fn handler(
+	ctx: Context<CloseEmployee>,
+	_employee_wallet: Pubkey,
+	_item_id: u64,
+) -> Result<()> {
+	// A balance left behind could never be claimed once the employee is gone.
+	let claimable_balance_info = ctx.accounts.claimable_balance.to_account_info();
+	if !claimable_balance_info.data_is_empty() {
+		let claimable_balance = ClaimableBalance::try_deserialize(&mut &claimable_balance_info.try_borrow_data()?[..])?;
+		require!(claimable_balance.amount == 0, EmployeePayrollError::ClaimableBalanceNotEmpty);
+
+		let lamports = claimable_balance_info.lamports();
+		claimable_balance_info.sub_lamports(lamports)?;
+		ctx.accounts.authority.add_lamports(lamports)?;
+		claimable_balance_info.assign(&System::id());
+		claimable_balance_info.resize(0)?;
+	}
+
+	let employee = &ctx.accounts.employee;
+	let written_off = employee
+		.outstanding_advance
+		.checked_add(employee.outstanding_adjustment)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+	emit!(EmployeeClosed {
+		employee: employee.key(),
+		written_off,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,102 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		session_id: u64,
+		emit_archive: bool,
+	)]
+	pub struct CloseWorkSession<'info> {
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager
+				|| authority.key() == work_session.rent_payer @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			close = rent_payer,
+			has_one = employee,
+			has_one = rent_payer @ EmployeePayrollError::RentPayerMismatch,
+			seeds = [
+				b"work_session",
+				employee_wallet.as_ref(),
+				session_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			constraint = work_session.is_paid @ EmployeePayrollError::SessionNotPaid,
+			constraint = !work_session.is_reversible(Clock::get()?.unix_timestamp) @ EmployeePayrollError::CreditStillReversible,
+		)]
+		pub work_session: Account<'info, WorkSession>,
+
+		#[account(
+			address = work_session.batch @ EmployeePayrollError::BatchMismatch,
+			constraint = payroll_batch.is_closed() @ EmployeePayrollError::BatchNotCompleted,
+		)]
+		pub payroll_batch: Option<Account<'info, PayrollBatch>>,
+
+		#[account(
+			mut,
+		)]
+		pub rent_payer: SystemAccount<'info>,
+	}
+
+/// Close a paid work session and return its rent to the payer
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner, payroll manager or rent payer
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+/// 4. `[]` payroll_batch: [PayrollBatch] Completed or cancelled batch that paid the session, omitted when it was marked paid outside a batch
+/// 5. `[writable]` rent_payer: [AccountInfo] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+/// - emit_archive: [bool] Emit a `WorkSessionArchived` event before closing
+pub fn handler(
+	ctx: Context<CloseWorkSession>,
+	_employee_wallet: Pubkey,
+	_session_id: u64,
+	emit_archive: bool,
+) -> Result<()> {
+	let work_session = &ctx.accounts.work_session;
+	require!(
+		work_session.batch == Pubkey::default() || ctx.accounts.payroll_batch.is_some(),
+		EmployeePayrollError::BatchNotCompleted
+	);
+
+	if emit_archive {
+		emit_work_session_archived(work_session);
+	}
+
+	Ok(())
+}
+
+pub(crate) fn emit_work_session_archived(work_session: &WorkSession) {
+	emit!(WorkSessionArchived {
+		employee: work_session.employee,
+		session_id: work_session.session_id,
+		check_in_time: work_session.check_in_time,
+		check_out_time: work_session.check_out_time,
+		batch: work_session.batch,
+	});
+}
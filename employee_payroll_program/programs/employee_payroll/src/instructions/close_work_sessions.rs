@@ -0,0 +1,74 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		batch_id: u64,
+		emit_archive: bool,
+	)]
+	pub struct CloseWorkSessions<'info> {
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = payroll_batch.is_closed() @ EmployeePayrollError::BatchNotCompleted,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+	}
+
+/// Close every paid work session of a completed or cancelled batch, returning rent to each payer
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` payroll_batch: [PayrollBatch] 
+///    3..n. `[writable]` remaining accounts: (work_session, rent_payer) pairs
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - emit_archive: [bool] Emit a `WorkSessionArchived` event for each session before closing
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, CloseWorkSessions<'info>>,
+	_batch_id: u64,
+	emit_archive: bool,
+) -> Result<()> {
+	require!(
+		ctx.remaining_accounts.len().is_multiple_of(2),
+		EmployeePayrollError::InvalidRemainingAccounts
+	);
+
+	let now = Clock::get()?.unix_timestamp;
+	let batch_key = ctx.accounts.payroll_batch.key();
+	for pair in ctx.remaining_accounts.chunks(2) {
+		let (session_info, rent_payer_info) = (&pair[0], &pair[1]);
+
+		let work_session = Account::<WorkSession>::try_from(session_info)?;
+		require_keys_eq!(work_session.batch, batch_key, EmployeePayrollError::BatchMismatch);
+		require!(work_session.is_paid, EmployeePayrollError::SessionNotPaid);
+		require!(!work_session.is_reversible(now), EmployeePayrollError::CreditStillReversible);
+		require_keys_eq!(work_session.rent_payer, rent_payer_info.key(), EmployeePayrollError::RentPayerMismatch);
+
+		if emit_archive {
+			emit_work_session_archived(&work_session);
+		}
+
+		work_session.close(rent_payer_info.clone())?;
+	}
+
+	Ok(())
+}
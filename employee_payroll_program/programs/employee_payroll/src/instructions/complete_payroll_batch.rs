@@ -0,0 +1,54 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		batch_id: u64,
+	)]
+	pub struct CompletePayrollBatch<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = !payroll_batch.is_processed @ EmployeePayrollError::BatchAlreadyProcessed,
+			constraint = !payroll_batch.is_cancelled @ EmployeePayrollError::BatchCancelled,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+	}
+
+/// Mark a payroll batch as completed once all of its sessions are paid
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+///
+/// Data:
+/// - batch_id: [u64] 
+pub fn handler(
+	ctx: Context<CompletePayrollBatch>,
+	_batch_id: u64,
+) -> Result<()> {
+	let payroll_batch = &mut ctx.accounts.payroll_batch;
+
+	payroll_batch.is_processed = true;
+	payroll_batch.processed_at = Clock::get()?.unix_timestamp;
+
+	Ok(())
+}
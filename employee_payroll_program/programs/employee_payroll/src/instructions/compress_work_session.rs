@@ -0,0 +1,157 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		session_id: u64,
+	)]
+	pub struct CompressWorkSession<'info> {
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager
+				|| authority.key() == work_session.rent_payer @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			constraint = employer.session_tree != Pubkey::default() @ EmployeePayrollError::SessionTreeNotInitialized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			close = rent_payer,
+			has_one = employee,
+			has_one = rent_payer @ EmployeePayrollError::RentPayerMismatch,
+			seeds = [
+				b"work_session",
+				employee_wallet.as_ref(),
+				session_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			constraint = work_session.check_out_time != 0 @ EmployeePayrollError::SessionNotCheckedOut,
+			constraint = work_session.is_paid || work_session.project_id == 0 @ EmployeePayrollError::ProjectSessionUnpaid,
+			constraint = !work_session.is_reversible(Clock::get()?.unix_timestamp) @ EmployeePayrollError::CreditStillReversible,
+		)]
+		pub work_session: Account<'info, WorkSession>,
+
+		#[account(
+			address = work_session.batch @ EmployeePayrollError::BatchMismatch,
+			constraint = payroll_batch.is_closed() @ EmployeePayrollError::BatchNotCompleted,
+		)]
+		pub payroll_batch: Option<Account<'info, PayrollBatch>>,
+
+		#[account(
+			mut,
+		)]
+		pub rent_payer: SystemAccount<'info>,
+
+		/// CHECK: checked against the employer's session tree
+		#[account(
+			mut,
+			address = employer.session_tree,
+		)]
+		pub merkle_tree: UncheckedAccount<'info>,
+
+		/// CHECK: PDA signing for the employer's session tree
+		#[account(
+			seeds = [
+				b"session_tree",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub tree_authority: UncheckedAccount<'info>,
+
+		/// CHECK: checked by address
+		#[account(
+			address = SPL_ACCOUNT_COMPRESSION_ID,
+		)]
+		pub compression_program: UncheckedAccount<'info>,
+
+		/// CHECK: checked by address
+		#[account(
+			address = SPL_NOOP_ID,
+		)]
+		pub noop: UncheckedAccount<'info>,
+	}
+
+/// Append a checked-out work session to the employer's session tree and close its account
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner, payroll manager or rent payer
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+/// 4. `[]` payroll_batch: [PayrollBatch] Completed or cancelled batch the session is attached to, omitted when it has none
+/// 5. `[writable]` rent_payer: [AccountInfo] 
+/// 6. `[writable]` merkle_tree: [AccountInfo] 
+/// 7. `[]` tree_authority: [AccountInfo] 
+/// 8. `[]` compression_program: [AccountInfo] 
+/// 9. `[]` noop: [AccountInfo] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+pub fn handler(
+	ctx: Context<CompressWorkSession>,
+	_employee_wallet: Pubkey,
+	_session_id: u64,
+) -> Result<()> {
+	let work_session = &ctx.accounts.work_session;
+	require!(
+		work_session.batch == Pubkey::default() || ctx.accounts.payroll_batch.is_some(),
+		EmployeePayrollError::BatchNotCompleted
+	);
+
+	let employer_key = ctx.accounts.employer.key();
+	let mut session = CompressedWorkSession::from_session(employer_key, work_session);
+	// The closed batch pays nothing more, so an unpaid session is left for
+	// whichever batch pays it from the tree.
+	if !session.is_paid {
+		session.batch = Pubkey::default();
+	}
+
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"session_tree",
+		employer_key.as_ref(),
+		&[ctx.bumps.tree_authority],
+	]];
+
+	SessionTreeCpi {
+		compression_program: ctx.accounts.compression_program.to_account_info(),
+		merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+		tree_authority: ctx.accounts.tree_authority.to_account_info(),
+		noop: ctx.accounts.noop.to_account_info(),
+		signer_seeds,
+	}
+	.append(session.leaf())?;
+
+	let employer = &mut ctx.accounts.employer;
+	let leaf_index = u32::try_from(employer.compressed_session_count)
+		.map_err(|_| EmployeePayrollError::ArithmeticOverflow)?;
+	employer.compressed_session_count += 1;
+
+	emit!(WorkSessionCompressed {
+		merkle_tree: employer.session_tree,
+		leaf_index,
+		session,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,175 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		batch_id: u64,
+	)]
+	pub struct CrankPayroll<'info> {
+		#[account(
+			mut,
+		)]
+		pub cranker: Signer<'info>,
+
+		#[account(
+			mut,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = payroll_batch.pay_date != 0 @ EmployeePayrollError::BatchNotApproved,
+			constraint = payroll_batch.has_pay_period() @ EmployeePayrollError::BatchHasNoPayPeriod,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = employee.compensation == Compensation::Hourly @ EmployeePayrollError::CompensationMismatch,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			has_one = employee,
+			constraint = work_session.batch == payroll_batch.key() @ EmployeePayrollError::BatchMismatch,
+		)]
+		pub work_session: Account<'info, WorkSession>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			address = employee.payout_wallet @ EmployeePayrollError::PayoutWalletMismatch,
+		)]
+		pub payout_wallet: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub claimable_balance: Option<Account<'info, ClaimableBalance>>,
+
+		/// CHECK: parsed as a price update by `convert_wages`
+		#[account(
+			address = employee.rate_price_feed @ EmployeePayrollError::PriceFeedMismatch,
+		)]
+		pub price_feed: Option<UncheckedAccount<'info>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = department.department_id == employee.department_id @ EmployeePayrollError::DepartmentMismatch,
+		)]
+		pub department: Option<Account<'info, Department>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = project.project_id == work_session.project_id @ EmployeePayrollError::ProjectMismatch,
+		)]
+		pub project: Option<Account<'info, Project>>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Pay one work session attached to an approved batch once its pay date has passed, tipping the caller from the vault
+///
+/// Accounts:
+/// 0. `[writable, signer]` cranker: [AccountInfo] Anyone; receives the employer's crank tip
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` payroll_batch: [PayrollBatch] 
+/// 4. `[writable]` employee: [Employee] 
+/// 5. `[writable]` work_session: [WorkSession] 
+/// 6. `[writable]` vault: [AccountInfo] 
+/// 7. `[writable]` payout_wallet: [AccountInfo] 
+/// 8. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 9. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 10. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 11. `[writable]` project: [Project] Optional, required when the session was worked on a project
+/// 12. `[]` system_program: [AccountInfo] 
+///     13..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     then a wallet for each of the employee's payout splits when paying directly
+///
+/// Data:
+/// - batch_id: [u64] 
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, CrankPayroll<'info>>,
+	_batch_id: u64,
+) -> Result<()> {
+	let now = Clock::get()?.unix_timestamp;
+	require_gte!(now, ctx.accounts.payroll_batch.pay_date, EmployeePayrollError::PayDateNotReached);
+
+	// Pay items and expenses wait for a manager-run payroll, so a cranker
+	// cannot choose which of them get paid.
+	let remaining = split_remaining_accounts(&ctx.accounts.employer, &ctx.accounts.employee, ctx.remaining_accounts);
+	require!(
+		remaining.pay_items.is_empty() && remaining.expenses.is_empty(),
+		EmployeePayrollError::InvalidRemainingAccounts
+	);
+
+	// Nobody vouches for a crank, so limits and budgets hold without exception
+	let tip = ctx.accounts.employer.crank_tip;
+	pay_work_session(
+		SessionPayment {
+			employer: &mut ctx.accounts.employer,
+			payroll_batch: &mut ctx.accounts.payroll_batch,
+			employee: &mut ctx.accounts.employee,
+			work_session: &mut ctx.accounts.work_session,
+			vault: &ctx.accounts.vault,
+			vault_bump: ctx.bumps.vault,
+			payout_wallet: &ctx.accounts.payout_wallet,
+			claimable_balance: ctx.accounts.claimable_balance.as_mut(),
+			price_feed: ctx.accounts.price_feed.as_ref(),
+			department: ctx.accounts.department.as_mut(),
+			project: ctx.accounts.project.as_mut(),
+			system_program: &ctx.accounts.system_program,
+		},
+		remaining,
+		Some((ctx.accounts.cranker.to_account_info(), tip)),
+		false,
+	)?;
+
+	emit!(PayrollCranked {
+		payroll_batch: ctx.accounts.payroll_batch.key(),
+		work_session: ctx.accounts.work_session.key(),
+		cranker: ctx.accounts.cranker.key(),
+		tip,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		rule_id: u64,
+		name: String,
+		kind: DeductionKind,
+		remittance_vault: Pubkey,
+	)]
+	pub struct CreateDeductionRule<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			init,
+			space=8 + DeductionRule::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"deduction_rule",
+				employer.key().as_ref(),
+				rule_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub deduction_rule: Account<'info, DeductionRule>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a withholding rule (flat, percentage or tiered) for the employer's payouts
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` deduction_rule: [DeductionRule] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - rule_id: [u64] 
+/// - name: [String] At most `MAX_DEDUCTION_NAME_LEN` bytes
+/// - kind: [DeductionKind] 
+/// - remittance_vault: [Pubkey] Receives the withheld lamports
+pub fn handler(
+	ctx: Context<CreateDeductionRule>,
+	rule_id: u64,
+	name: String,
+	kind: DeductionKind,
+	remittance_vault: Pubkey,
+) -> Result<()> {
+	DeductionRule::validate(&name, &kind)?;
+
+	let deduction_rule = &mut ctx.accounts.deduction_rule;
+
+	deduction_rule.employer = ctx.accounts.employer.key();
+	deduction_rule.rule_id = rule_id;
+	deduction_rule.name = name;
+	deduction_rule.kind = kind;
+	deduction_rule.remittance_vault = remittance_vault;
+	deduction_rule.version = DeductionRule::VERSION;
+
+	Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		department_id: u64,
+		name: String,
+	)]
+	pub struct CreateDepartment<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			init,
+			space=8 + Department::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"department",
+				employer.key().as_ref(),
+				department_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub department: Account<'info, Department>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a department to attribute labor cost to, with an optional budget per pay period
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` department: [Department] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - department_id: [u64] Nonzero
+/// - name: [String] At most `MAX_DEPARTMENT_NAME_LEN` bytes
+/// - budget_per_period: [u64] Lamports of gross pay per pay period, 0 for no cap
+pub fn handler(
+	ctx: Context<CreateDepartment>,
+	department_id: u64,
+	name: String,
+	budget_per_period: u64,
+) -> Result<()> {
+	Department::validate(department_id, &name)?;
+
+	let department = &mut ctx.accounts.department;
+
+	department.employer = ctx.accounts.employer.key();
+	department.department_id = department_id;
+	department.name = name;
+	department.budget_per_period = budget_per_period;
+	department.period_start = ctx.accounts.employer.current_period_start(Clock::get()?.unix_timestamp);
+	department.period_spent = 0;
+	department.total_spent = 0;
+	department.version = Department::VERSION;
+
+	Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		item_id: u64,
+	)]
+	pub struct CreatePayItem<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			init,
+			space=8 + PayItem::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"pay_item",
+				employee.key().as_ref(),
+				item_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub pay_item: Account<'info, PayItem>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a bonus, commission or adjustment to pay an employee outside of work sessions
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` pay_item: [PayItem] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - item_id: [u64] 
+/// - kind: [PayItemKind] Any but `FinalSettlement`, which only `terminate_employee` creates
+/// - amount: [i64] Lamports, negative to recover an overpayment from later payouts
+/// - memo_hash: [[u8; 32]] Hash of the off-chain memo
+pub fn handler(
+	ctx: Context<CreatePayItem>,
+	_employee_wallet: Pubkey,
+	item_id: u64,
+	kind: PayItemKind,
+	amount: i64,
+	memo_hash: [u8; 32],
+) -> Result<()> {
+	require!(amount != 0, EmployeePayrollError::InvalidPayItemAmount);
+	require!(kind != PayItemKind::FinalSettlement, EmployeePayrollError::InvalidPayItemKind);
+
+	let pay_item = &mut ctx.accounts.pay_item;
+
+	pay_item.employer = ctx.accounts.employer.key();
+	pay_item.employee = ctx.accounts.employee.key();
+	pay_item.item_id = item_id;
+	pay_item.kind = kind;
+	pay_item.amount = amount;
+	pay_item.memo_hash = memo_hash;
+	pay_item.batch = Pubkey::default();
+	pay_item.is_paid = false;
+	pay_item.created_at = Clock::get()?.unix_timestamp;
+	pay_item.version = PayItem::VERSION;
+
+	Ok(())
+}
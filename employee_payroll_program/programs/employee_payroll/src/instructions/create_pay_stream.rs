@@ -0,0 +1,96 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct CreatePayStream<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			address = employer.rate_approver @ EmployeePayrollError::Unauthorized,
+		)]
+		pub rate_approver: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = employee.is_active @ EmployeePayrollError::EmployeeInactive,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			init,
+			space=8 + PayStream::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"pay_stream",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub pay_stream: Account<'info, PayStream>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Start streaming pay to an employee, accruing every second from now on
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[signer]` rate_approver: [AccountInfo] Employer rate approver, as the stream pays outside
+///    the rate change policy
+/// 2. `[]` employer: [Employer] 
+/// 3. `[]` employee: [Employee] 
+/// 4. `[writable]` pay_stream: [PayStream] 
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - rate_per_second: [u64] Lamports accrued per second
+pub fn handler(
+	ctx: Context<CreatePayStream>,
+	_employee_wallet: Pubkey,
+	rate_per_second: u64,
+) -> Result<()> {
+	require!(rate_per_second > 0, EmployeePayrollError::InvalidStreamRate);
+
+	let now = Clock::get()?.unix_timestamp;
+	let pay_stream = &mut ctx.accounts.pay_stream;
+
+	pay_stream.employer = ctx.accounts.employer.key();
+	pay_stream.employee = ctx.accounts.employee.key();
+	pay_stream.rate_per_second = rate_per_second;
+	pay_stream.started_at = now;
+	pay_stream.last_accrual_at = now;
+	pay_stream.accrued = 0;
+	pay_stream.total_withdrawn = 0;
+	pay_stream.is_paused = false;
+	pay_stream.is_cancelled = false;
+	pay_stream.version = PayStream::VERSION;
+
+	emit!(PayStreamUpdated {
+		employee: pay_stream.employee,
+		pay_stream: pay_stream.key(),
+		rate_per_second,
+		is_paused: false,
+		is_cancelled: false,
+		accrued: 0,
+	});
+
+	Ok(())
+}
@@ -10,14 +10,21 @@ use std::str::FromStr;
 		timestamp: i64,
 	)]
 	pub struct CreatePayrollBatch<'info> {
+		#[account(mut)]
 		pub authority: Signer<'info>,
 
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
 		#[account(
 			init,
-			space=73,
+			space=8 + PayrollBatch::INIT_SPACE,
 			payer=authority,
 			seeds = [
 				b"payroll_batch",
+				employer.key().as_ref(),
 				batch_id.to_le_bytes().as_ref(),
 			],
 			bump,
@@ -31,8 +38,9 @@ use std::str::FromStr;
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` payroll_batch: [PayrollBatch] 
-/// 2. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - batch_id: [u64] 
@@ -42,7 +50,20 @@ pub fn handler(
 	batch_id: u64,
 	timestamp: i64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	let payroll_batch = &mut ctx.accounts.payroll_batch;
+
+	payroll_batch.batch_id = batch_id;
+	payroll_batch.total_amount = 0;
+	payroll_batch.created_at = timestamp;
+	payroll_batch.processed_at = 0;
+	payroll_batch.is_processed = false;
+	payroll_batch.authority = ctx.accounts.authority.key();
+	payroll_batch.employer = ctx.accounts.employer.key();
+	payroll_batch.version = PayrollBatch::VERSION;
+	payroll_batch.period_start = 0;
+	payroll_batch.period_end = 0;
+	payroll_batch.pay_date = 0;
+	payroll_batch.is_cancelled = false;
+
 	Ok(())
 }
@@ -0,0 +1,72 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		project_id: u64,
+		name: String,
+	)]
+	pub struct CreateProject<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			init,
+			space=8 + Project::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"project",
+				employer.key().as_ref(),
+				project_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub project: Account<'info, Project>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a project work sessions can be tagged with, billed to the client at `bill_rate`
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` project: [Project] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - project_id: [u64] Nonzero
+/// - name: [String] At most `MAX_PROJECT_NAME_LEN` bytes
+/// - bill_rate: [u64] Lamports per hour billed to the client, 0 for internal work
+pub fn handler(
+	ctx: Context<CreateProject>,
+	project_id: u64,
+	name: String,
+	bill_rate: u64,
+) -> Result<()> {
+	Project::validate(project_id, &name)?;
+
+	let project = &mut ctx.accounts.project;
+
+	project.employer = ctx.accounts.employer.key();
+	project.project_id = project_id;
+	project.name = name;
+	project.bill_rate = bill_rate;
+	project.is_active = true;
+	project.seconds_worked = 0;
+	project.billable_seconds = 0;
+	project.billable_amount = 0;
+	project.labor_cost = 0;
+	project.version = Project::VERSION;
+
+	Ok(())
+}
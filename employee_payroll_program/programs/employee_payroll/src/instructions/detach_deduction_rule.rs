@@ -0,0 +1,69 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		rule_id: u64,
+	)]
+	pub struct DetachDeductionRule<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			seeds = [
+				b"deduction_rule",
+				employer.key().as_ref(),
+				rule_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub deduction_rule: Account<'info, DeductionRule>,
+	}
+
+/// Stop applying a deduction rule to an employee
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[]` deduction_rule: [DeductionRule] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - rule_id: [u64] 
+pub fn handler(
+	ctx: Context<DetachDeductionRule>,
+	_employee_wallet: Pubkey,
+	_rule_id: u64,
+) -> Result<()> {
+	let rule_key = ctx.accounts.deduction_rule.key();
+	let deduction_rules = &mut ctx.accounts.employee.deduction_rules;
+
+	let position = deduction_rules
+		.iter()
+		.position(|key| *key == rule_key)
+		.ok_or(EmployeePayrollError::DeductionRuleNotAttached)?;
+	deduction_rules.remove(position);
+
+	Ok(())
+}
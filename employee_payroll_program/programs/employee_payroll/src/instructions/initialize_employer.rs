@@ -5,17 +5,20 @@ use std::str::FromStr;
 
 
 	#[derive(Accounts)]
+	#[instruction(
+		rate_approver: Pubkey,
+	)]
 	pub struct InitializeEmployer<'info> {
+		#[account(mut)]
 		pub authority: Signer<'info>,
 
 		#[account(
 			signer,
 			init,
-			space=8,
+			space=8 + Employer::INIT_SPACE,
 			payer=authority,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub employer: UncheckedAccount<'info>,
+		pub employer: Account<'info, Employer>,
 
 		pub system_program: Program<'info, System>,
 	}
@@ -24,12 +27,47 @@ use std::str::FromStr;
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable, signer]` employer: [AccountInfo] 
+/// 1. `[writable, signer]` employer: [Employer] 
 /// 2. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - rate_approver: [Pubkey] Co-signs large raises and rate policy changes, other than the authority
 pub fn handler(
 	ctx: Context<InitializeEmployer>,
+	rate_approver: Pubkey,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	require_keys_neq!(rate_approver, ctx.accounts.authority.key(), EmployeePayrollError::RateApproverIsAuthority);
+
+	let employer = &mut ctx.accounts.employer;
+
+	employer.authority = ctx.accounts.authority.key();
+	employer.paused = false;
+	employer.paused_by_guardian = false;
+	employer.payroll_manager = ctx.accounts.authority.key();
+	employer.daily_limit = 0;
+	employer.period_limit = 0;
+	employer.period_duration = 0;
+	employer.period_start = 0;
+	employer.period_spent = 0;
+	employer.hourly_outflow = [0; 24];
+	employer.last_outflow_hour = 0;
+	employer.rate_increase_threshold_bps = DEFAULT_RATE_INCREASE_THRESHOLD_BPS;
+	employer.rate_increase_delay = DEFAULT_RATE_INCREASE_DELAY;
+	employer.rate_approver = rate_approver;
+	employer.session_tree = Pubkey::default();
+	employer.compressed_session_count = 0;
+	employer.version = Employer::VERSION;
+	employer.fiscal_year_start_month = 1;
+	employer.advance_limit_bps = 0;
+	employer.payout_mode = PayoutMode::Push;
+	employer.max_price_age = DEFAULT_MAX_PRICE_AGE;
+	employer.max_price_conf_bps = DEFAULT_MAX_PRICE_CONF_BPS;
+	employer.pay_frequency = None;
+	employer.next_pay_period_start = 0;
+	employer.crank_tip = 0;
+	employer.reversal_window = 0;
+	employer.pto_payout_on_termination = false;
+	employer.pay_period_opened = false;
+
 	Ok(())
 }
@@ -0,0 +1,62 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		guardian: Pubkey,
+	)]
+	pub struct InitializeProgramConfig<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			init,
+			space=8 + ProgramConfig::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"program_config",
+			],
+			bump,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			constraint = program.programdata_address()? == Some(program_data.key()),
+		)]
+		pub program: Program<'info, crate::program::EmployeePayroll>,
+
+		#[account(
+			constraint = program_data.upgrade_authority_address == Some(authority.key()) @ EmployeePayrollError::Unauthorized,
+		)]
+		pub program_data: Account<'info, ProgramData>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Initialize the program-wide config holding the guardian key
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Program upgrade authority
+/// 1. `[writable]` program_config: [ProgramConfig] 
+/// 2. `[]` program: [AccountInfo] 
+/// 3. `[]` program_data: [AccountInfo] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - guardian: [Pubkey] 
+pub fn handler(
+	ctx: Context<InitializeProgramConfig>,
+	guardian: Pubkey,
+) -> Result<()> {
+	let program_config = &mut ctx.accounts.program_config;
+
+	program_config.guardian = guardian;
+	program_config.paused = false;
+	program_config.bump = ctx.bumps.program_config;
+	program_config.version = ProgramConfig::VERSION;
+
+	Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		max_depth: u32,
+		max_buffer_size: u32,
+	)]
+	pub struct InitializeSessionTree<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority,
+			constraint = employer.session_tree == Pubkey::default() @ EmployeePayrollError::SessionTreeAlreadyInitialized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		/// CHECK: allocated by the client and initialized by the compression program
+		#[account(
+			mut,
+			owner = SPL_ACCOUNT_COMPRESSION_ID,
+		)]
+		pub merkle_tree: UncheckedAccount<'info>,
+
+		/// CHECK: PDA signing for the employer's session tree
+		#[account(
+			seeds = [
+				b"session_tree",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub tree_authority: UncheckedAccount<'info>,
+
+		/// CHECK: checked by address
+		#[account(
+			address = SPL_ACCOUNT_COMPRESSION_ID,
+		)]
+		pub compression_program: UncheckedAccount<'info>,
+
+		/// CHECK: checked by address
+		#[account(
+			address = SPL_NOOP_ID,
+		)]
+		pub noop: UncheckedAccount<'info>,
+	}
+
+/// Initialize the concurrent Merkle tree that stores the employer's compressed work sessions
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[writable]` merkle_tree: [AccountInfo] Pre-allocated tree account owned by the compression program
+/// 3. `[]` tree_authority: [AccountInfo] 
+/// 4. `[]` compression_program: [AccountInfo] 
+/// 5. `[]` noop: [AccountInfo] 
+///
+/// Data:
+/// - max_depth: [u32] 
+/// - max_buffer_size: [u32] 
+pub fn handler(
+	ctx: Context<InitializeSessionTree>,
+	max_depth: u32,
+	max_buffer_size: u32,
+) -> Result<()> {
+	let employer_key = ctx.accounts.employer.key();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"session_tree",
+		employer_key.as_ref(),
+		&[ctx.bumps.tree_authority],
+	]];
+
+	SessionTreeCpi {
+		compression_program: ctx.accounts.compression_program.to_account_info(),
+		merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+		tree_authority: ctx.accounts.tree_authority.to_account_info(),
+		noop: ctx.accounts.noop.to_account_info(),
+		signer_seeds,
+	}
+	.init_empty_merkle_tree(max_depth, max_buffer_size)?;
+
+	let employer = &mut ctx.accounts.employer;
+	employer.session_tree = ctx.accounts.merkle_tree.key();
+	employer.compressed_session_count = 0;
+
+	Ok(())
+}
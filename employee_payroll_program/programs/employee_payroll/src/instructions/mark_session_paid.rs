@@ -12,8 +12,35 @@ use std::str::FromStr;
 	pub struct MarkSessionPaid<'info> {
 		pub authority: Signer<'info>,
 
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
 		#[account(
 			mut,
+			has_one = employee,
 			seeds = [
 				b"work_session",
 				employee_wallet.as_ref(),
@@ -22,23 +49,44 @@ use std::str::FromStr;
 			bump,
 		)]
 		pub work_session: Account<'info, WorkSession>,
+
+		#[account(
+			address = work_session.batch @ EmployeePayrollError::BatchMismatch,
+			has_one = employer,
+		)]
+		pub payroll_batch: Option<Account<'info, PayrollBatch>>,
 	}
 
 /// Mark a work session as paid
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` work_session: [WorkSession] 
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` employee: [Employee] 
+/// 4. `[writable]` work_session: [WorkSession] Checked-out session
+/// 5. `[]` payroll_batch: [PayrollBatch] Batch the session is attached to, omitted when it is not attached to one
 ///
 /// Data:
 /// - employee_wallet: [Pubkey] 
 /// - session_id: [u64] 
 pub fn handler(
 	ctx: Context<MarkSessionPaid>,
-	employee_wallet: Pubkey,
-	session_id: u64,
+	_employee_wallet: Pubkey,
+	_session_id: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	let work_session = &mut ctx.accounts.work_session;
+	require!(!work_session.is_paid, EmployeePayrollError::SessionAlreadyPaid);
+	require!(work_session.check_out_time != 0, EmployeePayrollError::SessionNotCheckedOut);
+	require!(
+		work_session.batch == Pubkey::default() || ctx.accounts.payroll_batch.is_some(),
+		EmployeePayrollError::BatchMismatch
+	);
+
+	work_session.is_paid = true;
+
+	let employee = &mut ctx.accounts.employee;
+	employee.unpaid_sessions = employee.unpaid_sessions.saturating_sub(1);
+
 	Ok(())
 }
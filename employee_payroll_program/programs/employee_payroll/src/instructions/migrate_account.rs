@@ -0,0 +1,120 @@
+use crate::*;
+use crate::state::legacy::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::{Discriminator, Space};
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	pub struct MigrateAccount<'info> {
+		#[account(
+			mut,
+		)]
+		pub payer: Signer<'info>,
+
+		/// CHECK: any program account, its layout is identified by discriminator and size
+		#[account(
+			mut,
+			owner = crate::ID,
+		)]
+		pub account: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Rewrite a program account from its original layout to the current version
+///
+/// Accounts:
+/// 0. `[writable, signer]` payer: [AccountInfo] Funds the extra rent when the account grows
+/// 1. `[writable]` account: [AccountInfo] Employee, WorkSession or PayrollBatch
+/// 2. `[]` system_program: [AccountInfo] 
+///    3..n. `[]` remaining accounts: the `Employer` to attach an employee or payroll batch to, owned
+///    by the payer
+pub fn handler(
+	ctx: Context<MigrateAccount>,
+) -> Result<()> {
+	let discriminator = ctx.accounts.account.try_borrow_data()?
+		.get(..8)
+		.ok_or(ErrorCode::AccountDiscriminatorNotFound)?
+		.to_vec();
+
+	match discriminator.as_slice() {
+		d if d == Employee::DISCRIMINATOR => {
+			let legacy = read_legacy::<EmployeeV0, Employee>(&ctx)?;
+			let employer = attached_employer(&ctx, &legacy.authority)?;
+			rewrite(&ctx, legacy.upgrade(employer))
+		}
+		d if d == WorkSession::DISCRIMINATOR => {
+			let legacy = read_legacy::<WorkSessionV0, WorkSession>(&ctx)?;
+			rewrite(&ctx, WorkSession::from(legacy))
+		}
+		d if d == PayrollBatch::DISCRIMINATOR => {
+			let legacy = read_legacy::<PayrollBatchV0, PayrollBatch>(&ctx)?;
+			let employer = attached_employer(&ctx, &legacy.authority)?;
+			rewrite(&ctx, legacy.upgrade(employer))
+		}
+		_ => err!(EmployeePayrollError::UnsupportedAccountLayout),
+	}
+}
+
+/// Layouts are told apart by size: an account sized for `Legacy` is read,
+/// one already sized for `Current` is left alone.
+fn read_legacy<Legacy, Current>(ctx: &Context<MigrateAccount>) -> Result<Legacy>
+where
+	Legacy: AnchorDeserialize + Space,
+	Current: Space,
+{
+	let data_len = ctx.accounts.account.data_len();
+	require_neq!(data_len, 8 + Current::INIT_SPACE, EmployeePayrollError::AccountAlreadyMigrated);
+	require_eq!(data_len, 8 + Legacy::INIT_SPACE, EmployeePayrollError::UnsupportedAccountLayout);
+
+	let legacy = Legacy::deserialize(&mut &ctx.accounts.account.try_borrow_data()?[8..])?;
+	Ok(legacy)
+}
+
+/// Original employees and payroll batches belong to no employer. The owner of
+/// the one they are attached to has to be their authority and pay for the
+/// migration.
+fn attached_employer(ctx: &Context<MigrateAccount>, authority: &Pubkey) -> Result<Pubkey> {
+	let employer_info = ctx.remaining_accounts.first().ok_or(EmployeePayrollError::InvalidRemainingAccounts)?;
+	require_keys_eq!(*employer_info.owner, crate::ID, EmployeePayrollError::InvalidRemainingAccounts);
+	let employer = Employer::try_deserialize(&mut &employer_info.try_borrow_data()?[..])?;
+
+	require_keys_eq!(employer.authority, *authority, EmployeePayrollError::Unauthorized);
+	require_keys_eq!(ctx.accounts.payer.key(), *authority, EmployeePayrollError::Unauthorized);
+
+	Ok(employer_info.key())
+}
+
+/// Resizes the account for `current`, topping up its rent from the payer,
+/// and writes `current` over it.
+fn rewrite<Current>(ctx: &Context<MigrateAccount>, current: Current) -> Result<()>
+where
+	Current: AccountSerialize + Space,
+{
+	let account = ctx.accounts.account.to_account_info();
+	let new_len = 8 + Current::INIT_SPACE;
+
+	let rent_due = Rent::get()?
+		.minimum_balance(new_len)
+		.saturating_sub(account.lamports());
+	if rent_due > 0 {
+		transfer(
+			CpiContext::new(
+				ctx.accounts.system_program.to_account_info(),
+				Transfer {
+					from: ctx.accounts.payer.to_account_info(),
+					to: account.clone(),
+				},
+			),
+			rent_due,
+		)?;
+	}
+
+	account.resize(new_len)?;
+	let mut data = account.try_borrow_mut_data()?;
+	data.fill(0);
+	current.try_serialize(&mut &mut data[..])
+}
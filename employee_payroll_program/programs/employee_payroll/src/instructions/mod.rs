@@ -7,6 +7,73 @@ pub mod check_out;
 pub mod create_payroll_batch;
 pub mod process_payroll;
 pub mod mark_session_paid;
+pub mod propose_payout_wallet;
+pub mod accept_payout_wallet;
+pub mod initialize_program_config;
+pub mod pause_employer;
+pub mod unpause_employer;
+pub mod pause_program;
+pub mod unpause_program;
+pub mod withdraw_from_vault;
+pub mod set_payroll_manager;
+pub mod set_spending_limits;
+pub mod set_rate_change_policy;
+pub mod apply_rate_change;
+pub mod veto_rate_change;
+pub mod complete_payroll_batch;
+pub mod close_work_session;
+pub mod close_work_sessions;
+pub mod initialize_session_tree;
+pub mod compress_work_session;
+pub mod process_compressed_payroll;
+pub mod migrate_account;
+pub mod create_deduction_rule;
+pub mod update_deduction_rule;
+pub mod attach_deduction_rule;
+pub mod detach_deduction_rule;
+pub mod set_fiscal_year;
+pub mod set_compensation;
+pub mod process_salary_payroll;
+pub mod create_pay_item;
+pub mod attach_pay_item;
+pub mod submit_expense;
+pub mod approve_expense;
+pub mod reject_expense;
+pub mod create_pay_stream;
+pub mod pause_pay_stream;
+pub mod resume_pay_stream;
+pub mod cancel_pay_stream;
+pub mod withdraw_streamed;
+pub mod set_advance_policy;
+pub mod request_advance;
+pub mod set_payout_splits;
+pub mod set_payout_mode;
+pub mod open_claimable_balance;
+pub mod claim;
+pub mod set_price_policy;
+pub mod set_rate_currency;
+pub mod set_pto_accrual;
+pub mod request_leave;
+pub mod approve_leave;
+pub mod create_department;
+pub mod set_department_budget;
+pub mod assign_department;
+pub mod create_project;
+pub mod update_project;
+pub mod set_pay_schedule;
+pub mod open_next_pay_period;
+pub mod approve_payroll_batch;
+pub mod set_crank_tip;
+pub mod crank_payroll;
+pub mod cancel_batch;
+pub mod set_reversal_window;
+pub mod claw_back_credit;
+pub mod set_pto_payout;
+pub mod terminate_employee;
+pub mod close_employee;
+pub mod approve_work_session;
+pub mod attach_work_session;
+pub mod process_leave_payroll;
 
 pub use initialize_employer::*;
 pub use register_employee::*;
@@ -16,3 +83,70 @@ pub use check_out::*;
 pub use create_payroll_batch::*;
 pub use process_payroll::*;
 pub use mark_session_paid::*;
+pub use propose_payout_wallet::*;
+pub use accept_payout_wallet::*;
+pub use initialize_program_config::*;
+pub use pause_employer::*;
+pub use unpause_employer::*;
+pub use pause_program::*;
+pub use unpause_program::*;
+pub use withdraw_from_vault::*;
+pub use set_payroll_manager::*;
+pub use set_spending_limits::*;
+pub use set_rate_change_policy::*;
+pub use apply_rate_change::*;
+pub use veto_rate_change::*;
+pub use complete_payroll_batch::*;
+pub use close_work_session::*;
+pub use close_work_sessions::*;
+pub use initialize_session_tree::*;
+pub use compress_work_session::*;
+pub use process_compressed_payroll::*;
+pub use migrate_account::*;
+pub use create_deduction_rule::*;
+pub use update_deduction_rule::*;
+pub use attach_deduction_rule::*;
+pub use detach_deduction_rule::*;
+pub use set_fiscal_year::*;
+pub use set_compensation::*;
+pub use process_salary_payroll::*;
+pub use create_pay_item::*;
+pub use attach_pay_item::*;
+pub use submit_expense::*;
+pub use approve_expense::*;
+pub use reject_expense::*;
+pub use create_pay_stream::*;
+pub use pause_pay_stream::*;
+pub use resume_pay_stream::*;
+pub use cancel_pay_stream::*;
+pub use withdraw_streamed::*;
+pub use set_advance_policy::*;
+pub use request_advance::*;
+pub use set_payout_splits::*;
+pub use set_payout_mode::*;
+pub use open_claimable_balance::*;
+pub use claim::*;
+pub use set_price_policy::*;
+pub use set_rate_currency::*;
+pub use set_pto_accrual::*;
+pub use request_leave::*;
+pub use approve_leave::*;
+pub use create_department::*;
+pub use set_department_budget::*;
+pub use assign_department::*;
+pub use create_project::*;
+pub use update_project::*;
+pub use set_pay_schedule::*;
+pub use open_next_pay_period::*;
+pub use approve_payroll_batch::*;
+pub use set_crank_tip::*;
+pub use crank_payroll::*;
+pub use cancel_batch::*;
+pub use set_reversal_window::*;
+pub use claw_back_credit::*;
+pub use set_pto_payout::*;
+pub use terminate_employee::*;
+pub use close_employee::*;
+pub use approve_work_session::*;
+pub use attach_work_session::*;
+pub use process_leave_payroll::*;
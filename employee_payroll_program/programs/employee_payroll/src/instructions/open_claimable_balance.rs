@@ -0,0 +1,62 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct OpenClaimableBalance<'info> {
+		#[account(mut)]
+		pub payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			init,
+			space=8 + ClaimableBalance::INIT_SPACE,
+			payer=payer,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub claimable_balance: Account<'info, ClaimableBalance>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Open the account an employee's net pay is credited to when their employer pays by claim
+///
+/// Accounts:
+/// 0. `[writable, signer]` payer: [AccountInfo] Anyone, usually the employer or the employee
+/// 1. `[]` employee: [Employee] 
+/// 2. `[writable]` claimable_balance: [ClaimableBalance] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+pub fn handler(
+	ctx: Context<OpenClaimableBalance>,
+	_employee_wallet: Pubkey,
+) -> Result<()> {
+	let claimable_balance = &mut ctx.accounts.claimable_balance;
+
+	claimable_balance.employer = ctx.accounts.employee.employer;
+	claimable_balance.employee = ctx.accounts.employee.key();
+	claimable_balance.amount = 0;
+	claimable_balance.total_claimed = 0;
+	claimable_balance.version = ClaimableBalance::VERSION;
+
+	Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	pub struct OpenNextPayPeriod<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			init,
+			space=8 + PayrollBatch::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				Employer::pay_period_batch_id(&employer.key(), employer.next_pay_period_start).to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Open a payroll batch for the employer's next scheduled pay period
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] Batch id from `Employer::pay_period_batch_id`
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+pub fn handler(
+	ctx: Context<OpenNextPayPeriod>,
+) -> Result<()> {
+	let employer = &mut ctx.accounts.employer;
+	let pay_frequency = employer.pay_frequency.ok_or(EmployeePayrollError::PayScheduleNotSet)?;
+	let now = Clock::get()?.unix_timestamp;
+	require!(now >= employer.next_pay_period_start, EmployeePayrollError::PayPeriodNotStarted);
+
+	let period_start = employer.next_pay_period_start;
+	let period_end = pay_frequency.next_period_start(period_start);
+	let batch_id = Employer::pay_period_batch_id(&employer.key(), period_start);
+	employer.next_pay_period_start = period_end;
+	employer.pay_period_opened = true;
+
+	let payroll_batch = &mut ctx.accounts.payroll_batch;
+
+	payroll_batch.batch_id = batch_id;
+	payroll_batch.total_amount = 0;
+	payroll_batch.created_at = now;
+	payroll_batch.processed_at = 0;
+	payroll_batch.is_processed = false;
+	payroll_batch.authority = ctx.accounts.authority.key();
+	payroll_batch.employer = employer.key();
+	payroll_batch.version = PayrollBatch::VERSION;
+	payroll_batch.period_start = period_start;
+	payroll_batch.period_end = period_end;
+	payroll_batch.pay_date = 0;
+	payroll_batch.is_cancelled = false;
+
+	emit!(PayPeriodOpened {
+		employer: employer.key(),
+		payroll_batch: payroll_batch.key(),
+		batch_id,
+		period_start,
+		period_end,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	pub struct PauseEmployer<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+	}
+
+/// Freeze payouts, vault withdrawals and check-ins for an employer
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or program guardian
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+pub fn handler(
+	ctx: Context<PauseEmployer>,
+) -> Result<()> {
+	let authority = ctx.accounts.authority.key();
+	let by_guardian = authority == ctx.accounts.program_config.guardian;
+	require!(
+		by_guardian || authority == ctx.accounts.employer.authority,
+		EmployeePayrollError::Unauthorized
+	);
+
+	let employer = &mut ctx.accounts.employer;
+	employer.paused = true;
+	employer.paused_by_guardian = employer.paused_by_guardian || by_guardian;
+
+	emit!(EmployerPaused {
+		employer: employer.key(),
+		paused_by: authority,
+		by_guardian,
+	});
+
+	Ok(())
+}
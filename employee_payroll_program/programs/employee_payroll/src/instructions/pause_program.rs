@@ -0,0 +1,37 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	pub struct PauseProgram<'info> {
+		pub guardian: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			has_one = guardian @ EmployeePayrollError::Unauthorized,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+	}
+
+/// Freeze payouts, vault withdrawals and check-ins for every employer
+///
+/// Accounts:
+/// 0. `[signer]` guardian: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] 
+pub fn handler(
+	ctx: Context<PauseProgram>,
+) -> Result<()> {
+	ctx.accounts.program_config.paused = true;
+
+	emit!(ProgramPaused {
+		guardian: ctx.accounts.guardian.key(),
+	});
+
+	Ok(())
+}
@@ -0,0 +1,249 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		batch_id: u64,
+		root: [u8; 32],
+		session: CompressedWorkSession,
+		index: u32,
+	)]
+	pub struct ProcessCompressedPayroll<'info> {
+		#[account(
+			mut,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			address = employer.authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub owner: Option<Signer<'info>>,
+
+		#[account(
+			mut,
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+			constraint = employer.session_tree != Pubkey::default() @ EmployeePayrollError::SessionTreeNotInitialized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = !payroll_batch.is_processed @ EmployeePayrollError::BatchAlreadyProcessed,
+			constraint = !payroll_batch.is_cancelled @ EmployeePayrollError::BatchCancelled,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = employee.compensation == Compensation::Hourly @ EmployeePayrollError::CompensationMismatch,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			address = employee.payout_wallet @ EmployeePayrollError::PayoutWalletMismatch,
+		)]
+		pub payout_wallet: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub claimable_balance: Option<Account<'info, ClaimableBalance>>,
+
+		/// CHECK: parsed as a price update by `convert_wages`
+		#[account(
+			address = employee.rate_price_feed @ EmployeePayrollError::PriceFeedMismatch,
+		)]
+		pub price_feed: Option<UncheckedAccount<'info>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = department.department_id == employee.department_id @ EmployeePayrollError::DepartmentMismatch,
+		)]
+		pub department: Option<Account<'info, Department>>,
+
+		/// CHECK: checked against the employer's session tree
+		#[account(
+			mut,
+			address = employer.session_tree,
+		)]
+		pub merkle_tree: UncheckedAccount<'info>,
+
+		/// CHECK: PDA signing for the employer's session tree
+		#[account(
+			seeds = [
+				b"session_tree",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub tree_authority: UncheckedAccount<'info>,
+
+		/// CHECK: checked by address
+		#[account(
+			address = SPL_ACCOUNT_COMPRESSION_ID,
+		)]
+		pub compression_program: UncheckedAccount<'info>,
+
+		/// CHECK: checked by address
+		#[account(
+			address = SPL_NOOP_ID,
+		)]
+		pub noop: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Pay a compressed work session, proving it against the session tree and marking its leaf paid
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[signer]` owner: [AccountInfo] Optional owner co-signature for payouts above the spending limits
+/// 2. `[writable]` employer: [Employer] 
+/// 3. `[]` program_config: [ProgramConfig] 
+/// 4. `[writable]` payroll_batch: [PayrollBatch] 
+/// 5. `[writable]` employee: [Employee] 
+/// 6. `[writable]` vault: [AccountInfo] 
+/// 7. `[writable]` payout_wallet: [AccountInfo] 
+/// 8. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 9. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 10. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 11. `[writable]` merkle_tree: [AccountInfo] 
+/// 12. `[]` tree_authority: [AccountInfo] 
+/// 13. `[]` compression_program: [AccountInfo] 
+/// 14. `[]` noop: [AccountInfo] 
+/// 15. `[]` system_program: [AccountInfo] 
+///     16..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     then a wallet for each of the employee's payout splits when paying directly
+///     n... `[]` remaining accounts: Merkle proof nodes, leaf to root
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - root: [[u8; 32]] Current root of the session tree
+/// - session: [CompressedWorkSession] Leaf contents, as emitted by `compress_work_session`
+/// - index: [u32] Leaf index
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, ProcessCompressedPayroll<'info>>,
+	_batch_id: u64,
+	root: [u8; 32],
+	session: CompressedWorkSession,
+	index: u32,
+) -> Result<()> {
+	require_keys_eq!(session.employer, ctx.accounts.employer.key(), EmployeePayrollError::CompressedSessionMismatch);
+	require_keys_eq!(session.employee, ctx.accounts.employee.key(), EmployeePayrollError::CompressedSessionMismatch);
+	require!(
+		session.batch == Pubkey::default() || session.batch == ctx.accounts.payroll_batch.key(),
+		EmployeePayrollError::BatchMismatch
+	);
+
+	let mut paid_session = session.clone();
+	paid_session.is_paid = true;
+	paid_session.batch = ctx.accounts.payroll_batch.key();
+
+	let deduction_count = 2 * ctx.accounts.employee.deduction_rules.len();
+	let split_count = payout_split_count(&ctx.accounts.employer, &ctx.accounts.employee);
+	require_gte!(ctx.remaining_accounts.len(), deduction_count, EmployeePayrollError::DeductionAccountsMismatch);
+	require_gte!(
+		ctx.remaining_accounts.len(),
+		deduction_count + split_count,
+		EmployeePayrollError::PayoutSplitMismatch
+	);
+	let (deduction_accounts, rest) = ctx.remaining_accounts.split_at(deduction_count);
+	let (split_accounts, proof) = rest.split_at(split_count);
+
+	// The compression program rejects a stale proof or a leaf that does not
+	// match, so a session can only be flipped to paid once.
+	let employer_key = ctx.accounts.employer.key();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"session_tree",
+		employer_key.as_ref(),
+		&[ctx.bumps.tree_authority],
+	]];
+
+	SessionTreeCpi {
+		compression_program: ctx.accounts.compression_program.to_account_info(),
+		merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+		tree_authority: ctx.accounts.tree_authority.to_account_info(),
+		noop: ctx.accounts.noop.to_account_info(),
+		signer_seeds,
+	}
+	.replace_leaf(proof, root, session.leaf(), paid_session.leaf(), index)?;
+
+	// Paid like the live session it was made from, which had no pay items
+	// or expenses attached.
+	let mut work_session = session.to_session();
+	let owner_signed = ctx.accounts.authority.key() == ctx.accounts.employer.authority
+		|| ctx.accounts.owner.is_some();
+	pay_work_session(
+		SessionPayment {
+			employer: &mut ctx.accounts.employer,
+			payroll_batch: &mut ctx.accounts.payroll_batch,
+			employee: &mut ctx.accounts.employee,
+			work_session: &mut work_session,
+			vault: &ctx.accounts.vault,
+			vault_bump: ctx.bumps.vault,
+			payout_wallet: &ctx.accounts.payout_wallet,
+			claimable_balance: ctx.accounts.claimable_balance.as_mut(),
+			price_feed: ctx.accounts.price_feed.as_ref(),
+			department: ctx.accounts.department.as_mut(),
+			project: None,
+			system_program: &ctx.accounts.system_program,
+		},
+		PayrollRemainingAccounts {
+			deductions: deduction_accounts,
+			payout_splits: split_accounts,
+			pay_items: &[],
+			expenses: &[],
+		},
+		None,
+		owner_signed,
+	)?;
+
+	emit!(CompressedSessionPaid {
+		merkle_tree: ctx.accounts.merkle_tree.key(),
+		leaf_index: index,
+		session: paid_session,
+		amount: work_session.payment.gross,
+		exchange_rate: work_session.exchange_rate,
+	});
+
+	Ok(())
+}
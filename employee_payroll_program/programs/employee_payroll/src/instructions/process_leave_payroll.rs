@@ -0,0 +1,160 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		batch_id: u64,
+		timestamp: i64,
+	)]
+	pub struct ProcessLeavePayroll<'info> {
+		#[account(
+			mut,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			address = employer.authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub owner: Option<Signer<'info>>,
+
+		#[account(
+			mut,
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = employee.is_active @ EmployeePayrollError::EmployeeInactive,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		/// CHECK: parsed as a price update by `convert_wages`
+		#[account(
+			address = employee.rate_price_feed @ EmployeePayrollError::PriceFeedMismatch,
+		)]
+		pub price_feed: Option<UncheckedAccount<'info>>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			address = employee.payout_wallet @ EmployeePayrollError::PayoutWalletMismatch,
+		)]
+		pub payout_wallet: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub claimable_balance: Option<Account<'info, ClaimableBalance>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = department.department_id == employee.department_id @ EmployeePayrollError::DepartmentMismatch,
+		)]
+		pub department: Option<Account<'info, Department>>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Pay an hourly employee's approved leave on its own, for a pay period they have no work session to pay it with
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[signer]` owner: [AccountInfo] Optional owner co-signature for payouts above the spending limits
+/// 2. `[writable]` employer: [Employer] 
+/// 3. `[]` program_config: [ProgramConfig] 
+/// 4. `[writable]` payroll_batch: [PayrollBatch] 
+/// 5. `[writable]` employee: [Employee] 
+/// 6. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 7. `[writable]` vault: [AccountInfo] 
+/// 8. `[writable]` payout_wallet: [AccountInfo] 
+/// 9. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 10. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 11. `[]` system_program: [AccountInfo] 
+///     12..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     a wallet for each of the employee's payout splits when paying directly, then any `PayItem`s attached to the batch and approved `Expense`s to pay alongside the leave
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - timestamp: [i64] Unused, `processed_at` is taken from the clock
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, ProcessLeavePayroll<'info>>,
+	_batch_id: u64,
+	_timestamp: i64,
+) -> Result<()> {
+	let now = Clock::get()?.unix_timestamp;
+	let leave = take_unpaid_leave(&mut ctx.accounts.employee, &ctx.accounts.payroll_batch.key());
+	require!(leave > 0, EmployeePayrollError::NoUnpaidLeave);
+	let (leave_pay, _exchange_rate) = convert_wages(
+		&ctx.accounts.employer,
+		&ctx.accounts.employee,
+		ctx.accounts.price_feed.as_ref(),
+		session_pay(leave, ctx.accounts.employee.hourly_rate)?,
+		now,
+	)?;
+	let remaining = split_remaining_accounts(&ctx.accounts.employer, &ctx.accounts.employee, ctx.remaining_accounts);
+	let owner_signed = ctx.accounts.authority.key() == ctx.accounts.employer.authority
+		|| ctx.accounts.owner.is_some();
+	pay_employee(
+		EmployeePayment {
+			employer: &mut ctx.accounts.employer,
+			payroll_batch: &mut ctx.accounts.payroll_batch,
+			employee: &mut ctx.accounts.employee,
+			vault: &ctx.accounts.vault,
+			vault_bump: ctx.bumps.vault,
+			payout_wallet: &ctx.accounts.payout_wallet,
+			claimable_balance: ctx.accounts.claimable_balance.as_mut(),
+			department: ctx.accounts.department.as_mut(),
+			system_program: &ctx.accounts.system_program,
+		},
+		remaining,
+		leave_pay,
+		false,
+		None,
+		owner_signed,
+	)?;
+
+	Ok(())
+}
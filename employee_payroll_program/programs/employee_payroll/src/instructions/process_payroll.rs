@@ -1,5 +1,7 @@
 use crate::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::system_program::{transfer, Transfer};
 use std::str::FromStr;
 
 
@@ -15,44 +17,749 @@ use std::str::FromStr;
 		)]
 		pub authority: Signer<'info>,
 
+		#[account(
+			address = employer.authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub owner: Option<Signer<'info>>,
+
+		#[account(
+			mut,
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
 		#[account(
 			mut,
 			seeds = [
 				b"payroll_batch",
+				employer.key().as_ref(),
 				batch_id.to_le_bytes().as_ref(),
 			],
 			bump,
+			has_one = employer,
 		)]
 		pub payroll_batch: Account<'info, PayrollBatch>,
 
 		#[account(
 			mut,
+			has_one = employer,
+			constraint = employee.compensation == Compensation::Hourly @ EmployeePayrollError::CompensationMismatch,
 		)]
 		pub employee: Account<'info, Employee>,
 
 		#[account(
 			mut,
+			has_one = employee,
 		)]
 		pub work_session: Account<'info, WorkSession>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			address = employee.payout_wallet @ EmployeePayrollError::PayoutWalletMismatch,
+		)]
+		pub payout_wallet: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub claimable_balance: Option<Account<'info, ClaimableBalance>>,
+
+		/// CHECK: parsed as a price update by `convert_wages`
+		#[account(
+			address = employee.rate_price_feed @ EmployeePayrollError::PriceFeedMismatch,
+		)]
+		pub price_feed: Option<UncheckedAccount<'info>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = department.department_id == employee.department_id @ EmployeePayrollError::DepartmentMismatch,
+		)]
+		pub department: Option<Account<'info, Department>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = project.project_id == work_session.project_id @ EmployeePayrollError::ProjectMismatch,
+		)]
+		pub project: Option<Account<'info, Project>>,
+
+		pub system_program: Program<'info, System>,
 	}
 
 /// Process payments for all employees in a batch
 ///
 /// Accounts:
-/// 0. `[writable, signer]` authority: [AccountInfo] 
-/// 1. `[writable]` payroll_batch: [PayrollBatch] 
-/// 2. `[writable]` employee: [Employee] 
-/// 3. `[writable]` work_session: [WorkSession] 
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[signer]` owner: [AccountInfo] Optional owner co-signature for payouts above the spending limits
+/// 2. `[writable]` employer: [Employer] 
+/// 3. `[]` program_config: [ProgramConfig] 
+/// 4. `[writable]` payroll_batch: [PayrollBatch] 
+/// 5. `[writable]` employee: [Employee] 
+/// 6. `[writable]` work_session: [WorkSession] 
+/// 7. `[writable]` vault: [AccountInfo] 
+/// 8. `[writable]` payout_wallet: [AccountInfo] 
+/// 9. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 10. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 11. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 12. `[writable]` project: [Project] Optional, required when the session was worked on a project
+/// 13. `[]` system_program: [AccountInfo] 
+///     14..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     a wallet for each of the employee's payout splits when paying directly, then any `PayItem`s attached to the batch and approved `Expense`s to pay alongside the session
 ///
 /// Data:
 /// - batch_id: [u64] 
-/// - timestamp: [i64] 
-pub fn handler(
-	ctx: Context<ProcessPayroll>,
-	batch_id: u64,
-	timestamp: i64,
+/// - timestamp: [i64] Unused, `processed_at` is taken from the clock
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, ProcessPayroll<'info>>,
+	_batch_id: u64,
+	_timestamp: i64,
+) -> Result<()> {
+	// Sessions a manager attached to another batch are paid there
+	let batch = ctx.accounts.work_session.batch;
+	require!(
+		batch == Pubkey::default() || batch == ctx.accounts.payroll_batch.key(),
+		EmployeePayrollError::BatchMismatch
+	);
+
+	let remaining = split_remaining_accounts(&ctx.accounts.employer, &ctx.accounts.employee, ctx.remaining_accounts);
+	let owner_signed = ctx.accounts.authority.key() == ctx.accounts.employer.authority
+		|| ctx.accounts.owner.is_some();
+	pay_work_session(
+		SessionPayment {
+			employer: &mut ctx.accounts.employer,
+			payroll_batch: &mut ctx.accounts.payroll_batch,
+			employee: &mut ctx.accounts.employee,
+			work_session: &mut ctx.accounts.work_session,
+			vault: &ctx.accounts.vault,
+			vault_bump: ctx.bumps.vault,
+			payout_wallet: &ctx.accounts.payout_wallet,
+			claimable_balance: ctx.accounts.claimable_balance.as_mut(),
+			price_feed: ctx.accounts.price_feed.as_ref(),
+			department: ctx.accounts.department.as_mut(),
+			project: ctx.accounts.project.as_mut(),
+			system_program: &ctx.accounts.system_program,
+		},
+		remaining,
+		None,
+		owner_signed,
+	)
+}
+
+/// Accounts `pay_work_session` pays a session with, borrowed from the
+/// instruction paying it.
+pub(crate) struct SessionPayment<'a, 'info> {
+	pub employer: &'a mut Account<'info, Employer>,
+	pub payroll_batch: &'a mut Account<'info, PayrollBatch>,
+	pub employee: &'a mut Account<'info, Employee>,
+	pub work_session: &'a mut WorkSession,
+	pub vault: &'a SystemAccount<'info>,
+	pub vault_bump: u8,
+	pub payout_wallet: &'a SystemAccount<'info>,
+	pub claimable_balance: Option<&'a mut Account<'info, ClaimableBalance>>,
+	pub price_feed: Option<&'a UncheckedAccount<'info>>,
+	pub department: Option<&'a mut Account<'info, Department>>,
+	pub project: Option<&'a mut Account<'info, Project>>,
+	pub system_program: &'a Program<'info, System>,
+}
+
+/// Pays a checked-out work session in its batch: the hours worked, at the
+/// rate the session was checked out at, and any approved leave at the current
+/// rate, the `PayItem`s and `Expense`s in `remaining`, less
+/// withholding and outstanding advances. `tip` leaves the vault with the
+/// payouts and counts towards the employer's spending limits.
+pub(crate) fn pay_work_session<'info>(
+	accounts: SessionPayment<'_, 'info>,
+	remaining: PayrollRemainingAccounts<'info, 'info>,
+	tip: Option<(AccountInfo<'info>, u64)>,
+	owner_signed: bool,
+) -> Result<()> {
+	let SessionPayment {
+		employer,
+		payroll_batch,
+		employee,
+		work_session,
+		vault,
+		vault_bump,
+		payout_wallet,
+		claimable_balance,
+		price_feed,
+		department,
+		project,
+		system_program,
+	} = accounts;
+
+	require!(!work_session.is_paid, EmployeePayrollError::SessionAlreadyPaid);
+	require!(work_session.check_out_time != 0, EmployeePayrollError::SessionNotCheckedOut);
+	require!(
+		payroll_batch.covers(work_session.check_in_time, work_session.check_out_time),
+		EmployeePayrollError::SessionOutsidePayPeriod
+	);
+
+	let now = Clock::get()?.unix_timestamp;
+	let batch_key = payroll_batch.key();
+	let leave = take_unpaid_leave(employee, &batch_key);
+	let hourly_rate = match work_session.hourly_rate {
+		0 => employee.hourly_rate,
+		hourly_rate => hourly_rate,
+	};
+	let worked_pay = session_pay(work_session.duration, hourly_rate)?;
+	let wages = session_pay(leave, employee.hourly_rate)?
+		.checked_add(worked_pay)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	let (session_amount, exchange_rate) = convert_wages(employer, employee, price_feed, wages, now)?;
+	let mut labor_cost = 0;
+	if work_session.project_id != 0 {
+		// Approved leave paid alongside the session is not project work
+		labor_cost = ((session_amount as u128) * (worked_pay as u128))
+			.checked_div(wages as u128)
+			.unwrap_or(0) as u64;
+		project
+			.ok_or(EmployeePayrollError::ProjectRequired)?
+			.record_labor_cost(labor_cost)?;
+	}
+
+	let (payment, net) = pay_employee(
+		EmployeePayment {
+			employer: &mut *employer,
+			payroll_batch,
+			employee: &mut *employee,
+			vault,
+			vault_bump,
+			payout_wallet,
+			claimable_balance,
+			department,
+			system_program,
+		},
+		remaining,
+		session_amount,
+		true,
+		tip,
+		owner_signed,
+	)?;
+
+	work_session.is_paid = true;
+	work_session.batch = batch_key;
+	work_session.exchange_rate = exchange_rate;
+	work_session.credited = match employer.payout_mode {
+		PayoutMode::Push => 0,
+		PayoutMode::Claim => net,
+	};
+	work_session.payment = PaymentRecord {
+		leave_paid: leave,
+		labor_cost,
+		..payment
+	};
+
+	employee.unpaid_sessions = employee.unpaid_sessions.saturating_sub(1);
+
+	Ok(())
+}
+
+/// Accounts `pay_employee` pays with, borrowed from the instruction paying.
+pub(crate) struct EmployeePayment<'a, 'info> {
+	pub employer: &'a mut Account<'info, Employer>,
+	pub payroll_batch: &'a mut Account<'info, PayrollBatch>,
+	pub employee: &'a mut Account<'info, Employee>,
+	pub vault: &'a SystemAccount<'info>,
+	pub vault_bump: u8,
+	pub payout_wallet: &'a SystemAccount<'info>,
+	pub claimable_balance: Option<&'a mut Account<'info, ClaimableBalance>>,
+	pub department: Option<&'a mut Account<'info, Department>>,
+	pub system_program: &'a Program<'info, System>,
+}
+
+/// Pays the employee `wages` in lamports from the batch, with the `PayItem`s
+/// and `Expense`s in `remaining`, less withholding and outstanding advances,
+/// and books the payment. A `reversible` credit is held from `claim` for the
+/// employer's reversal window. Returns the payment and the net pay sent or
+/// credited; leave and project labor cost are left for the caller to record.
+pub(crate) fn pay_employee<'info>(
+	accounts: EmployeePayment<'_, 'info>,
+	remaining: PayrollRemainingAccounts<'info, 'info>,
+	wages: u64,
+	reversible: bool,
+	tip: Option<(AccountInfo<'info>, u64)>,
+	owner_signed: bool,
+) -> Result<(PaymentRecord, u64)> {
+	let EmployeePayment {
+		employer,
+		payroll_batch,
+		employee,
+		vault,
+		vault_bump,
+		payout_wallet,
+		claimable_balance,
+		department,
+		system_program,
+	} = accounts;
+
+	require!(!payroll_batch.is_processed, EmployeePayrollError::BatchAlreadyProcessed);
+	require!(!payroll_batch.is_cancelled, EmployeePayrollError::BatchCancelled);
+
+	let now = Clock::get()?.unix_timestamp;
+	let batch_key = payroll_batch.key();
+	let paid_with = paid_with_hash(remaining.pay_items, remaining.expenses);
+	let adjustment_before = employee.outstanding_adjustment;
+	let amount = apply_pay_items(employee, &batch_key, remaining.pay_items, wages)?;
+	let reimbursed = reimburse_expenses(employee, &batch_key, remaining.expenses)?;
+	let (mut payouts, withheld) = withhold(employer, employee, remaining.deductions, amount, now)?;
+	let wages = recover_advance(employee, amount - withheld);
+	let net = wages
+		.checked_add(reimbursed)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	let reversible_until = match employer.payout_mode {
+		PayoutMode::Claim if reversible => now + employer.reversal_window,
+		_ => now,
+	};
+	match employer.payout_mode {
+		PayoutMode::Push => push_net_payouts(
+			employee,
+			remaining.payout_splits,
+			payout_wallet.to_account_info(),
+			net,
+			&mut payouts,
+		)?,
+		PayoutMode::Claim => {
+			credit_claimable_balance(claimable_balance, net, Some((now, reversible_until)), &mut payouts)?
+		}
+	}
+
+	payouts.extend(tip);
+	pay_from_vault(employer, vault, vault_bump, system_program, &payouts, owner_signed)?;
+	charge_department(employer, employee, department, amount, owner_signed)?;
+
+	employee.total_paid = employee
+		.total_paid
+		.checked_add(amount)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	employee.record_earnings(
+		employer,
+		now,
+		&EarningsTotals {
+			gross: amount,
+			net: amount - withheld,
+			deductions: withheld,
+			..Default::default()
+		},
+	)?;
+
+	payroll_batch.total_amount = payroll_batch
+		.total_amount
+		.checked_add(amount)
+		.and_then(|total| total.checked_add(reimbursed))
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	payroll_batch.processed_at = now;
+
+	let payment = PaymentRecord {
+		paid_at: now,
+		reversible_until,
+		gross: amount,
+		withheld,
+		reimbursed,
+		advance_recovered: amount - withheld - wages,
+		adjustment_recovered: adjustment_before as i64 - employee.outstanding_adjustment as i64,
+		leave_paid: 0,
+		labor_cost: 0,
+		department_id: employee.department_id,
+		paid_with,
+	};
+
+	Ok((payment, net))
+}
+
+/// Wages owed for `duration` seconds at `hourly_rate` per hour, in the unit
+/// the rate is set in.
+pub(crate) fn session_pay(duration: u64, hourly_rate: u64) -> Result<u64> {
+	u64::try_from((duration as u128) * (hourly_rate as u128) / 3_600)
+		.map_err(|_| EmployeePayrollError::ArithmeticOverflow.into())
+}
+
+/// Takes the employee's approved leave that has not been paid yet, in
+/// seconds, to be paid at `hourly_rate` alongside a work session or on its
+/// own by `process_leave_payroll`.
+pub(crate) fn take_unpaid_leave(employee: &mut Account<Employee>, payroll_batch: &Pubkey) -> u64 {
+	let leave = std::mem::take(&mut employee.unpaid_leave);
+	if leave > 0 {
+		emit!(LeavePaid {
+			employee: employee.key(),
+			duration: leave,
+			batch: *payroll_batch,
+		});
+	}
+
+	leave
+}
+
+/// Converts `wages` worked out at the employee's `hourly_rate` to lamports.
+/// Rates priced in a reference currency are converted at the price in
+/// `price_feed`. Returns the lamports and the lamports per currency unit
+/// used, which is 0 for rates already in lamports.
+pub(crate) fn convert_wages(
+	employer: &Employer,
+	employee: &Employee,
+	price_feed: Option<&UncheckedAccount>,
+	wages: u64,
+	now: i64,
+) -> Result<(u64, u64)> {
+	if employee.rate_price_feed == Pubkey::default() {
+		return Ok((wages, 0));
+	}
+
+	let price_feed = price_feed.ok_or(EmployeePayrollError::PriceFeedRequired)?;
+	let exchange_rate = PriceUpdate::load(price_feed, &employee.rate_feed_id)?.lamports_per_unit(
+		now,
+		employer.max_price_age,
+		employer.max_price_conf_bps,
+	)?;
+	let lamports = u64::try_from((wages as u128) * (exchange_rate as u128) / 10u128.pow(REFERENCE_RATE_DECIMALS))
+		.map_err(|_| EmployeePayrollError::ArithmeticOverflow)?;
+
+	Ok((lamports, exchange_rate))
+}
+
+/// The remaining accounts of a payroll instruction, in the order they are
+/// passed.
+pub(crate) struct PayrollRemainingAccounts<'a, 'info> {
+	pub deductions: &'a [AccountInfo<'info>],
+	pub payout_splits: &'a [AccountInfo<'info>],
+	pub pay_items: &'a [AccountInfo<'info>],
+	pub expenses: &'a [AccountInfo<'info>],
+}
+
+/// Number of payout split wallets a payroll instruction expects: none when
+/// the employer pays by claim, as splits are applied by `claim` instead.
+pub(crate) fn payout_split_count(employer: &Employer, employee: &Employee) -> usize {
+	match employer.payout_mode {
+		PayoutMode::Push => employee.payout_splits.len(),
+		PayoutMode::Claim => 0,
+	}
+}
+
+/// Separates the remaining accounts into the `(deduction_rule,
+/// remittance_vault)` pairs that come first, one wallet per payout split,
+/// the `PayItem`s after them and the `Expense`s at the end.
+pub(crate) fn split_remaining_accounts<'a, 'info>(
+	employer: &Employer,
+	employee: &Employee,
+	remaining_accounts: &'a [AccountInfo<'info>],
+) -> PayrollRemainingAccounts<'a, 'info> {
+	let deduction_count = (2 * employee.deduction_rules.len()).min(remaining_accounts.len());
+	let (deductions, rest) = remaining_accounts.split_at(deduction_count);
+	let (payout_splits, rest) = rest.split_at(payout_split_count(employer, employee).min(rest.len()));
+	let pay_item_count = rest
+		.iter()
+		.take_while(|info| !info.try_borrow_data().is_ok_and(|data| data.starts_with(Expense::DISCRIMINATOR)))
+		.count();
+	let (pay_items, expenses) = rest.split_at(pay_item_count);
+	PayrollRemainingAccounts {
+		deductions,
+		payout_splits,
+		pay_items,
+		expenses,
+	}
+}
+
+/// Adds the employee's unpaid `PayItem`s attached to `payroll_batch` to
+/// `amount` and marks them paid, then recovers negative items and any
+/// `outstanding_adjustment` from the total. What cannot be recovered from
+/// this payout carries over to the next one.
+pub(crate) fn apply_pay_items<'info>(
+	employee: &mut Account<'info, Employee>,
+	payroll_batch: &Pubkey,
+	pay_items: &'info [AccountInfo<'info>],
+	amount: u64,
+) -> Result<u64> {
+	let mut total = amount as i128 - employee.outstanding_adjustment as i128;
+	for pay_item_info in pay_items {
+		let mut pay_item = Account::<PayItem>::try_from(pay_item_info)?;
+		require_keys_eq!(pay_item.employee, employee.key(), EmployeePayrollError::PayItemMismatch);
+		require_keys_eq!(pay_item.batch, *payroll_batch, EmployeePayrollError::PayItemMismatch);
+		require!(!pay_item.is_paid, EmployeePayrollError::PayItemAlreadyPaid);
+
+		pay_item.is_paid = true;
+		pay_item.exit(&crate::ID)?;
+		total += pay_item.amount as i128;
+
+		emit!(PayItemPaid {
+			employee: employee.key(),
+			pay_item: pay_item.key(),
+			kind: pay_item.kind,
+			amount: pay_item.amount,
+			batch: *payroll_batch,
+		});
+	}
+
+	employee.outstanding_adjustment = u64::try_from((-total).max(0))
+		.map_err(|_| EmployeePayrollError::ArithmeticOverflow)?;
+	u64::try_from(total.max(0)).map_err(|_| EmployeePayrollError::ArithmeticOverflow.into())
+}
+
+/// Marks the employee's approved `Expense`s paid in `payroll_batch` and
+/// returns their total, which is reimbursed on top of the net payout.
+pub(crate) fn reimburse_expenses<'info>(
+	employee: &mut Account<'info, Employee>,
+	payroll_batch: &Pubkey,
+	expenses: &'info [AccountInfo<'info>],
+) -> Result<u64> {
+	let mut reimbursed = 0u64;
+	for expense_info in expenses {
+		let mut expense = Account::<Expense>::try_from(expense_info)?;
+		require_keys_eq!(expense.employee, employee.key(), EmployeePayrollError::ExpenseMismatch);
+		require!(expense.status == ExpenseStatus::Approved, EmployeePayrollError::ExpenseNotApproved);
+
+		expense.status = ExpenseStatus::Paid;
+		expense.batch = *payroll_batch;
+		expense.exit(&crate::ID)?;
+		reimbursed = reimbursed
+			.checked_add(expense.amount)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+		emit!(ExpenseReimbursed {
+			employee: employee.key(),
+			expense: expense.key(),
+			amount: expense.amount,
+			batch: *payroll_batch,
+		});
+	}
+
+	employee.total_reimbursed = employee
+		.total_reimbursed
+		.checked_add(reimbursed)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	Ok(reimbursed)
+}
+
+/// Recovers as much of the employee's outstanding advance as `net` covers
+/// and returns what is left of `net` for the payout wallet.
+pub(crate) fn recover_advance(employee: &mut Account<Employee>, net: u64) -> u64 {
+	let recovered = employee.outstanding_advance.min(net);
+	if recovered > 0 {
+		employee.outstanding_advance -= recovered;
+
+		emit!(AdvanceRecovered {
+			employee: employee.key(),
+			amount: recovered,
+			outstanding_advance: employee.outstanding_advance,
+		});
+	}
+
+	net - recovered
+}
+
+/// Adds the employee's `net` pay to `payouts`, one share per payout split
+/// wallet in `split_accounts` followed by the rest, rounding dust
+/// included, for `payout_wallet`.
+pub(crate) fn push_net_payouts<'info>(
+	employee: &Employee,
+	split_accounts: &[AccountInfo<'info>],
+	payout_wallet: AccountInfo<'info>,
+	net: u64,
+	payouts: &mut Vec<(AccountInfo<'info>, u64)>,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	require_eq!(
+		split_accounts.len(),
+		employee.payout_splits.len(),
+		EmployeePayrollError::PayoutSplitMismatch
+	);
+
+	let (shares, remainder) = employee.split_payout(net);
+	for ((split, wallet_info), share) in employee.payout_splits.iter().zip(split_accounts).zip(shares) {
+		require_keys_eq!(wallet_info.key(), split.wallet, EmployeePayrollError::PayoutSplitMismatch);
+		payouts.push((wallet_info.clone(), share));
+	}
+	payouts.push((payout_wallet, remainder));
+
+	Ok(())
+}
+
+/// Adds the employee's `net` pay to `payouts` as a credit to their
+/// `ClaimableBalance`, which keeps it until the employee claims it. A credit
+/// that may be clawed back is given as `(now, reversible_until)` and is held
+/// from `claim` until then.
+pub(crate) fn credit_claimable_balance<'info>(
+	claimable_balance: Option<&mut Account<'info, ClaimableBalance>>,
+	net: u64,
+	reversible: Option<(i64, i64)>,
+	payouts: &mut Vec<(AccountInfo<'info>, u64)>,
+) -> Result<()> {
+	let claimable_balance = claimable_balance.ok_or(EmployeePayrollError::ClaimableBalanceRequired)?;
+	claimable_balance.amount = claimable_balance
+		.amount
+		.checked_add(net)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	if let Some((now, reversible_until)) = reversible.filter(|(now, until)| until > now) {
+		claimable_balance.lock(net, now, reversible_until)?;
+	}
+	payouts.push((claimable_balance.to_account_info(), net));
+
+	emit!(ClaimableBalanceCredited {
+		employee: claimable_balance.employee,
+		claimable_balance: claimable_balance.key(),
+		amount: net,
+		balance: claimable_balance.amount,
+	});
+
+	Ok(())
+}
+
+/// Hash of the `PayItem`s and `Expense`s paid with a session, recorded so
+/// the same accounts have to be passed to reverse the payment.
+pub(crate) fn paid_with_hash<'info>(pay_items: &[AccountInfo<'info>], expenses: &[AccountInfo<'info>]) -> [u8; 32] {
+	let keys: Vec<&[u8]> = pay_items.iter().chain(expenses).map(|info| info.key.as_ref()).collect();
+	hashv(&keys).to_bytes()
+}
+
+/// Splits a `gross` payout across the employee's deduction rules, passed as
+/// `(deduction_rule, remittance_vault)` account pairs in attachment order.
+/// Returns the withheld payouts and their total; each rule is capped at
+/// what is left of `gross`. Rules owed per pay period only withhold what
+/// the employee's earlier payouts in the period at `now` have not covered.
+/// The employee's `withholding_credit` is taken off what is sent to the
+/// remittance vaults, not off what is withheld.
+pub(crate) fn withhold<'info>(
+	employer: &Employer,
+	employee: &mut Account<'info, Employee>,
+	deduction_accounts: &[AccountInfo<'info>],
+	gross: u64,
+	now: i64,
+) -> Result<(Vec<(AccountInfo<'info>, u64)>, u64)> {
+	require_eq!(
+		deduction_accounts.len(),
+		2 * employee.deduction_rules.len(),
+		EmployeePayrollError::DeductionAccountsMismatch
+	);
+
+	let period_gross = employee.period_gross(employer, now);
+	let mut payouts = Vec::with_capacity(employee.deduction_rules.len() + 1);
+	let mut withheld = 0u64;
+	let mut withholding_credit = employee.withholding_credit;
+	for (rule_key, pair) in employee.deduction_rules.iter().zip(deduction_accounts.chunks(2)) {
+		let (rule_info, remittance_info) = (&pair[0], &pair[1]);
+		require_keys_eq!(rule_info.key(), *rule_key, EmployeePayrollError::DeductionAccountsMismatch);
+		require_keys_eq!(*rule_info.owner, crate::ID, EmployeePayrollError::DeductionAccountsMismatch);
+
+		let rule = DeductionRule::try_deserialize(&mut &rule_info.try_borrow_data()?[..])?;
+		require_keys_eq!(remittance_info.key(), rule.remittance_vault, EmployeePayrollError::DeductionAccountsMismatch);
+
+		let amount = rule.withholding(period_gross, gross).min(gross - withheld);
+		if amount > 0 {
+			emit!(DeductionWithheld {
+				employee: employee.key(),
+				deduction_rule: rule_info.key(),
+				remittance_vault: rule.remittance_vault,
+				amount,
+			});
+		}
+
+		// Remitted already when the pay it was withheld from was clawed back
+		let credited = amount.min(withholding_credit);
+		withholding_credit -= credited;
+
+		withheld += amount;
+		payouts.push((remittance_info.clone(), amount - credited));
+	}
+	employee.withholding_credit = withholding_credit;
+
+	Ok((payouts, withheld))
+}
+
+/// Books the employee's gross pay `amount` against their department's budget
+/// for the pay period. Going over it takes the owner's signature.
+pub(crate) fn charge_department(
+	employer: &Employer,
+	employee: &Employee,
+	department: Option<&mut Account<Department>>,
+	amount: u64,
+	owner_signed: bool,
+) -> Result<()> {
+	if employee.department_id == 0 {
+		return Ok(());
+	}
+
+	let department = department.ok_or(EmployeePayrollError::DepartmentRequired)?;
+	if department.record_spend(employer, amount, Clock::get()?.unix_timestamp, owner_signed)? {
+		emit!(DepartmentBudgetOverridden {
+			department: department.key(),
+			amount,
+			owner: employer.authority,
+		});
+	}
+
+	Ok(())
+}
+
+/// Books the total of `payouts` against the employer's spending limits and
+/// sends each amount from the vault to its account.
+pub(crate) fn pay_from_vault<'info>(
+	employer: &mut Account<'info, Employer>,
+	vault: &SystemAccount<'info>,
+	vault_bump: u8,
+	system_program: &Program<'info, System>,
+	payouts: &[(AccountInfo<'info>, u64)],
+	owner_signed: bool,
+) -> Result<()> {
+	let amount = payouts
+		.iter()
+		.try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	require_gte!(vault.lamports(), amount, EmployeePayrollError::InsufficientVaultBalance);
+
+	let now = Clock::get()?.unix_timestamp;
+	if employer.record_outflow(amount, now, owner_signed)? {
+		emit!(SpendingLimitOverridden {
+			employer: employer.key(),
+			amount,
+			owner: employer.authority,
+		});
+	}
+
+	let employer_key = employer.key();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"vault",
+		employer_key.as_ref(),
+		&[vault_bump],
+	]];
+
+	for (to, amount) in payouts.iter().filter(|(_, amount)| *amount > 0) {
+		transfer(
+			CpiContext::new_with_signer(
+				system_program.to_account_info(),
+				Transfer {
+					from: vault.to_account_info(),
+					to: to.clone(),
+				},
+				signer_seeds,
+			),
+			*amount,
+		)?;
+	}
+
 	Ok(())
 }
@@ -0,0 +1,161 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		batch_id: u64,
+		timestamp: i64,
+	)]
+	pub struct ProcessSalaryPayroll<'info> {
+		#[account(
+			mut,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			address = employer.authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub owner: Option<Signer<'info>>,
+
+		#[account(
+			mut,
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payroll_batch",
+				employer.key().as_ref(),
+				batch_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub payroll_batch: Account<'info, PayrollBatch>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = employee.is_active || employee.is_terminated @ EmployeePayrollError::EmployeeInactive,
+			constraint = employee.compensation != Compensation::Hourly
+				|| employee.is_terminated @ EmployeePayrollError::CompensationMismatch,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			address = employee.payout_wallet @ EmployeePayrollError::PayoutWalletMismatch,
+		)]
+		pub payout_wallet: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"claimable_balance",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub claimable_balance: Option<Account<'info, ClaimableBalance>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+			constraint = department.department_id == employee.department_id @ EmployeePayrollError::DepartmentMismatch,
+		)]
+		pub department: Option<Account<'info, Department>>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Pay a salaried or fixed-fee employee for the batch's pay period, or any terminated employee their final settlement
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[signer]` owner: [AccountInfo] Optional owner co-signature for payouts above the spending limits
+/// 2. `[writable]` employer: [Employer] 
+/// 3. `[]` program_config: [ProgramConfig] 
+/// 4. `[writable]` payroll_batch: [PayrollBatch] 
+/// 5. `[writable]` employee: [Employee] 
+/// 6. `[writable]` vault: [AccountInfo] 
+/// 7. `[writable]` payout_wallet: [AccountInfo] 
+/// 8. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 9. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 10. `[]` system_program: [AccountInfo] 
+///     11..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     a wallet for each of the employee's payout splits when paying directly, then any `PayItem`s attached to the batch and approved `Expense`s to pay alongside the salary
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - timestamp: [i64] Unused, `processed_at` is taken from the clock
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, ProcessSalaryPayroll<'info>>,
+	_batch_id: u64,
+	_timestamp: i64,
+) -> Result<()> {
+	let period_start = ctx.accounts.payroll_batch.period_start;
+	// Terminated employees are owed nothing past the settlement attached to
+	// the batch, which already prorates their last period.
+	let terminated = ctx.accounts.employee.is_terminated;
+	let period_amount = if terminated {
+		0
+	} else {
+		let pay_frequency = ctx.accounts.employer.pay_frequency.ok_or(EmployeePayrollError::PayScheduleNotSet)?;
+		require!(ctx.accounts.payroll_batch.has_pay_period(), EmployeePayrollError::BatchHasNoPayPeriod);
+		require!(ctx.accounts.employee.last_salary_period < period_start, EmployeePayrollError::SalaryAlreadyPaid);
+		ctx.accounts.employee.compensation.period_pay(pay_frequency)
+	};
+	let remaining = split_remaining_accounts(&ctx.accounts.employer, &ctx.accounts.employee, ctx.remaining_accounts);
+	let owner_signed = ctx.accounts.authority.key() == ctx.accounts.employer.authority
+		|| ctx.accounts.owner.is_some();
+	pay_employee(
+		EmployeePayment {
+			employer: &mut ctx.accounts.employer,
+			payroll_batch: &mut ctx.accounts.payroll_batch,
+			employee: &mut ctx.accounts.employee,
+			vault: &ctx.accounts.vault,
+			vault_bump: ctx.bumps.vault,
+			payout_wallet: &ctx.accounts.payout_wallet,
+			claimable_balance: ctx.accounts.claimable_balance.as_mut(),
+			department: ctx.accounts.department.as_mut(),
+			system_program: &ctx.accounts.system_program,
+		},
+		remaining,
+		period_amount,
+		false,
+		None,
+		owner_signed,
+	)?;
+
+	if !terminated {
+		ctx.accounts.employee.last_salary_period = period_start;
+	}
+
+	Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		new_payout_wallet: Pubkey,
+	)]
+	pub struct ProposePayoutWallet<'info> {
+		#[account(
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub employee: Account<'info, Employee>,
+	}
+
+/// Employee proposes a new payout wallet
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee's identity wallet
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - new_payout_wallet: [Pubkey] Has to sign `accept_payout_wallet` before it receives funds
+pub fn handler(
+	ctx: Context<ProposePayoutWallet>,
+	_employee_wallet: Pubkey,
+	new_payout_wallet: Pubkey,
+) -> Result<()> {
+	require_keys_neq!(new_payout_wallet, Pubkey::default(), EmployeePayrollError::InvalidPayoutWallet);
+
+	let employee = &mut ctx.accounts.employee;
+	employee.pending_payout_wallet = Some(new_payout_wallet);
+
+	emit!(PayoutWalletProposed {
+		employee: employee.key(),
+		current_payout_wallet: employee.payout_wallet,
+		proposed_payout_wallet: new_payout_wallet,
+	});
+
+	Ok(())
+}
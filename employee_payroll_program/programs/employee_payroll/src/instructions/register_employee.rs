@@ -12,11 +12,17 @@ use std::str::FromStr;
 		employee_wallet: Pubkey,
 	)]
 	pub struct RegisterEmployee<'info> {
+		#[account(mut)]
 		pub authority: Signer<'info>,
 
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
 		#[account(
 			init,
-			space=205,
+			space=8 + Employee::INIT_SPACE,
 			payer=authority,
 			seeds = [
 				b"employee",
@@ -33,12 +39,13 @@ use std::str::FromStr;
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` employee: [Employee] 
-/// 2. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
-/// - name: [String] 
-/// - position: [String] 
+/// - name: [String] Non-blank, at most `MAX_NAME_LEN` bytes
+/// - position: [String] At most `MAX_POSITION_LEN` bytes
 /// - hourly_rate: [u64] 
 /// - employee_wallet: [Pubkey] 
 pub fn handler(
@@ -48,7 +55,44 @@ pub fn handler(
 	hourly_rate: u64,
 	employee_wallet: Pubkey,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	Employee::validate_profile(&name, &position)?;
+
+	let employee = &mut ctx.accounts.employee;
+
+	employee.name = name;
+	employee.position = position;
+	employee.hourly_rate = hourly_rate;
+	employee.total_hours_worked = 0;
+	employee.total_paid = 0;
+	employee.authority = ctx.accounts.authority.key();
+	employee.employee_wallet = employee_wallet;
+	employee.is_active = true;
+	employee.payout_wallet = employee_wallet;
+	employee.pending_payout_wallet = None;
+	employee.employer = ctx.accounts.employer.key();
+	employee.pending_hourly_rate = 0;
+	employee.pending_rate_effective_at = 0;
+	employee.version = Employee::VERSION;
+	employee.deduction_rules = vec![];
+	employee.ytd = EarningsTotals::default();
+	employee.ytd_start = 0;
+	employee.period_to_date = EarningsTotals::default();
+	employee.period_start = 0;
+	employee.compensation = Compensation::Hourly;
+	employee.last_salary_period = 0;
+	employee.outstanding_adjustment = 0;
+	employee.total_reimbursed = 0;
+	employee.outstanding_advance = 0;
+	employee.payout_splits = vec![];
+	employee.rate_price_feed = Pubkey::default();
+	employee.pto_accrual_bps = 0;
+	employee.pto_balance = 0;
+	employee.unpaid_leave = 0;
+	employee.department_id = 0;
+	employee.is_terminated = false;
+	employee.last_raise_at = 0;
+	employee.rate_feed_id = [0; 32];
+	employee.unpaid_sessions = 0;
+
 	Ok(())
 }
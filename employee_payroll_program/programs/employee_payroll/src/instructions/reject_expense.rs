@@ -0,0 +1,74 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		expense_id: u64,
+	)]
+	pub struct RejectExpense<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"expense",
+				employee.key().as_ref(),
+				expense_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			constraint = expense.status == ExpenseStatus::Submitted @ EmployeePayrollError::ExpenseAlreadyReviewed,
+		)]
+		pub expense: Account<'info, Expense>,
+	}
+
+/// Manager rejects a submitted expense
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` expense: [Expense] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - expense_id: [u64] 
+pub fn handler(
+	ctx: Context<RejectExpense>,
+	_employee_wallet: Pubkey,
+	_expense_id: u64,
+) -> Result<()> {
+	let expense = &mut ctx.accounts.expense;
+
+	expense.status = ExpenseStatus::Rejected;
+	expense.reviewed_by = ctx.accounts.authority.key();
+	expense.reviewed_at = Clock::get()?.unix_timestamp;
+
+	emit!(ExpenseReviewed {
+		employee: expense.employee,
+		expense: expense.key(),
+		approved: false,
+		reviewer: expense.reviewed_by,
+	});
+
+	Ok(())
+}
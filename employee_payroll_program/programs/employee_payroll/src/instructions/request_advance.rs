@@ -0,0 +1,151 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		amount: u64,
+	)]
+	pub struct RequestAdvance<'info> {
+		#[account(
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+			constraint = employer.advance_limit_bps > 0 @ EmployeePayrollError::AdvancesDisabled,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = employee.is_active @ EmployeePayrollError::EmployeeInactive,
+			constraint = employee.compensation == Compensation::Hourly @ EmployeePayrollError::CompensationMismatch,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			address = employee.payout_wallet @ EmployeePayrollError::PayoutWalletMismatch,
+		)]
+		pub payout_wallet: SystemAccount<'info>,
+
+		/// CHECK: parsed as a price update by `convert_wages`
+		#[account(
+			address = employee.rate_price_feed @ EmployeePayrollError::PriceFeedMismatch,
+		)]
+		pub price_feed: Option<UncheckedAccount<'info>>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Employee draws part of the wages earned in approved, unpaid work sessions before payday
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` employee: [Employee] 
+/// 4. `[writable]` vault: [AccountInfo] 
+/// 5. `[writable]` payout_wallet: [AccountInfo] 
+/// 6. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 7. `[]` system_program: [AccountInfo] 
+///    8..n. `[]` remaining accounts: the employee's approved, unpaid `WorkSession`s the advance is drawn against
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - amount: [u64] Lamports to advance; with earlier advances still outstanding, at most
+///   `advance_limit_bps` of the wages earned in the given sessions, converted at the current price
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, RequestAdvance<'info>>,
+	_employee_wallet: Pubkey,
+	amount: u64,
+) -> Result<()> {
+	require!(amount > 0, EmployeePayrollError::InvalidAdvanceAmount);
+
+	let employee_key = ctx.accounts.employee.key();
+	let mut session_keys = Vec::with_capacity(ctx.remaining_accounts.len());
+	let mut wages = 0u64;
+	for session_info in ctx.remaining_accounts {
+		require!(!session_keys.contains(session_info.key), EmployeePayrollError::InvalidRemainingAccounts);
+		session_keys.push(*session_info.key);
+
+		let session = Account::<WorkSession>::try_from(session_info)?;
+		require_keys_eq!(session.employee, employee_key, EmployeePayrollError::InvalidRemainingAccounts);
+		require!(session.check_out_time != 0, EmployeePayrollError::SessionNotCheckedOut);
+		require!(!session.is_paid, EmployeePayrollError::SessionAlreadyPaid);
+		// Employees report their own hours; only reviewed ones are advanced against.
+		require!(session.is_approved, EmployeePayrollError::SessionNotApproved);
+
+		wages = wages
+			.checked_add(session_pay(session.duration, ctx.accounts.employee.hourly_rate)?)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	}
+
+	// Advances are repaid from lamport pay, so the limit is in lamports too.
+	let (earned, _) = convert_wages(
+		&ctx.accounts.employer,
+		&ctx.accounts.employee,
+		ctx.accounts.price_feed.as_ref(),
+		wages,
+		Clock::get()?.unix_timestamp,
+	)?;
+
+	let limit = (earned as u128) * (ctx.accounts.employer.advance_limit_bps as u128) / 10_000;
+	let outstanding_advance = ctx
+		.accounts
+		.employee
+		.outstanding_advance
+		.checked_add(amount)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	require!(outstanding_advance as u128 <= limit, EmployeePayrollError::AdvanceLimitExceeded);
+
+	pay_from_vault(
+		&mut ctx.accounts.employer,
+		&ctx.accounts.vault,
+		ctx.bumps.vault,
+		&ctx.accounts.system_program,
+		&[(ctx.accounts.payout_wallet.to_account_info(), amount)],
+		false,
+	)?;
+
+	ctx.accounts.employee.outstanding_advance = outstanding_advance;
+
+	emit!(AdvanceIssued {
+		employee: employee_key,
+		amount,
+		outstanding_advance,
+	});
+
+	Ok(())
+}
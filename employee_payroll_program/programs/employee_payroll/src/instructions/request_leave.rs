@@ -0,0 +1,89 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		request_id: u64,
+	)]
+	pub struct RequestLeave<'info> {
+		#[account(
+			mut,
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			constraint = employee.is_active @ EmployeePayrollError::EmployeeInactive,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			init,
+			space=8 + LeaveRequest::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"leave_request",
+				employee.key().as_ref(),
+				request_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub leave_request: Account<'info, LeaveRequest>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Employee requests paid time off out of their accrued leave
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employee: [Employee] 
+/// 2. `[writable]` leave_request: [LeaveRequest] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - request_id: [u64] 
+/// - start_time: [i64] When the leave starts
+/// - duration: [u64] Seconds of leave, at most the employee's `pto_balance`
+pub fn handler(
+	ctx: Context<RequestLeave>,
+	_employee_wallet: Pubkey,
+	request_id: u64,
+	start_time: i64,
+	duration: u64,
+) -> Result<()> {
+	require!(duration > 0, EmployeePayrollError::InvalidLeaveDuration);
+	require!(duration <= ctx.accounts.employee.pto_balance, EmployeePayrollError::InsufficientLeaveBalance);
+
+	let leave_request = &mut ctx.accounts.leave_request;
+
+	leave_request.employer = ctx.accounts.employee.employer;
+	leave_request.employee = ctx.accounts.employee.key();
+	leave_request.request_id = request_id;
+	leave_request.start_time = start_time;
+	leave_request.duration = duration;
+	leave_request.status = LeaveStatus::Requested;
+	leave_request.requested_at = Clock::get()?.unix_timestamp;
+	leave_request.reviewed_by = Pubkey::default();
+	leave_request.reviewed_at = 0;
+	leave_request.version = LeaveRequest::VERSION;
+
+	emit!(LeaveRequested {
+		employee: leave_request.employee,
+		leave_request: leave_request.key(),
+		start_time,
+		duration,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct ResumePayStream<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"pay_stream",
+				employee.key().as_ref(),
+			],
+			bump,
+			constraint = !pay_stream.is_cancelled @ EmployeePayrollError::StreamCancelled,
+		)]
+		pub pay_stream: Account<'info, PayStream>,
+	}
+
+/// Let a paused pay stream accrue again from now
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` pay_stream: [PayStream] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+pub fn handler(
+	ctx: Context<ResumePayStream>,
+	_employee_wallet: Pubkey,
+) -> Result<()> {
+	let pay_stream = &mut ctx.accounts.pay_stream;
+	require!(pay_stream.is_paused, EmployeePayrollError::StreamNotPaused);
+
+	pay_stream.last_accrual_at = pay_stream.last_accrual_at.max(Clock::get()?.unix_timestamp);
+	pay_stream.is_paused = false;
+
+	emit!(PayStreamUpdated {
+		employee: pay_stream.employee,
+		pay_stream: pay_stream.key(),
+		rate_per_second: pay_stream.rate_per_second,
+		is_paused: pay_stream.is_paused,
+		is_cancelled: pay_stream.is_cancelled,
+		accrued: pay_stream.accrued,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		advance_limit_bps: u16,
+	)]
+	pub struct SetAdvancePolicy<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Set how much of their earned, unpaid wages employees may draw early
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - advance_limit_bps: [u16] Share of earned wages in basis points, 0 to disable advances
+pub fn handler(
+	ctx: Context<SetAdvancePolicy>,
+	advance_limit_bps: u16,
+) -> Result<()> {
+	require!(advance_limit_bps <= 10_000, EmployeePayrollError::InvalidAdvanceLimit);
+
+	ctx.accounts.employer.advance_limit_bps = advance_limit_bps;
+
+	Ok(())
+}
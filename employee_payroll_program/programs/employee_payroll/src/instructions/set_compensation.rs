@@ -0,0 +1,75 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		compensation: Compensation,
+	)]
+	pub struct SetCompensation<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			address = employer.rate_approver @ EmployeePayrollError::Unauthorized,
+		)]
+		pub rate_approver: Option<Signer<'info>>,
+
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+	}
+
+/// Switch an employee between hourly, salaried and fixed contractor pay
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[signer]` rate_approver: [AccountInfo] Optional employer rate approver, required when the
+///    pay per period rises by more than the rate increase threshold or within the delay of the last
+///    raise made without it
+/// 2. `[]` employer: [Employer] 
+/// 3. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - compensation: [Compensation] Hourly pay keeps using `hourly_rate`
+pub fn handler(
+	ctx: Context<SetCompensation>,
+	_employee_wallet: Pubkey,
+	compensation: Compensation,
+) -> Result<()> {
+	let employer = &ctx.accounts.employer;
+	let employee = &mut ctx.accounts.employee;
+
+	// Pay other than hourly is owed per period of the pay schedule.
+	let (current_pay, new_pay) = match employer.pay_frequency {
+		Some(pay_frequency) => (employee.compensation.period_pay(pay_frequency), compensation.period_pay(pay_frequency)),
+		None => {
+			require!(compensation == Compensation::Hourly, EmployeePayrollError::PayScheduleNotSet);
+			(0, 0)
+		},
+	};
+
+	let now = Clock::get()?.unix_timestamp;
+	if employee.raise_needs_review(employer, current_pay, new_pay, now) {
+		require!(ctx.accounts.rate_approver.is_some(), EmployeePayrollError::RateApproverRequired);
+	} else if new_pay > current_pay {
+		employee.last_raise_at = now;
+	}
+
+	employee.compensation = compensation;
+
+	Ok(())
+}
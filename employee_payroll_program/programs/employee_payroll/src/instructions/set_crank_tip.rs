@@ -0,0 +1,38 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		crank_tip: u64,
+	)]
+	pub struct SetCrankTip<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Set the tip paid from the vault for each session paid by `crank_payroll`
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - crank_tip: [u64] Lamports, at most `MAX_CRANK_TIP`
+pub fn handler(
+	ctx: Context<SetCrankTip>,
+	crank_tip: u64,
+) -> Result<()> {
+	require_gte!(MAX_CRANK_TIP, crank_tip, EmployeePayrollError::CrankTipTooHigh);
+
+	ctx.accounts.employer.crank_tip = crank_tip;
+
+	Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		department_id: u64,
+	)]
+	pub struct SetDepartmentBudget<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"department",
+				employer.key().as_ref(),
+				department_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub department: Account<'info, Department>,
+	}
+
+/// Change a department's budget per pay period
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` department: [Department] 
+///
+/// Data:
+/// - department_id: [u64] 
+/// - budget_per_period: [u64] Lamports of gross pay per pay period, 0 for no cap
+pub fn handler(
+	ctx: Context<SetDepartmentBudget>,
+	_department_id: u64,
+	budget_per_period: u64,
+) -> Result<()> {
+	ctx.accounts.department.budget_per_period = budget_per_period;
+
+	Ok(())
+}
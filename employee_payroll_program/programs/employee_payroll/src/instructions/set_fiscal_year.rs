@@ -0,0 +1,35 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	pub struct SetFiscalYear<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Set the month the employer's fiscal year starts in, used to roll over year-to-date totals
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - start_month: [u8] 1 for January through 12 for December
+pub fn handler(
+	ctx: Context<SetFiscalYear>,
+	start_month: u8,
+) -> Result<()> {
+	require!((1..=12).contains(&start_month), EmployeePayrollError::InvalidFiscalYearStart);
+
+	ctx.accounts.employer.fiscal_year_start_month = start_month;
+
+	Ok(())
+}
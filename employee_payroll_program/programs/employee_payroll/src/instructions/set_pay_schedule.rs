@@ -0,0 +1,47 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		pay_frequency: PayFrequency,
+		anchor: i64,
+	)]
+	pub struct SetPaySchedule<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Set the recurring pay schedule that `open_next_pay_period` opens periods from
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - pay_frequency: [PayFrequency] 
+/// - anchor: [i64] Start of the first pay period; on the 1st or 16th for `SemiMonthly`, at most the 28th for `Monthly`. Once a period was opened, not before the next one
+pub fn handler(
+	ctx: Context<SetPaySchedule>,
+	pay_frequency: PayFrequency,
+	anchor: i64,
+) -> Result<()> {
+	pay_frequency.validate_anchor(anchor)?;
+
+	let employer = &mut ctx.accounts.employer;
+	require!(
+		!employer.pay_period_opened || anchor >= employer.next_pay_period_start,
+		EmployeePayrollError::PayScheduleAnchorInPast,
+	);
+	employer.pay_frequency = Some(pay_frequency);
+	employer.next_pay_period_start = anchor;
+
+	Ok(())
+}
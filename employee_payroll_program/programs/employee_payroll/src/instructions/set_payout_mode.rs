@@ -0,0 +1,36 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		payout_mode: PayoutMode,
+	)]
+	pub struct SetPayoutMode<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Choose whether payroll transfers net pay directly or credits claimable balances
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - payout_mode: [PayoutMode] `Claim` requires every paid employee to have a claimable balance
+pub fn handler(
+	ctx: Context<SetPayoutMode>,
+	payout_mode: PayoutMode,
+) -> Result<()> {
+	ctx.accounts.employer.payout_mode = payout_mode;
+
+	Ok(())
+}
@@ -0,0 +1,53 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		payout_splits: Vec<PayoutSplit>,
+	)]
+	pub struct SetPayoutSplits<'info> {
+		#[account(
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub employee: Account<'info, Employee>,
+	}
+
+/// Employee sets the wallets that receive a share of each payout
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee's identity wallet
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - payout_splits: [Vec<PayoutSplit>] Up to `MAX_PAYOUT_SPLITS` wallets with basis-point shares; the rest goes to the payout wallet
+pub fn handler(
+	ctx: Context<SetPayoutSplits>,
+	_employee_wallet: Pubkey,
+	payout_splits: Vec<PayoutSplit>,
+) -> Result<()> {
+	let employee = &mut ctx.accounts.employee;
+	employee.validate_payout_splits(&payout_splits)?;
+	employee.payout_splits = payout_splits;
+
+	emit!(PayoutSplitsChanged {
+		employee: employee.key(),
+		payout_splits: employee.payout_splits.clone(),
+	});
+
+	Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		payroll_manager: Pubkey,
+	)]
+	pub struct SetPayrollManager<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Delegate routine payroll processing to a payroll manager
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - payroll_manager: [Pubkey] 
+pub fn handler(
+	ctx: Context<SetPayrollManager>,
+	payroll_manager: Pubkey,
+) -> Result<()> {
+	ctx.accounts.employer.payroll_manager = payroll_manager;
+
+	Ok(())
+}
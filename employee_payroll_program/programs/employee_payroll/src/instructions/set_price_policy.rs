@@ -0,0 +1,46 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		max_price_age: i64,
+		max_price_conf_bps: u16,
+	)]
+	pub struct SetPricePolicy<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Set which prices are trusted to convert reference currency rates at payout
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - max_price_age: [i64] Seconds a price may be old when payroll runs
+/// - max_price_conf_bps: [u16] Widest confidence interval accepted, in basis points of the price
+pub fn handler(
+	ctx: Context<SetPricePolicy>,
+	max_price_age: i64,
+	max_price_conf_bps: u16,
+) -> Result<()> {
+	require!(
+		max_price_age > 0 && max_price_conf_bps <= 10_000,
+		EmployeePayrollError::InvalidPricePolicy
+	);
+
+	let employer = &mut ctx.accounts.employer;
+	employer.max_price_age = max_price_age;
+	employer.max_price_conf_bps = max_price_conf_bps;
+
+	Ok(())
+}
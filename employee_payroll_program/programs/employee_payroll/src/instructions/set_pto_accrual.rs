@@ -0,0 +1,46 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		pto_accrual_bps: u16,
+	)]
+	pub struct SetPtoAccrual<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employee: Account<'info, Employee>,
+	}
+
+/// Set how much paid leave an employee accrues for each hour worked
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - pto_accrual_bps: [u16] Leave per time worked in basis points, e.g. 385 for about one hour every 26 hours
+pub fn handler(
+	ctx: Context<SetPtoAccrual>,
+	_employee_wallet: Pubkey,
+	pto_accrual_bps: u16,
+) -> Result<()> {
+	require!(pto_accrual_bps <= 10_000, EmployeePayrollError::InvalidPtoAccrual);
+
+	ctx.accounts.employee.pto_accrual_bps = pto_accrual_bps;
+
+	Ok(())
+}
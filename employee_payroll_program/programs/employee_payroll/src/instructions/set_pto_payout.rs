@@ -0,0 +1,36 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		pto_payout_on_termination: bool,
+	)]
+	pub struct SetPtoPayout<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Set whether terminated employees are paid out their remaining leave balance
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - pto_payout_on_termination: [bool] Add the leave balance to the final settlement at `hourly_rate`
+pub fn handler(
+	ctx: Context<SetPtoPayout>,
+	pto_payout_on_termination: bool,
+) -> Result<()> {
+	ctx.accounts.employer.pto_payout_on_termination = pto_payout_on_termination;
+
+	Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		rate_increase_threshold_bps: u16,
+		rate_increase_delay: i64,
+		rate_approver: Pubkey,
+	)]
+	pub struct SetRateChangePolicy<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			address = employer.rate_approver @ EmployeePayrollError::Unauthorized,
+		)]
+		pub rate_approver_signer: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Configure the timelock for large hourly rate increases
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[signer]` rate_approver_signer: [AccountInfo] Current rate approver
+/// 2. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - rate_increase_threshold_bps: [u16] Increases above this are timelocked
+/// - rate_increase_delay: [i64] Timelock in seconds
+/// - rate_approver: [Pubkey] New rate approver, other than the authority
+pub fn handler(
+	ctx: Context<SetRateChangePolicy>,
+	rate_increase_threshold_bps: u16,
+	rate_increase_delay: i64,
+	rate_approver: Pubkey,
+) -> Result<()> {
+	require!(rate_increase_delay >= 0, EmployeePayrollError::InvalidRateChangeDelay);
+	require_keys_neq!(rate_approver, ctx.accounts.authority.key(), EmployeePayrollError::RateApproverIsAuthority);
+
+	let employer = &mut ctx.accounts.employer;
+
+	employer.rate_increase_threshold_bps = rate_increase_threshold_bps;
+	employer.rate_increase_delay = rate_increase_delay;
+	employer.rate_approver = rate_approver;
+
+	Ok(())
+}
@@ -0,0 +1,88 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		rate_price_feed: Pubkey,
+		rate_feed_id: [u8; 32],
+		hourly_rate: u64,
+	)]
+	pub struct SetRateCurrency<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			address = employer.rate_approver @ EmployeePayrollError::Unauthorized,
+		)]
+		pub rate_approver: Signer<'info>,
+
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+			has_one = employer,
+			constraint = employee.unpaid_sessions == 0 @ EmployeePayrollError::SessionsStillUnpaid,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		/// CHECK: parsed as a price update by `PriceUpdate::load`
+		#[account(
+			address = rate_price_feed @ EmployeePayrollError::PriceFeedMismatch,
+		)]
+		pub price_feed: Option<UncheckedAccount<'info>>,
+	}
+
+/// Move an employee's hourly rate to another currency, priced through an oracle at payout
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[signer]` rate_approver: [AccountInfo] Employer rate approver, as the old and new rates
+///    cannot be compared
+/// 2. `[]` employer: [Employer] 
+/// 3. `[writable]` employee: [Employee] With no work sessions left to pay, which are paid at their rate in the old currency
+/// 4. `[]` price_feed: [AccountInfo] Optional, the `rate_price_feed` account, required unless paying in lamports
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - rate_price_feed: [Pubkey] Price account quoting SOL in the new currency, default to pay in lamports
+/// - rate_feed_id: [[u8; 32]] Feed id every price update read from `rate_price_feed` has to carry
+/// - hourly_rate: [u64] Rate in the new currency, with `REFERENCE_RATE_DECIMALS` decimals unless in lamports
+pub fn handler(
+	ctx: Context<SetRateCurrency>,
+	_employee_wallet: Pubkey,
+	rate_price_feed: Pubkey,
+	rate_feed_id: [u8; 32],
+	hourly_rate: u64,
+) -> Result<()> {
+	// The approver signs off on a feed that is quoting the currency now.
+	if rate_price_feed != Pubkey::default() {
+		let price_feed = ctx.accounts.price_feed.as_ref().ok_or(EmployeePayrollError::PriceFeedRequired)?;
+		PriceUpdate::load(price_feed, &rate_feed_id)?;
+	}
+
+	let employee = &mut ctx.accounts.employee;
+	employee.rate_price_feed = rate_price_feed;
+	employee.rate_feed_id = rate_feed_id;
+	employee.hourly_rate = hourly_rate;
+	// A pending increase was set in the old currency.
+	employee.pending_hourly_rate = 0;
+	employee.pending_rate_effective_at = 0;
+
+	emit!(RateCurrencyChanged {
+		employee: employee.key(),
+		rate_price_feed,
+		rate_feed_id,
+		hourly_rate,
+	});
+
+	Ok(())
+}
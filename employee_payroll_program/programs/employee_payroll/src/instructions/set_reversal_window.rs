@@ -0,0 +1,41 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		reversal_window: i64,
+	)]
+	pub struct SetReversalWindow<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Set how long after a batch completes its unclaimed credits can be clawed back
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - reversal_window: [i64] Seconds, at most `MAX_REVERSAL_WINDOW`; 0 disallows clawbacks
+pub fn handler(
+	ctx: Context<SetReversalWindow>,
+	reversal_window: i64,
+) -> Result<()> {
+	require!(
+		(0..=MAX_REVERSAL_WINDOW).contains(&reversal_window),
+		EmployeePayrollError::InvalidReversalWindow
+	);
+
+	ctx.accounts.employer.reversal_window = reversal_window;
+
+	Ok(())
+}
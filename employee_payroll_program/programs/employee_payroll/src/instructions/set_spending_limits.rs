@@ -0,0 +1,56 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		daily_limit: u64,
+		period_limit: u64,
+		period_duration: i64,
+	)]
+	pub struct SetSpendingLimits<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+	}
+
+/// Configure how much may leave the employer vault per rolling day and per limit period
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - daily_limit: [u64] Lamports per rolling 24 hours, 0 for no cap
+/// - period_limit: [u64] Lamports per limit period, 0 for no cap
+/// - period_duration: [i64] Limit period length in seconds; a new period starts now when it changes
+pub fn handler(
+	ctx: Context<SetSpendingLimits>,
+	daily_limit: u64,
+	period_limit: u64,
+	period_duration: i64,
+) -> Result<()> {
+	require!(
+		period_duration > 0 || (period_duration == 0 && period_limit == 0),
+		EmployeePayrollError::InvalidPeriodDuration
+	);
+
+	let employer = &mut ctx.accounts.employer;
+
+	employer.daily_limit = daily_limit;
+	employer.period_limit = period_limit;
+	// Changing only the amounts keeps the outflow already booked this period.
+	if period_duration != employer.period_duration {
+		employer.period_duration = period_duration;
+		employer.period_start = Clock::get()?.unix_timestamp;
+		employer.period_spent = 0;
+	}
+
+	Ok(())
+}
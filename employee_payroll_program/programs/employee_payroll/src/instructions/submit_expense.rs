@@ -0,0 +1,92 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		expense_id: u64,
+	)]
+	pub struct SubmitExpense<'info> {
+		#[account(
+			mut,
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			constraint = employee.is_active @ EmployeePayrollError::EmployeeInactive,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			init,
+			space=8 + Expense::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"expense",
+				employee.key().as_ref(),
+				expense_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub expense: Account<'info, Expense>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Employee claims reimbursement for a travel, equipment or other expense
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employee: [Employee] 
+/// 2. `[writable]` expense: [Expense] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - expense_id: [u64] 
+/// - amount: [u64] Lamports to reimburse
+/// - category: [ExpenseCategory] 
+/// - receipt_hash: [[u8; 32]] Hash of the receipt kept off-chain
+pub fn handler(
+	ctx: Context<SubmitExpense>,
+	_employee_wallet: Pubkey,
+	expense_id: u64,
+	amount: u64,
+	category: ExpenseCategory,
+	receipt_hash: [u8; 32],
+) -> Result<()> {
+	require!(amount > 0, EmployeePayrollError::InvalidExpenseAmount);
+
+	let expense = &mut ctx.accounts.expense;
+
+	expense.employer = ctx.accounts.employee.employer;
+	expense.employee = ctx.accounts.employee.key();
+	expense.expense_id = expense_id;
+	expense.category = category;
+	expense.amount = amount;
+	expense.receipt_hash = receipt_hash;
+	expense.status = ExpenseStatus::Submitted;
+	expense.submitted_at = Clock::get()?.unix_timestamp;
+	expense.reviewed_by = Pubkey::default();
+	expense.reviewed_at = 0;
+	expense.batch = Pubkey::default();
+	expense.version = Expense::VERSION;
+
+	emit!(ExpenseSubmitted {
+		employee: expense.employee,
+		expense: expense.key(),
+		category,
+		amount,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+		item_id: u64,
+	)]
+	pub struct TerminateEmployee<'info> {
+		#[account(mut)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+			constraint = !employee.is_terminated @ EmployeePayrollError::EmployeeTerminated,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			has_one = employee,
+			constraint = work_session.check_out_time == 0 @ EmployeePayrollError::SessionAlreadyCheckedOut,
+		)]
+		pub work_session: Option<Account<'info, WorkSession>>,
+
+		#[account(
+			mut,
+			has_one = employer,
+		)]
+		pub project: Option<Account<'info, Project>>,
+
+		/// CHECK: parsed as a price update by `convert_wages`
+		#[account(
+			address = employee.rate_price_feed @ EmployeePayrollError::PriceFeedMismatch,
+		)]
+		pub price_feed: Option<UncheckedAccount<'info>>,
+
+		/// CHECK: the employee's pay stream, which may never have been opened
+		#[account(
+			mut,
+			seeds = [
+				b"pay_stream",
+				employee.key().as_ref(),
+			],
+			bump,
+		)]
+		pub pay_stream: UncheckedAccount<'info>,
+
+		#[account(
+			init,
+			space=8 + PayItem::INIT_SPACE,
+			payer=authority,
+			seeds = [
+				b"pay_item",
+				employee.key().as_ref(),
+				item_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub final_settlement: Account<'info, PayItem>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Terminate an employee, checking out their open session, ending their pay stream and creating their final settlement
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] Optional, the employee's open session, checked out now
+/// 4. `[writable]` project: [Project] Optional, required when the open session was worked on a project
+/// 5. `[]` price_feed: [AccountInfo] Optional, required when leave is paid out and the employee's rate is priced in a reference currency
+/// 6. `[writable]` pay_stream: [AccountInfo] Ended now along with the employment, when it exists
+/// 7. `[writable]` final_settlement: [PayItem] Negative when salary was already paid for the rest of the pay period
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - item_id: [u64] Id of the final settlement `PayItem`, to attach to a batch like any other
+pub fn handler(
+	ctx: Context<TerminateEmployee>,
+	_employee_wallet: Pubkey,
+	item_id: u64,
+) -> Result<()> {
+	let now = Clock::get()?.unix_timestamp;
+	let employer = &ctx.accounts.employer;
+	let employee = &mut ctx.accounts.employee;
+
+	// Hours of the open session are paid with it by `process_payroll`; its
+	// leave accrual is what gets paid out below.
+	if let Some(work_session) = ctx.accounts.work_session.as_mut() {
+		if let Some(project) = ctx.accounts.project.as_ref() {
+			require!(project.project_id == work_session.project_id, EmployeePayrollError::ProjectMismatch);
+		}
+		record_check_out(employer, employee, work_session, ctx.accounts.project.as_mut(), now)?;
+	}
+
+	// A running stream would keep accruing pay past the termination; what
+	// accrued up to now stays withdrawable.
+	let pay_stream_info = ctx.accounts.pay_stream.to_account_info();
+	if !pay_stream_info.data_is_empty() {
+		let mut pay_stream = PayStream::try_deserialize(&mut &pay_stream_info.try_borrow_data()?[..])?;
+		if !pay_stream.is_cancelled {
+			pay_stream.accrue(now)?;
+			pay_stream.is_cancelled = true;
+			pay_stream.try_serialize(&mut &mut pay_stream_info.try_borrow_mut_data()?[..])?;
+
+			emit!(PayStreamUpdated {
+				employee: employee.key(),
+				pay_stream: pay_stream_info.key(),
+				rate_per_second: pay_stream.rate_per_second,
+				is_paused: pay_stream.is_paused,
+				is_cancelled: pay_stream.is_cancelled,
+				accrued: pay_stream.accrued,
+			});
+		}
+	}
+
+	// Approved leave would otherwise wait for a work session to be paid with.
+	let mut leave_paid = std::mem::take(&mut employee.unpaid_leave);
+	if employer.pto_payout_on_termination {
+		leave_paid = leave_paid
+			.checked_add(std::mem::take(&mut employee.pto_balance))
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	}
+	let (leave_pay, _exchange_rate) = if leave_paid > 0 {
+		convert_wages(
+			employer,
+			employee,
+			ctx.accounts.price_feed.as_ref(),
+			session_pay(leave_paid, employee.hourly_rate)?,
+			now,
+		)?
+	} else {
+		(0, 0)
+	};
+
+	// Salaries are paid per pay period, so the part of the current period
+	// worked is owed when the period is unpaid, and the part left after now
+	// is taken back when it has been paid in full already.
+	let mut salary_pay = 0i128;
+	if let (Some(pay_frequency), Some((period_start, period_end))) = (employer.pay_frequency, employer.pay_period_at(now)) {
+		if employee.compensation != Compensation::Hourly {
+			let period_amount = employee.compensation.period_pay(pay_frequency) as i128;
+			let period_length = (period_end - period_start) as i128;
+			if employee.last_salary_period < period_start {
+				salary_pay = period_amount * (now - period_start) as i128 / period_length;
+				employee.last_salary_period = period_start;
+			} else {
+				salary_pay = -(period_amount * (period_end - now) as i128 / period_length);
+			}
+		}
+	}
+
+	let amount = i64::try_from(leave_pay as i128 + salary_pay).map_err(|_| EmployeePayrollError::ArithmeticOverflow)?;
+
+	let final_settlement = &mut ctx.accounts.final_settlement;
+	final_settlement.employer = employer.key();
+	final_settlement.employee = employee.key();
+	final_settlement.item_id = item_id;
+	final_settlement.kind = PayItemKind::FinalSettlement;
+	final_settlement.amount = amount;
+	final_settlement.memo_hash = [0; 32];
+	final_settlement.batch = Pubkey::default();
+	// Nothing owed leaves nothing to pay, so the employee can be closed.
+	final_settlement.is_paid = amount == 0;
+	final_settlement.created_at = now;
+	final_settlement.version = PayItem::VERSION;
+
+	employee.is_active = false;
+	employee.is_terminated = true;
+
+	emit!(EmployeeTerminated {
+		employee: employee.key(),
+		terminated_by: ctx.accounts.authority.key(),
+		final_settlement: final_settlement.key(),
+		amount,
+		leave_paid,
+		timestamp: now,
+	});
+
+	Ok(())
+}
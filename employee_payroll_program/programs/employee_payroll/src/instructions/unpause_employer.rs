@@ -0,0 +1,53 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	pub struct UnpauseEmployer<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			constraint = employer.paused @ EmployeePayrollError::EmployerNotPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+	}
+
+/// Lift an employer pause
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Program guardian, or the employer owner if the guardian did not pause
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+pub fn handler(
+	ctx: Context<UnpauseEmployer>,
+) -> Result<()> {
+	let authority = ctx.accounts.authority.key();
+	let is_guardian = authority == ctx.accounts.program_config.guardian;
+	let is_owner = authority == ctx.accounts.employer.authority;
+	require!(
+		is_guardian || (is_owner && !ctx.accounts.employer.paused_by_guardian),
+		EmployeePayrollError::Unauthorized
+	);
+
+	let employer = &mut ctx.accounts.employer;
+	employer.paused = false;
+	employer.paused_by_guardian = false;
+
+	emit!(EmployerUnpaused {
+		employer: employer.key(),
+		unpaused_by: authority,
+	});
+
+	Ok(())
+}
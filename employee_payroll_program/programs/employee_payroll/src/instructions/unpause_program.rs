@@ -0,0 +1,38 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	pub struct UnpauseProgram<'info> {
+		pub guardian: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = program_config.paused @ EmployeePayrollError::ProgramNotPaused,
+			has_one = guardian @ EmployeePayrollError::Unauthorized,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+	}
+
+/// Lift a program-wide pause
+///
+/// Accounts:
+/// 0. `[signer]` guardian: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] 
+pub fn handler(
+	ctx: Context<UnpauseProgram>,
+) -> Result<()> {
+	ctx.accounts.program_config.paused = false;
+
+	emit!(ProgramUnpaused {
+		guardian: ctx.accounts.guardian.key(),
+	});
+
+	Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		rule_id: u64,
+		name: String,
+		kind: DeductionKind,
+		remittance_vault: Pubkey,
+	)]
+	pub struct UpdateDeductionRule<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"deduction_rule",
+				employer.key().as_ref(),
+				rule_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub deduction_rule: Account<'info, DeductionRule>,
+	}
+
+/// Change a deduction rule; applies to every employee it is attached to from the next payout
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` deduction_rule: [DeductionRule] 
+///
+/// Data:
+/// - rule_id: [u64] 
+/// - name: [String] At most `MAX_DEDUCTION_NAME_LEN` bytes
+/// - kind: [DeductionKind] 
+/// - remittance_vault: [Pubkey] 
+pub fn handler(
+	ctx: Context<UpdateDeductionRule>,
+	_rule_id: u64,
+	name: String,
+	kind: DeductionKind,
+	remittance_vault: Pubkey,
+) -> Result<()> {
+	DeductionRule::validate(&name, &kind)?;
+
+	let deduction_rule = &mut ctx.accounts.deduction_rule;
+
+	deduction_rule.name = name;
+	deduction_rule.kind = kind;
+	deduction_rule.remittance_vault = remittance_vault;
+
+	Ok(())
+}
@@ -15,6 +15,8 @@ use std::str::FromStr;
 	pub struct UpdateEmployee<'info> {
 		pub authority: Signer<'info>,
 
+		pub employer: Account<'info, Employer>,
+
 		#[account(
 			mut,
 			seeds = [
@@ -22,6 +24,8 @@ use std::str::FromStr;
 				employee_wallet.as_ref(),
 			],
 			bump,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+			has_one = employer,
 		)]
 		pub employee: Account<'info, Employee>,
 	}
@@ -30,23 +34,61 @@ use std::str::FromStr;
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` employee: [Employee] 
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
 ///
 /// Data:
-/// - name: [String] 
-/// - position: [String] 
-/// - hourly_rate: [u64] 
-/// - is_active: [bool] 
-/// - employee_wallet: [Pubkey] 
+/// - name: [String] Non-blank, at most `MAX_NAME_LEN` bytes
+/// - position: [String] At most `MAX_POSITION_LEN` bytes
+/// - hourly_rate: [u64] Increases above the employer threshold, or within the delay of the last
+///   immediate one, are queued behind a timelock
+/// - is_active: [bool] Terminated employees cannot be reactivated
+/// - employee_wallet: [Pubkey] Only used to derive the employee PDA; payout
+///   wallet changes go through `propose_payout_wallet`/`accept_payout_wallet`
 pub fn handler(
 	ctx: Context<UpdateEmployee>,
 	name: String,
 	position: String,
 	hourly_rate: u64,
 	is_active: bool,
-	employee_wallet: Pubkey,
+	_employee_wallet: Pubkey,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	Employee::validate_profile(&name, &position)?;
+
+	let employer = &ctx.accounts.employer;
+	let employee = &mut ctx.accounts.employee;
+	require!(!(is_active && employee.is_terminated), EmployeePayrollError::EmployeeTerminated);
+
+	employee.name = name;
+	employee.position = position;
+	employee.is_active = is_active;
+
+	let unchanged = hourly_rate == employee.hourly_rate
+		|| (employee.pending_rate_effective_at != 0 && hourly_rate == employee.pending_hourly_rate);
+	if unchanged {
+		return Ok(());
+	}
+
+	let now = Clock::get()?.unix_timestamp;
+	if employee.raise_needs_review(employer, employee.hourly_rate, hourly_rate, now) {
+		let effective_at = now + employer.rate_increase_delay;
+		employee.pending_hourly_rate = hourly_rate;
+		employee.pending_rate_effective_at = effective_at;
+
+		emit!(RateChangeQueued {
+			employee: employee.key(),
+			current_hourly_rate: employee.hourly_rate,
+			pending_hourly_rate: hourly_rate,
+			effective_at,
+		});
+	} else {
+		if hourly_rate > employee.hourly_rate {
+			employee.last_raise_at = now;
+		}
+		employee.hourly_rate = hourly_rate;
+		employee.pending_hourly_rate = 0;
+		employee.pending_rate_effective_at = 0;
+	}
+
 	Ok(())
 }
@@ -0,0 +1,55 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		project_id: u64,
+	)]
+	pub struct UpdateProject<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			constraint = authority.key() == employer.authority
+				|| authority.key() == employer.payroll_manager @ EmployeePayrollError::Unauthorized,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"project",
+				employer.key().as_ref(),
+				project_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub project: Account<'info, Project>,
+	}
+
+/// Change a project's bill rate, or stop new sessions from being tagged with it
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` project: [Project] 
+///
+/// Data:
+/// - project_id: [u64] 
+/// - bill_rate: [u64] Lamports per hour billed to the client from now on, 0 for internal work
+/// - is_active: [bool] Whether new sessions may be tagged with the project
+pub fn handler(
+	ctx: Context<UpdateProject>,
+	_project_id: u64,
+	bill_rate: u64,
+	is_active: bool,
+) -> Result<()> {
+	let project = &mut ctx.accounts.project;
+
+	project.bill_rate = bill_rate;
+	project.is_active = is_active;
+
+	Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct VetoRateChange<'info> {
+		#[account(
+			address = employer.rate_approver @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+	}
+
+/// Rate approver cancels a pending hourly rate increase
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer rate approver
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+pub fn handler(
+	ctx: Context<VetoRateChange>,
+	_employee_wallet: Pubkey,
+) -> Result<()> {
+	let employee = &mut ctx.accounts.employee;
+	require!(employee.pending_rate_effective_at != 0, EmployeePayrollError::NoPendingRateChange);
+
+	let vetoed_hourly_rate = employee.pending_hourly_rate;
+	employee.pending_hourly_rate = 0;
+	employee.pending_rate_effective_at = 0;
+
+	emit!(RateChangeVetoed {
+		employee: employee.key(),
+		vetoed_hourly_rate,
+		approver: ctx.accounts.authority.key(),
+	});
+
+	Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		amount: u64,
+	)]
+	pub struct WithdrawFromVault<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			has_one = authority @ EmployeePayrollError::Unauthorized,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub destination: SystemAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Withdraw lamports from the employer payroll vault
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[writable]` employer: [Employer] Withdrawals count towards its spending limits
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` vault: [AccountInfo] 
+/// 4. `[writable]` destination: [AccountInfo] 
+/// 5. `[]` system_program: [AccountInfo] 
+///
+/// Data:
+/// - amount: [u64] 
+pub fn handler(
+	ctx: Context<WithdrawFromVault>,
+	amount: u64,
+) -> Result<()> {
+	require_gte!(ctx.accounts.vault.lamports(), amount, EmployeePayrollError::InsufficientVaultBalance);
+
+	let now = Clock::get()?.unix_timestamp;
+	let employer = &mut ctx.accounts.employer;
+	if employer.record_outflow(amount, now, true)? {
+		emit!(SpendingLimitOverridden {
+			employer: employer.key(),
+			amount,
+			owner: employer.authority,
+		});
+	}
+
+	let employer_key = ctx.accounts.employer.key();
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"vault",
+		employer_key.as_ref(),
+		&[ctx.bumps.vault],
+	]];
+
+	transfer(
+		CpiContext::new_with_signer(
+			ctx.accounts.system_program.to_account_info(),
+			Transfer {
+				from: ctx.accounts.vault.to_account_info(),
+				to: ctx.accounts.destination.to_account_info(),
+			},
+			signer_seeds,
+		),
+		amount,
+	)
+}
@@ -0,0 +1,142 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		employee_wallet: Pubkey,
+	)]
+	pub struct WithdrawStreamed<'info> {
+		#[account(
+			address = employee_wallet @ EmployeePayrollError::Unauthorized,
+		)]
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			constraint = !employer.paused @ EmployeePayrollError::EmployerPaused,
+		)]
+		pub employer: Account<'info, Employer>,
+
+		#[account(
+			seeds = [
+				b"program_config",
+			],
+			bump = program_config.bump,
+			constraint = !program_config.paused @ EmployeePayrollError::ProgramPaused,
+		)]
+		pub program_config: Account<'info, ProgramConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			bump,
+			has_one = employer,
+		)]
+		pub employee: Account<'info, Employee>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"pay_stream",
+				employee.key().as_ref(),
+			],
+			bump,
+			constraint = pay_stream.is_cancelled || !employee.is_terminated @ EmployeePayrollError::EmployeeTerminated,
+		)]
+		pub pay_stream: Account<'info, PayStream>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				employer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			address = employee.payout_wallet @ EmployeePayrollError::PayoutWalletMismatch,
+		)]
+		pub payout_wallet: SystemAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Employee withdraws pay accrued on their pay stream so far
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` employee: [Employee] 
+/// 4. `[writable]` pay_stream: [PayStream] 
+/// 5. `[writable]` vault: [AccountInfo] 
+/// 6. `[writable]` payout_wallet: [AccountInfo] 
+/// 7. `[]` system_program: [AccountInfo] 
+///    8..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - amount: [u64] Lamports to withdraw, at most what has accrued
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, WithdrawStreamed<'info>>,
+	_employee_wallet: Pubkey,
+	amount: u64,
+) -> Result<()> {
+	let now = Clock::get()?.unix_timestamp;
+	let pay_stream = &mut ctx.accounts.pay_stream;
+	pay_stream.accrue(now)?;
+
+	require!(pay_stream.accrued > 0, EmployeePayrollError::NothingToWithdraw);
+	require!(amount > 0 && amount <= pay_stream.accrued, EmployeePayrollError::InvalidWithdrawAmount);
+	pay_stream.accrued -= amount;
+	pay_stream.total_withdrawn = pay_stream
+		.total_withdrawn
+		.checked_add(amount)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+	let (mut payouts, withheld) = withhold(&ctx.accounts.employer, &mut ctx.accounts.employee, ctx.remaining_accounts, amount, now)?;
+	payouts.push((ctx.accounts.payout_wallet.to_account_info(), amount - withheld));
+
+	pay_from_vault(
+		&mut ctx.accounts.employer,
+		&ctx.accounts.vault,
+		ctx.bumps.vault,
+		&ctx.accounts.system_program,
+		&payouts,
+		false,
+	)?;
+
+	let employee = &mut ctx.accounts.employee;
+	employee.total_paid = employee
+		.total_paid
+		.checked_add(amount)
+		.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+	employee.record_earnings(
+		&ctx.accounts.employer,
+		now,
+		&EarningsTotals {
+			gross: amount,
+			net: amount - withheld,
+			deductions: withheld,
+			..Default::default()
+		},
+	)?;
+
+	emit!(StreamWithdrawn {
+		employee: employee.key(),
+		pay_stream: ctx.accounts.pay_stream.key(),
+		amount,
+		withheld,
+	});
+
+	Ok(())
+}
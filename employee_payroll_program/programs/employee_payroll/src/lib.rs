@@ -1,14 +1,23 @@
+#![allow(deprecated)]
 
+pub mod calendar;
+pub mod compression;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod oracle;
 pub mod state;
 
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
+pub use compression::*;
 pub use constants::*;
+pub use error::*;
+pub use events::*;
 pub use instructions::*;
+pub use oracle::*;
 pub use state::*;
 
 declare_id!("CJL2foUtZDC8vE9MF3Tb2BeJrfDZsVCkZD2pkdPV9b4X");
@@ -21,22 +30,26 @@ pub mod employee_payroll {
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable, signer]` employer: [AccountInfo] 
+/// 1. `[writable, signer]` employer: [Employer] 
 /// 2. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
-	pub fn initialize_employer(ctx: Context<InitializeEmployer>) -> Result<()> {
-		initialize_employer::handler(ctx)
+///
+/// Data:
+/// - rate_approver: [Pubkey] Co-signs large raises and rate policy changes, other than the authority
+	pub fn initialize_employer(ctx: Context<InitializeEmployer>, rate_approver: Pubkey) -> Result<()> {
+		initialize_employer::handler(ctx, rate_approver)
 	}
 
 /// Register a new employee
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` employee: [Employee] 
-/// 2. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
-/// - name: [String] 
-/// - position: [String] 
+/// - name: [String] Non-blank, at most `MAX_NAME_LEN` bytes
+/// - position: [String] At most `MAX_POSITION_LEN` bytes
 /// - hourly_rate: [u64] 
 /// - employee_wallet: [Pubkey] 
 	pub fn register_employee(ctx: Context<RegisterEmployee>, name: String, position: String, hourly_rate: u64, employee_wallet: Pubkey) -> Result<()> {
@@ -47,14 +60,17 @@ pub mod employee_payroll {
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` employee: [Employee] 
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
 ///
 /// Data:
-/// - name: [String] 
-/// - position: [String] 
-/// - hourly_rate: [u64] 
-/// - is_active: [bool] 
-/// - employee_wallet: [Pubkey] 
+/// - name: [String] Non-blank, at most `MAX_NAME_LEN` bytes
+/// - position: [String] At most `MAX_POSITION_LEN` bytes
+/// - hourly_rate: [u64] Increases above the employer threshold, or within the delay of the last
+///   immediate one, are queued behind a timelock
+/// - is_active: [bool] Terminated employees cannot be reactivated
+/// - employee_wallet: [Pubkey] Only used to derive the employee PDA; payout
+///   wallet changes go through `propose_payout_wallet`/`accept_payout_wallet`
 	pub fn update_employee(ctx: Context<UpdateEmployee>, name: String, position: String, hourly_rate: u64, is_active: bool, employee_wallet: Pubkey) -> Result<()> {
 		update_employee::handler(ctx, name, position, hourly_rate, is_active, employee_wallet)
 	}
@@ -62,15 +78,18 @@ pub mod employee_payroll {
 /// Employee checks in to start work
 ///
 /// Accounts:
-/// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[]` employee: [Employee] 
-/// 2. `[writable]` work_session: [WorkSession] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 0. `[writable, signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` employee: [Employee] Hourly, as only hourly pay is paid per work session
+/// 4. `[writable]` work_session: [WorkSession] 
+/// 5. `[]` project: [Project] Optional, the project the session is worked on
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - employee_wallet: [Pubkey] 
 /// - session_id: [u64] 
-/// - timestamp: [i64] 
+/// - timestamp: [i64] Unused, the check-in time is taken from the clock
 	pub fn check_in(ctx: Context<CheckIn>, employee_wallet: Pubkey, session_id: u64, timestamp: i64) -> Result<()> {
 		check_in::handler(ctx, employee_wallet, session_id, timestamp)
 	}
@@ -78,14 +97,16 @@ pub mod employee_payroll {
 /// Employee checks out to end work
 ///
 /// Accounts:
-/// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` employee: [Employee] 
-/// 2. `[writable]` work_session: [WorkSession] 
+/// 0. `[signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+/// 4. `[writable]` project: [Project] Optional, required when the session was worked on a project
 ///
 /// Data:
 /// - employee_wallet: [Pubkey] 
 /// - session_id: [u64] 
-/// - timestamp: [i64] 
+/// - timestamp: [i64] Unused, the check-out time is taken from the clock
 	pub fn check_out(ctx: Context<CheckOut>, employee_wallet: Pubkey, session_id: u64, timestamp: i64) -> Result<()> {
 		check_out::handler(ctx, employee_wallet, session_id, timestamp)
 	}
@@ -94,8 +115,9 @@ pub mod employee_payroll {
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` payroll_batch: [PayrollBatch] 
-/// 2. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - batch_id: [u64] 
@@ -107,15 +129,27 @@ pub mod employee_payroll {
 /// Process payments for all employees in a batch
 ///
 /// Accounts:
-/// 0. `[writable, signer]` authority: [AccountInfo] 
-/// 1. `[writable]` payroll_batch: [PayrollBatch] 
-/// 2. `[writable]` employee: [Employee] 
-/// 3. `[writable]` work_session: [WorkSession] 
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[signer]` owner: [AccountInfo] Optional owner co-signature for payouts above the spending limits
+/// 2. `[writable]` employer: [Employer] 
+/// 3. `[]` program_config: [ProgramConfig] 
+/// 4. `[writable]` payroll_batch: [PayrollBatch] 
+/// 5. `[writable]` employee: [Employee] 
+/// 6. `[writable]` work_session: [WorkSession] 
+/// 7. `[writable]` vault: [AccountInfo] 
+/// 8. `[writable]` payout_wallet: [AccountInfo] 
+/// 9. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 10. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 11. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 12. `[writable]` project: [Project] Optional, required when the session was worked on a project
+/// 13. `[]` system_program: [AccountInfo] 
+///     14..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     a wallet for each of the employee's payout splits when paying directly, then any `PayItem`s attached to the batch and approved `Expense`s to pay alongside the session
 ///
 /// Data:
 /// - batch_id: [u64] 
-/// - timestamp: [i64] 
-	pub fn process_payroll(ctx: Context<ProcessPayroll>, batch_id: u64, timestamp: i64) -> Result<()> {
+/// - timestamp: [i64] Unused, `processed_at` is taken from the clock
+	pub fn process_payroll<'info>(ctx: Context<'_, '_, 'info, 'info, ProcessPayroll<'info>>, batch_id: u64, timestamp: i64) -> Result<()> {
 		process_payroll::handler(ctx, batch_id, timestamp)
 	}
 
@@ -123,7 +157,11 @@ pub mod employee_payroll {
 ///
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[writable]` work_session: [WorkSession] 
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` employee: [Employee] 
+/// 4. `[writable]` work_session: [WorkSession] Checked-out session
+/// 5. `[]` payroll_batch: [PayrollBatch] Batch the session is attached to, omitted when it is not attached to one
 ///
 /// Data:
 /// - employee_wallet: [Pubkey] 
@@ -132,6 +170,1044 @@ pub mod employee_payroll {
 		mark_session_paid::handler(ctx, employee_wallet, session_id)
 	}
 
+/// Employee proposes a new payout wallet
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee's identity wallet
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - new_payout_wallet: [Pubkey] Has to sign `accept_payout_wallet` before it receives funds
+	pub fn propose_payout_wallet(ctx: Context<ProposePayoutWallet>, employee_wallet: Pubkey, new_payout_wallet: Pubkey) -> Result<()> {
+		propose_payout_wallet::handler(ctx, employee_wallet, new_payout_wallet)
+	}
+
+/// Proposed payout wallet signs to accept becoming the payout destination
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The pending payout wallet
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn accept_payout_wallet(ctx: Context<AcceptPayoutWallet>, employee_wallet: Pubkey) -> Result<()> {
+		accept_payout_wallet::handler(ctx, employee_wallet)
+	}
+
+/// Initialize the program-wide config holding the guardian key
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Program upgrade authority
+/// 1. `[writable]` program_config: [ProgramConfig] 
+/// 2. `[]` program: [AccountInfo] 
+/// 3. `[]` program_data: [AccountInfo] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - guardian: [Pubkey] 
+	pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>, guardian: Pubkey) -> Result<()> {
+		initialize_program_config::handler(ctx, guardian)
+	}
+
+/// Freeze payouts, vault withdrawals and check-ins for an employer
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or program guardian
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+	pub fn pause_employer(ctx: Context<PauseEmployer>) -> Result<()> {
+		pause_employer::handler(ctx)
+	}
+
+/// Lift an employer pause
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Program guardian, or the employer owner if the guardian did not pause
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+	pub fn unpause_employer(ctx: Context<UnpauseEmployer>) -> Result<()> {
+		unpause_employer::handler(ctx)
+	}
+
+/// Freeze payouts, vault withdrawals and check-ins for every employer
+///
+/// Accounts:
+/// 0. `[signer]` guardian: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] 
+	pub fn pause_program(ctx: Context<PauseProgram>) -> Result<()> {
+		pause_program::handler(ctx)
+	}
+
+/// Lift a program-wide pause
+///
+/// Accounts:
+/// 0. `[signer]` guardian: [AccountInfo] 
+/// 1. `[writable]` program_config: [ProgramConfig] 
+	pub fn unpause_program(ctx: Context<UnpauseProgram>) -> Result<()> {
+		unpause_program::handler(ctx)
+	}
+
+/// Withdraw lamports from the employer payroll vault
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[writable]` employer: [Employer] Withdrawals count towards its spending limits
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` vault: [AccountInfo] 
+/// 4. `[writable]` destination: [AccountInfo] 
+/// 5. `[]` system_program: [AccountInfo] 
+///
+/// Data:
+/// - amount: [u64] 
+	pub fn withdraw_from_vault(ctx: Context<WithdrawFromVault>, amount: u64) -> Result<()> {
+		withdraw_from_vault::handler(ctx, amount)
+	}
+
+/// Delegate routine payroll processing to a payroll manager
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - payroll_manager: [Pubkey] 
+	pub fn set_payroll_manager(ctx: Context<SetPayrollManager>, payroll_manager: Pubkey) -> Result<()> {
+		set_payroll_manager::handler(ctx, payroll_manager)
+	}
+
+/// Configure how much may leave the employer vault per rolling day and per limit period
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - daily_limit: [u64] Lamports per rolling 24 hours, 0 for no cap
+/// - period_limit: [u64] Lamports per limit period, 0 for no cap
+/// - period_duration: [i64] Limit period length in seconds; a new period starts now when it changes
+	pub fn set_spending_limits(ctx: Context<SetSpendingLimits>, daily_limit: u64, period_limit: u64, period_duration: i64) -> Result<()> {
+		set_spending_limits::handler(ctx, daily_limit, period_limit, period_duration)
+	}
+
+/// Configure the timelock for large hourly rate increases
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[signer]` rate_approver_signer: [AccountInfo] Current rate approver
+/// 2. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - rate_increase_threshold_bps: [u16] Increases above this are timelocked
+/// - rate_increase_delay: [i64] Timelock in seconds
+/// - rate_approver: [Pubkey] New rate approver, other than the authority
+	pub fn set_rate_change_policy(ctx: Context<SetRateChangePolicy>, rate_increase_threshold_bps: u16, rate_increase_delay: i64, rate_approver: Pubkey) -> Result<()> {
+		set_rate_change_policy::handler(ctx, rate_increase_threshold_bps, rate_increase_delay, rate_approver)
+	}
+
+/// Apply a timelocked hourly rate increase once its delay has passed
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn apply_rate_change(ctx: Context<ApplyRateChange>, employee_wallet: Pubkey) -> Result<()> {
+		apply_rate_change::handler(ctx, employee_wallet)
+	}
+
+/// Rate approver cancels a pending hourly rate increase
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer rate approver
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn veto_rate_change(ctx: Context<VetoRateChange>, employee_wallet: Pubkey) -> Result<()> {
+		veto_rate_change::handler(ctx, employee_wallet)
+	}
+
+/// Mark a payroll batch as completed once all of its sessions are paid
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+///
+/// Data:
+/// - batch_id: [u64] 
+	pub fn complete_payroll_batch(ctx: Context<CompletePayrollBatch>, batch_id: u64) -> Result<()> {
+		complete_payroll_batch::handler(ctx, batch_id)
+	}
+
+/// Close a paid work session and return its rent to the payer
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner, payroll manager or rent payer
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+/// 4. `[]` payroll_batch: [PayrollBatch] Completed or cancelled batch that paid the session, omitted when it was marked paid outside a batch
+/// 5. `[writable]` rent_payer: [AccountInfo] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+/// - emit_archive: [bool] Emit a `WorkSessionArchived` event before closing
+	pub fn close_work_session(ctx: Context<CloseWorkSession>, employee_wallet: Pubkey, session_id: u64, emit_archive: bool) -> Result<()> {
+		close_work_session::handler(ctx, employee_wallet, session_id, emit_archive)
+	}
+
+/// Close every paid work session of a completed or cancelled batch, returning rent to each payer
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` payroll_batch: [PayrollBatch] 
+///    3..n. `[writable]` remaining accounts: (work_session, rent_payer) pairs
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - emit_archive: [bool] Emit a `WorkSessionArchived` event for each session before closing
+	pub fn close_work_sessions<'info>(ctx: Context<'_, '_, 'info, 'info, CloseWorkSessions<'info>>, batch_id: u64, emit_archive: bool) -> Result<()> {
+		close_work_sessions::handler(ctx, batch_id, emit_archive)
+	}
+
+/// Initialize the concurrent Merkle tree that stores the employer's compressed work sessions
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[writable]` merkle_tree: [AccountInfo] Pre-allocated tree account owned by the compression program
+/// 3. `[]` tree_authority: [AccountInfo] 
+/// 4. `[]` compression_program: [AccountInfo] 
+/// 5. `[]` noop: [AccountInfo] 
+///
+/// Data:
+/// - max_depth: [u32] 
+/// - max_buffer_size: [u32] 
+	pub fn initialize_session_tree(ctx: Context<InitializeSessionTree>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+		initialize_session_tree::handler(ctx, max_depth, max_buffer_size)
+	}
+
+/// Append a checked-out work session to the employer's session tree and close its account
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner, payroll manager or rent payer
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+/// 4. `[]` payroll_batch: [PayrollBatch] Completed or cancelled batch the session is attached to, omitted when it has none
+/// 5. `[writable]` rent_payer: [AccountInfo] 
+/// 6. `[writable]` merkle_tree: [AccountInfo] 
+/// 7. `[]` tree_authority: [AccountInfo] 
+/// 8. `[]` compression_program: [AccountInfo] 
+/// 9. `[]` noop: [AccountInfo] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+	pub fn compress_work_session(ctx: Context<CompressWorkSession>, employee_wallet: Pubkey, session_id: u64) -> Result<()> {
+		compress_work_session::handler(ctx, employee_wallet, session_id)
+	}
+
+/// Pay a compressed work session, proving it against the session tree and marking its leaf paid
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[signer]` owner: [AccountInfo] Optional owner co-signature for payouts above the spending limits
+/// 2. `[writable]` employer: [Employer] 
+/// 3. `[]` program_config: [ProgramConfig] 
+/// 4. `[writable]` payroll_batch: [PayrollBatch] 
+/// 5. `[writable]` employee: [Employee] 
+/// 6. `[writable]` vault: [AccountInfo] 
+/// 7. `[writable]` payout_wallet: [AccountInfo] 
+/// 8. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 9. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 10. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 11. `[writable]` merkle_tree: [AccountInfo] 
+/// 12. `[]` tree_authority: [AccountInfo] 
+/// 13. `[]` compression_program: [AccountInfo] 
+/// 14. `[]` noop: [AccountInfo] 
+/// 15. `[]` system_program: [AccountInfo] 
+///     16..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     then a wallet for each of the employee's payout splits when paying directly
+///     n... `[]` remaining accounts: Merkle proof nodes, leaf to root
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - root: [[u8; 32]] Current root of the session tree
+/// - session: [CompressedWorkSession] Leaf contents, as emitted by `compress_work_session`
+/// - index: [u32] Leaf index
+	pub fn process_compressed_payroll<'info>(ctx: Context<'_, '_, 'info, 'info, ProcessCompressedPayroll<'info>>, batch_id: u64, root: [u8; 32], session: CompressedWorkSession, index: u32) -> Result<()> {
+		process_compressed_payroll::handler(ctx, batch_id, root, session, index)
+	}
+
+/// Rewrite a program account from its original layout to the current version
+///
+/// Accounts:
+/// 0. `[writable, signer]` payer: [AccountInfo] Funds the extra rent when the account grows
+/// 1. `[writable]` account: [AccountInfo] Employee, WorkSession or PayrollBatch
+/// 2. `[]` system_program: [AccountInfo] 
+///    3..n. `[]` remaining accounts: the `Employer` to attach an employee or payroll batch to, owned
+///    by the payer
+	pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+		migrate_account::handler(ctx)
+	}
+
+/// Create a withholding rule (flat, percentage or tiered) for the employer's payouts
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` deduction_rule: [DeductionRule] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - rule_id: [u64] 
+/// - name: [String] At most `MAX_DEDUCTION_NAME_LEN` bytes
+/// - kind: [DeductionKind] 
+/// - remittance_vault: [Pubkey] Receives the withheld lamports
+	pub fn create_deduction_rule(ctx: Context<CreateDeductionRule>, rule_id: u64, name: String, kind: DeductionKind, remittance_vault: Pubkey) -> Result<()> {
+		create_deduction_rule::handler(ctx, rule_id, name, kind, remittance_vault)
+	}
+
+/// Change a deduction rule; applies to every employee it is attached to from the next payout
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` deduction_rule: [DeductionRule] 
+///
+/// Data:
+/// - rule_id: [u64] 
+/// - name: [String] At most `MAX_DEDUCTION_NAME_LEN` bytes
+/// - kind: [DeductionKind] 
+/// - remittance_vault: [Pubkey] 
+	pub fn update_deduction_rule(ctx: Context<UpdateDeductionRule>, rule_id: u64, name: String, kind: DeductionKind, remittance_vault: Pubkey) -> Result<()> {
+		update_deduction_rule::handler(ctx, rule_id, name, kind, remittance_vault)
+	}
+
+/// Attach a deduction rule to an employee, after any rules already attached
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[]` deduction_rule: [DeductionRule] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - rule_id: [u64] 
+	pub fn attach_deduction_rule(ctx: Context<AttachDeductionRule>, employee_wallet: Pubkey, rule_id: u64) -> Result<()> {
+		attach_deduction_rule::handler(ctx, employee_wallet, rule_id)
+	}
+
+/// Stop applying a deduction rule to an employee
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[]` deduction_rule: [DeductionRule] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - rule_id: [u64] 
+	pub fn detach_deduction_rule(ctx: Context<DetachDeductionRule>, employee_wallet: Pubkey, rule_id: u64) -> Result<()> {
+		detach_deduction_rule::handler(ctx, employee_wallet, rule_id)
+	}
+
+/// Set the month the employer's fiscal year starts in, used to roll over year-to-date totals
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - start_month: [u8] 1 for January through 12 for December
+	pub fn set_fiscal_year(ctx: Context<SetFiscalYear>, start_month: u8) -> Result<()> {
+		set_fiscal_year::handler(ctx, start_month)
+	}
+
+/// Switch an employee between hourly, salaried and fixed contractor pay
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[signer]` rate_approver: [AccountInfo] Optional employer rate approver, required when the
+///    pay per period rises by more than the rate increase threshold or within the delay of the last
+///    raise made without it
+/// 2. `[]` employer: [Employer] 
+/// 3. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - compensation: [Compensation] Hourly pay keeps using `hourly_rate`
+	pub fn set_compensation(ctx: Context<SetCompensation>, employee_wallet: Pubkey, compensation: Compensation) -> Result<()> {
+		set_compensation::handler(ctx, employee_wallet, compensation)
+	}
+
+/// Pay a salaried or fixed-fee employee for the batch's pay period, or any terminated employee their final settlement
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[signer]` owner: [AccountInfo] Optional owner co-signature for payouts above the spending limits
+/// 2. `[writable]` employer: [Employer] 
+/// 3. `[]` program_config: [ProgramConfig] 
+/// 4. `[writable]` payroll_batch: [PayrollBatch] 
+/// 5. `[writable]` employee: [Employee] 
+/// 6. `[writable]` vault: [AccountInfo] 
+/// 7. `[writable]` payout_wallet: [AccountInfo] 
+/// 8. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 9. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 10. `[]` system_program: [AccountInfo] 
+///     11..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     a wallet for each of the employee's payout splits when paying directly, then any `PayItem`s attached to the batch and approved `Expense`s to pay alongside the salary
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - timestamp: [i64] Unused, `processed_at` is taken from the clock
+	pub fn process_salary_payroll<'info>(ctx: Context<'_, '_, 'info, 'info, ProcessSalaryPayroll<'info>>, batch_id: u64, timestamp: i64) -> Result<()> {
+		process_salary_payroll::handler(ctx, batch_id, timestamp)
+	}
+
+/// Create a bonus, commission or adjustment to pay an employee outside of work sessions
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` pay_item: [PayItem] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - item_id: [u64] 
+/// - kind: [PayItemKind] Any but `FinalSettlement`, which only `terminate_employee` creates
+/// - amount: [i64] Lamports, negative to recover an overpayment from later payouts
+/// - memo_hash: [[u8; 32]] Hash of the off-chain memo
+	pub fn create_pay_item(ctx: Context<CreatePayItem>, employee_wallet: Pubkey, item_id: u64, kind: PayItemKind, amount: i64, memo_hash: [u8; 32]) -> Result<()> {
+		create_pay_item::handler(ctx, employee_wallet, item_id, kind, amount, memo_hash)
+	}
+
+/// Schedule an unpaid pay item into a payroll batch, replacing any earlier batch
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` pay_item: [PayItem] 
+/// 4. `[]` payroll_batch: [PayrollBatch] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - item_id: [u64] 
+/// - batch_id: [u64] 
+	pub fn attach_pay_item(ctx: Context<AttachPayItem>, employee_wallet: Pubkey, item_id: u64, batch_id: u64) -> Result<()> {
+		attach_pay_item::handler(ctx, employee_wallet, item_id, batch_id)
+	}
+
+/// Employee claims reimbursement for a travel, equipment or other expense
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employee: [Employee] 
+/// 2. `[writable]` expense: [Expense] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - expense_id: [u64] 
+/// - amount: [u64] Lamports to reimburse
+/// - category: [ExpenseCategory] 
+/// - receipt_hash: [[u8; 32]] Hash of the receipt kept off-chain
+	pub fn submit_expense(ctx: Context<SubmitExpense>, employee_wallet: Pubkey, expense_id: u64, amount: u64, category: ExpenseCategory, receipt_hash: [u8; 32]) -> Result<()> {
+		submit_expense::handler(ctx, employee_wallet, expense_id, amount, category, receipt_hash)
+	}
+
+/// Manager approves a submitted expense so the next payroll run reimburses it
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` expense: [Expense] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - expense_id: [u64] 
+	pub fn approve_expense(ctx: Context<ApproveExpense>, employee_wallet: Pubkey, expense_id: u64) -> Result<()> {
+		approve_expense::handler(ctx, employee_wallet, expense_id)
+	}
+
+/// Manager rejects a submitted expense
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` expense: [Expense] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - expense_id: [u64] 
+	pub fn reject_expense(ctx: Context<RejectExpense>, employee_wallet: Pubkey, expense_id: u64) -> Result<()> {
+		reject_expense::handler(ctx, employee_wallet, expense_id)
+	}
+
+/// Start streaming pay to an employee, accruing every second from now on
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[signer]` rate_approver: [AccountInfo] Employer rate approver, as the stream pays outside
+///    the rate change policy
+/// 2. `[]` employer: [Employer] 
+/// 3. `[]` employee: [Employee] 
+/// 4. `[writable]` pay_stream: [PayStream] 
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - rate_per_second: [u64] Lamports accrued per second
+	pub fn create_pay_stream(ctx: Context<CreatePayStream>, employee_wallet: Pubkey, rate_per_second: u64) -> Result<()> {
+		create_pay_stream::handler(ctx, employee_wallet, rate_per_second)
+	}
+
+/// Stop a pay stream from accruing until it is resumed
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` pay_stream: [PayStream] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn pause_pay_stream(ctx: Context<PausePayStream>, employee_wallet: Pubkey) -> Result<()> {
+		pause_pay_stream::handler(ctx, employee_wallet)
+	}
+
+/// Let a paused pay stream accrue again from now
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` pay_stream: [PayStream] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn resume_pay_stream(ctx: Context<ResumePayStream>, employee_wallet: Pubkey) -> Result<()> {
+		resume_pay_stream::handler(ctx, employee_wallet)
+	}
+
+/// End a pay stream for good, keeping what already accrued withdrawable
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` pay_stream: [PayStream] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn cancel_pay_stream(ctx: Context<CancelPayStream>, employee_wallet: Pubkey) -> Result<()> {
+		cancel_pay_stream::handler(ctx, employee_wallet)
+	}
+
+/// Employee withdraws pay accrued on their pay stream so far
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` employee: [Employee] 
+/// 4. `[writable]` pay_stream: [PayStream] 
+/// 5. `[writable]` vault: [AccountInfo] 
+/// 6. `[writable]` payout_wallet: [AccountInfo] 
+/// 7. `[]` system_program: [AccountInfo] 
+///    8..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - amount: [u64] Lamports to withdraw, at most what has accrued
+	pub fn withdraw_streamed<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawStreamed<'info>>, employee_wallet: Pubkey, amount: u64) -> Result<()> {
+		withdraw_streamed::handler(ctx, employee_wallet, amount)
+	}
+
+/// Set how much of their earned, unpaid wages employees may draw early
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - advance_limit_bps: [u16] Share of earned wages in basis points, 0 to disable advances
+	pub fn set_advance_policy(ctx: Context<SetAdvancePolicy>, advance_limit_bps: u16) -> Result<()> {
+		set_advance_policy::handler(ctx, advance_limit_bps)
+	}
+
+/// Employee draws part of the wages earned in approved, unpaid work sessions before payday
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` employee: [Employee] 
+/// 4. `[writable]` vault: [AccountInfo] 
+/// 5. `[writable]` payout_wallet: [AccountInfo] 
+/// 6. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 7. `[]` system_program: [AccountInfo] 
+///    8..n. `[]` remaining accounts: the employee's approved, unpaid `WorkSession`s the advance is drawn against
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - amount: [u64] Lamports to advance; with earlier advances still outstanding, at most
+///   `advance_limit_bps` of the wages earned in the given sessions, converted at the current price
+	pub fn request_advance<'info>(ctx: Context<'_, '_, 'info, 'info, RequestAdvance<'info>>, employee_wallet: Pubkey, amount: u64) -> Result<()> {
+		request_advance::handler(ctx, employee_wallet, amount)
+	}
+
+/// Employee sets the wallets that receive a share of each payout
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee's identity wallet
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - payout_splits: [Vec<PayoutSplit>] Up to `MAX_PAYOUT_SPLITS` wallets with basis-point shares; the rest goes to the payout wallet
+	pub fn set_payout_splits(ctx: Context<SetPayoutSplits>, employee_wallet: Pubkey, payout_splits: Vec<PayoutSplit>) -> Result<()> {
+		set_payout_splits::handler(ctx, employee_wallet, payout_splits)
+	}
+
+/// Choose whether payroll transfers net pay directly or credits claimable balances
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - payout_mode: [PayoutMode] `Claim` requires every paid employee to have a claimable balance
+	pub fn set_payout_mode(ctx: Context<SetPayoutMode>, payout_mode: PayoutMode) -> Result<()> {
+		set_payout_mode::handler(ctx, payout_mode)
+	}
+
+/// Open the account an employee's net pay is credited to when their employer pays by claim
+///
+/// Accounts:
+/// 0. `[writable, signer]` payer: [AccountInfo] Anyone, usually the employer or the employee
+/// 1. `[]` employee: [Employee] 
+/// 2. `[writable]` claimable_balance: [ClaimableBalance] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn open_claimable_balance(ctx: Context<OpenClaimableBalance>, employee_wallet: Pubkey) -> Result<()> {
+		open_claimable_balance::handler(ctx, employee_wallet)
+	}
+
+/// Employee withdraws their claimable balance to their payout wallets
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[]` employee: [Employee] 
+/// 4. `[writable]` claimable_balance: [ClaimableBalance] 
+/// 5. `[writable]` payout_wallet: [AccountInfo] 
+///    6..n. `[writable]` remaining accounts: a wallet for each of the employee's payout splits
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn claim<'info>(ctx: Context<'_, '_, 'info, 'info, Claim<'info>>, employee_wallet: Pubkey) -> Result<()> {
+		claim::handler(ctx, employee_wallet)
+	}
+
+/// Set which prices are trusted to convert reference currency rates at payout
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - max_price_age: [i64] Seconds a price may be old when payroll runs
+/// - max_price_conf_bps: [u16] Widest confidence interval accepted, in basis points of the price
+	pub fn set_price_policy(ctx: Context<SetPricePolicy>, max_price_age: i64, max_price_conf_bps: u16) -> Result<()> {
+		set_price_policy::handler(ctx, max_price_age, max_price_conf_bps)
+	}
+
+/// Move an employee's hourly rate to another currency, priced through an oracle at payout
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[signer]` rate_approver: [AccountInfo] Employer rate approver, as the old and new rates
+///    cannot be compared
+/// 2. `[]` employer: [Employer] 
+/// 3. `[writable]` employee: [Employee] With no work sessions left to pay, which are paid at their rate in the old currency
+/// 4. `[]` price_feed: [AccountInfo] Optional, the `rate_price_feed` account, required unless paying in lamports
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - rate_price_feed: [Pubkey] Price account quoting SOL in the new currency, default to pay in lamports
+/// - rate_feed_id: [[u8; 32]] Feed id every price update read from `rate_price_feed` has to carry
+/// - hourly_rate: [u64] Rate in the new currency, with `REFERENCE_RATE_DECIMALS` decimals unless in lamports
+	pub fn set_rate_currency(ctx: Context<SetRateCurrency>, employee_wallet: Pubkey, rate_price_feed: Pubkey, rate_feed_id: [u8; 32], hourly_rate: u64) -> Result<()> {
+		set_rate_currency::handler(ctx, employee_wallet, rate_price_feed, rate_feed_id, hourly_rate)
+	}
+
+/// Set how much paid leave an employee accrues for each hour worked
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[writable]` employee: [Employee] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - pto_accrual_bps: [u16] Leave per time worked in basis points, e.g. 385 for about one hour every 26 hours
+	pub fn set_pto_accrual(ctx: Context<SetPtoAccrual>, employee_wallet: Pubkey, pto_accrual_bps: u16) -> Result<()> {
+		set_pto_accrual::handler(ctx, employee_wallet, pto_accrual_bps)
+	}
+
+/// Employee requests paid time off out of their accrued leave
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] The employee wallet
+/// 1. `[]` employee: [Employee] 
+/// 2. `[writable]` leave_request: [LeaveRequest] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - request_id: [u64] 
+/// - start_time: [i64] When the leave starts
+/// - duration: [u64] Seconds of leave, at most the employee's `pto_balance`
+	pub fn request_leave(ctx: Context<RequestLeave>, employee_wallet: Pubkey, request_id: u64, start_time: i64, duration: u64) -> Result<()> {
+		request_leave::handler(ctx, employee_wallet, request_id, start_time, duration)
+	}
+
+/// Manager approves a leave request, taking it from the employee's leave balance
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[writable]` leave_request: [LeaveRequest] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - request_id: [u64] 
+	pub fn approve_leave(ctx: Context<ApproveLeave>, employee_wallet: Pubkey, request_id: u64) -> Result<()> {
+		approve_leave::handler(ctx, employee_wallet, request_id)
+	}
+
+/// Create a department to attribute labor cost to, with an optional budget per pay period
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` department: [Department] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - department_id: [u64] Nonzero
+/// - name: [String] At most `MAX_DEPARTMENT_NAME_LEN` bytes
+/// - budget_per_period: [u64] Lamports of gross pay per pay period, 0 for no cap
+	pub fn create_department(ctx: Context<CreateDepartment>, department_id: u64, name: String, budget_per_period: u64) -> Result<()> {
+		create_department::handler(ctx, department_id, name, budget_per_period)
+	}
+
+/// Change a department's budget per pay period
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` department: [Department] 
+///
+/// Data:
+/// - department_id: [u64] 
+/// - budget_per_period: [u64] Lamports of gross pay per pay period, 0 for no cap
+	pub fn set_department_budget(ctx: Context<SetDepartmentBudget>, department_id: u64, budget_per_period: u64) -> Result<()> {
+		set_department_budget::handler(ctx, department_id, budget_per_period)
+	}
+
+/// Move an employee into a department, or out of any when none is given
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[]` department: [Department] Optional
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+	pub fn assign_department(ctx: Context<AssignDepartment>, employee_wallet: Pubkey) -> Result<()> {
+		assign_department::handler(ctx, employee_wallet)
+	}
+
+/// Create a project work sessions can be tagged with, billed to the client at `bill_rate`
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` project: [Project] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - project_id: [u64] Nonzero
+/// - name: [String] At most `MAX_PROJECT_NAME_LEN` bytes
+/// - bill_rate: [u64] Lamports per hour billed to the client, 0 for internal work
+	pub fn create_project(ctx: Context<CreateProject>, project_id: u64, name: String, bill_rate: u64) -> Result<()> {
+		create_project::handler(ctx, project_id, name, bill_rate)
+	}
+
+/// Change a project's bill rate, or stop new sessions from being tagged with it
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` project: [Project] 
+///
+/// Data:
+/// - project_id: [u64] 
+/// - bill_rate: [u64] Lamports per hour billed to the client from now on, 0 for internal work
+/// - is_active: [bool] Whether new sessions may be tagged with the project
+	pub fn update_project(ctx: Context<UpdateProject>, project_id: u64, bill_rate: u64, is_active: bool) -> Result<()> {
+		update_project::handler(ctx, project_id, bill_rate, is_active)
+	}
+
+/// Set the recurring pay schedule that `open_next_pay_period` opens periods from
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - pay_frequency: [PayFrequency] 
+/// - anchor: [i64] Start of the first pay period; on the 1st or 16th for `SemiMonthly`, at most the 28th for `Monthly`. Once a period was opened, not before the next one
+	pub fn set_pay_schedule(ctx: Context<SetPaySchedule>, pay_frequency: PayFrequency, anchor: i64) -> Result<()> {
+		set_pay_schedule::handler(ctx, pay_frequency, anchor)
+	}
+
+/// Open a payroll batch for the employer's next scheduled pay period
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] Batch id from `Employer::pay_period_batch_id`
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+	pub fn open_next_pay_period(ctx: Context<OpenNextPayPeriod>) -> Result<()> {
+		open_next_pay_period::handler(ctx)
+	}
+
+/// Approve a payroll batch so anyone can crank its payouts from the pay date on
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - pay_date: [i64] When `crank_payroll` may start paying the batch, no earlier than the batch's period end
+	pub fn approve_payroll_batch(ctx: Context<ApprovePayrollBatch>, batch_id: u64, pay_date: i64) -> Result<()> {
+		approve_payroll_batch::handler(ctx, batch_id, pay_date)
+	}
+
+/// Set the tip paid from the vault for each session paid by `crank_payroll`
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - crank_tip: [u64] Lamports, at most `MAX_CRANK_TIP`
+	pub fn set_crank_tip(ctx: Context<SetCrankTip>, crank_tip: u64) -> Result<()> {
+		set_crank_tip::handler(ctx, crank_tip)
+	}
+
+/// Pay one work session attached to an approved batch once its pay date has passed, tipping the caller from the vault
+///
+/// Accounts:
+/// 0. `[writable, signer]` cranker: [AccountInfo] Anyone; receives the employer's crank tip
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[]` program_config: [ProgramConfig] 
+/// 3. `[writable]` payroll_batch: [PayrollBatch] 
+/// 4. `[writable]` employee: [Employee] 
+/// 5. `[writable]` work_session: [WorkSession] 
+/// 6. `[writable]` vault: [AccountInfo] 
+/// 7. `[writable]` payout_wallet: [AccountInfo] 
+/// 8. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 9. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 10. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 11. `[writable]` project: [Project] Optional, required when the session was worked on a project
+/// 12. `[]` system_program: [AccountInfo] 
+///     13..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     then a wallet for each of the employee's payout splits when paying directly
+///
+/// Data:
+/// - batch_id: [u64] 
+	pub fn crank_payroll<'info>(ctx: Context<'_, '_, 'info, 'info, CrankPayroll<'info>>, batch_id: u64) -> Result<()> {
+		crank_payroll::handler(ctx, batch_id)
+	}
+
+/// Cancel a payroll batch so it pays nothing more, releasing the pay items and work sessions attached to it
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+///    3..n. `[writable]` remaining accounts: unpaid `PayItem`s and `WorkSession`s attached to the batch, to attach to another one
+///
+/// Data:
+/// - batch_id: [u64] 
+	pub fn cancel_batch<'info>(ctx: Context<'_, '_, 'info, 'info, CancelBatch<'info>>, batch_id: u64) -> Result<()> {
+		cancel_batch::handler(ctx, batch_id)
+	}
+
+/// Set how long after a batch completes its unclaimed credits can be clawed back
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - reversal_window: [i64] Seconds, at most `MAX_REVERSAL_WINDOW`; 0 disallows clawbacks
+	pub fn set_reversal_window(ctx: Context<SetReversalWindow>, reversal_window: i64) -> Result<()> {
+		set_reversal_window::handler(ctx, reversal_window)
+	}
+
+/// Reverse a session's payment while its credit is still unclaimed, returning the credit to the vault and recording why
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+/// 2. `[writable]` payroll_batch: [PayrollBatch] 
+/// 3. `[writable]` employee: [Employee] 
+/// 4. `[writable]` work_session: [WorkSession] 
+/// 5. `[writable]` claimable_balance: [ClaimableBalance] 
+/// 6. `[writable]` vault: [AccountInfo] 
+/// 7. `[writable]` department: [Department] Optional, required when the payment was charged to a department
+/// 8. `[writable]` project: [Project] Optional, required when the session was worked on a project
+///    9..n. `[writable]` remaining accounts: the `PayItem`s then the `Expense`s paid with the session, in the order they were paid
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+/// - batch_id: [u64] 
+/// - reason: [String] At most `MAX_CLAWBACK_REASON_LEN` bytes, recorded in the `CreditClawedBack` event
+	pub fn claw_back_credit<'info>(ctx: Context<'_, '_, 'info, 'info, ClawBackCredit<'info>>, employee_wallet: Pubkey, session_id: u64, batch_id: u64, reason: String) -> Result<()> {
+		claw_back_credit::handler(ctx, employee_wallet, session_id, batch_id, reason)
+	}
+
+/// Set whether terminated employees are paid out their remaining leave balance
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner
+/// 1. `[writable]` employer: [Employer] 
+///
+/// Data:
+/// - pto_payout_on_termination: [bool] Add the leave balance to the final settlement at `hourly_rate`
+	pub fn set_pto_payout(ctx: Context<SetPtoPayout>, pto_payout_on_termination: bool) -> Result<()> {
+		set_pto_payout::handler(ctx, pto_payout_on_termination)
+	}
+
+/// Terminate an employee, checking out their open session, ending their pay stream and creating their final settlement
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] Optional, the employee's open session, checked out now
+/// 4. `[writable]` project: [Project] Optional, required when the open session was worked on a project
+/// 5. `[]` price_feed: [AccountInfo] Optional, required when leave is paid out and the employee's rate is priced in a reference currency
+/// 6. `[writable]` pay_stream: [AccountInfo] Ended now along with the employment, when it exists
+/// 7. `[writable]` final_settlement: [PayItem] Negative when salary was already paid for the rest of the pay period
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - item_id: [u64] Id of the final settlement `PayItem`, to attach to a batch like any other
+	pub fn terminate_employee(ctx: Context<TerminateEmployee>, employee_wallet: Pubkey, item_id: u64) -> Result<()> {
+		terminate_employee::handler(ctx, employee_wallet, item_id)
+	}
+
+/// Close a terminated employee whose final settlement and work sessions have been paid, returning the rent to the owner
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner
+/// 1. `[]` employer: [Employer] 
+/// 2. `[writable]` employee: [Employee] 
+/// 3. `[writable]` final_settlement: [PayItem] 
+/// 4. `[writable]` claimable_balance: [AccountInfo] Closed along with the employee, and must be empty, when it exists
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - item_id: [u64] Id of the final settlement created by `terminate_employee`
+	pub fn close_employee(ctx: Context<CloseEmployee>, employee_wallet: Pubkey, item_id: u64) -> Result<()> {
+		close_employee::handler(ctx, employee_wallet, item_id)
+	}
+
+/// Manager approves the hours of a checked-out work session
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+	pub fn approve_work_session(ctx: Context<ApproveWorkSession>, employee_wallet: Pubkey, session_id: u64) -> Result<()> {
+		approve_work_session::handler(ctx, employee_wallet, session_id)
+	}
+
+/// Schedule a checked-out work session into a payroll batch, approving its hours and replacing any earlier batch
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[]` employer: [Employer] 
+/// 2. `[]` employee: [Employee] 
+/// 3. `[writable]` work_session: [WorkSession] 
+/// 4. `[]` payroll_batch: [PayrollBatch] 
+///
+/// Data:
+/// - employee_wallet: [Pubkey] 
+/// - session_id: [u64] 
+/// - batch_id: [u64] 
+	pub fn attach_work_session(ctx: Context<AttachWorkSession>, employee_wallet: Pubkey, session_id: u64, batch_id: u64) -> Result<()> {
+		attach_work_session::handler(ctx, employee_wallet, session_id, batch_id)
+	}
+
+/// Pay an hourly employee's approved leave on its own, for a pay period they have no work session to pay it with
+///
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] Employer owner or payroll manager
+/// 1. `[signer]` owner: [AccountInfo] Optional owner co-signature for payouts above the spending limits
+/// 2. `[writable]` employer: [Employer] 
+/// 3. `[]` program_config: [ProgramConfig] 
+/// 4. `[writable]` payroll_batch: [PayrollBatch] 
+/// 5. `[writable]` employee: [Employee] 
+/// 6. `[]` price_feed: [AccountInfo] Optional, required when the employee's rate is priced in a reference currency
+/// 7. `[writable]` vault: [AccountInfo] 
+/// 8. `[writable]` payout_wallet: [AccountInfo] 
+/// 9. `[writable]` claimable_balance: [ClaimableBalance] Optional, required when the employer pays by claim
+/// 10. `[writable]` department: [Department] Optional, required when the employee belongs to a department
+/// 11. `[]` system_program: [AccountInfo] 
+///     12..n. `[writable]` remaining accounts: (deduction_rule, remittance_vault) pairs for each of the employee's deduction rules,
+///     a wallet for each of the employee's payout splits when paying directly, then any `PayItem`s attached to the batch and approved `Expense`s to pay alongside the leave
+///
+/// Data:
+/// - batch_id: [u64] 
+/// - timestamp: [i64] Unused, `processed_at` is taken from the clock
+	pub fn process_leave_payroll<'info>(ctx: Context<'_, '_, 'info, 'info, ProcessLeavePayroll<'info>>, batch_id: u64, timestamp: i64) -> Result<()> {
+		process_leave_payroll::handler(ctx, batch_id, timestamp)
+	}
+
 
 
 }
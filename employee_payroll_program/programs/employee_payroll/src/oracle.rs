@@ -0,0 +1,90 @@
+//! Reads Pyth-style `PriceUpdateV2` accounts, used to pay employees whose
+//! `hourly_rate` is set in a reference currency rather than lamports.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use anchor_lang::solana_program::pubkey;
+
+use crate::error::EmployeePayrollError;
+
+/// Owner of the price accounts payroll accepts, the Pyth Solana receiver.
+pub const PRICE_ORACLE_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// `sha256("account:PriceUpdateV2")[..8]`
+pub const PRICE_UPDATE_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationLevel {
+	Partial { num_signatures: u8 },
+	Full,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PriceFeedMessage {
+	pub feed_id: [u8; 32],
+	pub price: i64,
+	pub conf: u64,
+	pub exponent: i32,
+	pub publish_time: i64,
+	pub prev_publish_time: i64,
+	pub ema_price: i64,
+	pub ema_conf: u64,
+}
+
+/// Price of one SOL in the feed's quote currency, as posted by the oracle.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PriceUpdate {
+	pub write_authority: Pubkey,
+	pub verification_level: VerificationLevel,
+	pub price_message: PriceFeedMessage,
+	pub posted_slot: u64,
+}
+
+impl PriceUpdate {
+	/// Parses a fully verified price update for `feed_id` owned by
+	/// `PRICE_ORACLE_ID`.
+	pub fn load(price_feed: &AccountInfo, feed_id: &[u8; 32]) -> Result<Self> {
+		require_keys_eq!(*price_feed.owner, PRICE_ORACLE_ID, EmployeePayrollError::InvalidPriceFeed);
+
+		let data = price_feed.try_borrow_data()?;
+		require!(data.starts_with(&PRICE_UPDATE_DISCRIMINATOR), EmployeePayrollError::InvalidPriceFeed);
+		let price_update = Self::deserialize(&mut &data[8..]).map_err(|_| EmployeePayrollError::InvalidPriceFeed)?;
+		require!(
+			price_update.verification_level == VerificationLevel::Full,
+			EmployeePayrollError::InvalidPriceFeed
+		);
+		require!(
+			price_update.price_message.feed_id == *feed_id,
+			EmployeePayrollError::PriceFeedIdMismatch
+		);
+
+		Ok(price_update)
+	}
+
+	/// Lamports per whole unit of the quote currency, rounded down. The price
+	/// has to be positive, published at most `max_age` seconds before `now`
+	/// and have a confidence interval of at most `max_conf_bps` of the price.
+	pub fn lamports_per_unit(&self, now: i64, max_age: i64, max_conf_bps: u16) -> Result<u64> {
+		let message = &self.price_message;
+		require!(message.price > 0, EmployeePayrollError::InvalidPriceFeed);
+		require!(now.saturating_sub(message.publish_time) <= max_age, EmployeePayrollError::StalePrice);
+		require!(
+			(message.conf as u128) * 10_000 <= (message.price as u128) * (max_conf_bps as u128),
+			EmployeePayrollError::PriceConfidenceTooWide
+		);
+
+		// One SOL costs `price * 10^exponent` units.
+		let scale = 10u128
+			.checked_pow(message.exponent.unsigned_abs())
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+		let (numerator, denominator) = if message.exponent <= 0 {
+			((LAMPORTS_PER_SOL as u128).checked_mul(scale), Some(message.price as u128))
+		} else {
+			(Some(LAMPORTS_PER_SOL as u128), (message.price as u128).checked_mul(scale))
+		};
+		let numerator = numerator.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+		let denominator = denominator.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+		u64::try_from(numerator / denominator).map_err(|_| EmployeePayrollError::ArithmeticOverflow.into())
+	}
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CREDIT_LOCKS;
+use crate::error::EmployeePayrollError;
+
+/// A credit held from `claim` until its reversal window closes at `until`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CreditLock {
+	pub amount: u64,
+	pub until: i64,
+}
+
+/// Net pay credited by payroll in `PayoutMode::Claim`. Holds the lamports
+/// itself, on top of its rent, until the employee pulls them with `claim`.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimableBalance {
+	pub employer: Pubkey,
+	pub employee: Pubkey,
+	/// Lamports credited and not yet claimed.
+	pub amount: u64,
+	pub total_claimed: u64,
+	pub version: u8,
+	/// Parts of `amount` that may still be clawed back, each released once
+	/// its own reversal window has closed.
+	pub locks: [CreditLock; MAX_CREDIT_LOCKS],
+	pub _reserved: [u8; 16],
+}
+
+impl ClaimableBalance {
+	pub const VERSION: u8 = 1;
+
+	/// Lamports still held from `claim` at `now`.
+	pub fn locked(&self, now: i64) -> u64 {
+		self.locks.iter().filter(|lock| lock.until > now).map(|lock| lock.amount).sum()
+	}
+
+	/// Lamports `claim` may pay out at `now`.
+	pub fn claimable(&self, now: i64) -> u64 {
+		self.amount.saturating_sub(self.locked(now))
+	}
+
+	/// Holds `amount` just credited at `now` until `until`, in a lock whose
+	/// window has closed. When every lock is still held, it joins the one
+	/// released last.
+	pub fn lock(&mut self, amount: u64, now: i64, until: i64) -> Result<()> {
+		let lock = match self.locks.iter().position(|lock| lock.until <= now) {
+			Some(free) => {
+				self.locks[free] = CreditLock::default();
+				&mut self.locks[free]
+			}
+			None => self.locks.iter_mut().max_by_key(|lock| lock.until).unwrap(),
+		};
+
+		lock.amount = lock
+			.amount
+			.checked_add(amount)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+		lock.until = lock.until.max(until);
+
+		Ok(())
+	}
+
+	/// Releases `amount` locked until `until` when it is clawed back, from
+	/// the lock it was held in.
+	pub fn unlock(&mut self, amount: u64, until: i64) {
+		if let Some(lock) = self
+			.locks
+			.iter_mut()
+			.filter(|lock| lock.until >= until && lock.amount >= amount)
+			.min_by_key(|lock| lock.until)
+		{
+			lock.amount -= amount;
+		}
+	}
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_DEDUCTION_NAME_LEN, MAX_TAX_BRACKETS};
+use crate::error::EmployeePayrollError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, PartialEq)]
+pub struct TaxBracket {
+	/// Gross amount, in lamports, above which `bps` applies.
+	pub threshold: u64,
+	pub bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, PartialEq)]
+pub enum DeductionKind {
+	/// Fixed lamports per pay period, withheld from its first payout.
+	Flat { amount: u64 },
+	/// Share of the gross payout, in basis points.
+	Percentage { bps: u16 },
+	/// Marginal brackets sorted by ascending threshold; each rate applies to
+	/// the part of the pay period's gross pay between its threshold and the
+	/// next one.
+	Tiered {
+		#[max_len(MAX_TAX_BRACKETS)]
+		brackets: Vec<TaxBracket>,
+	},
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DeductionRule {
+	pub employer: Pubkey,
+	pub rule_id: u64,
+	#[max_len(MAX_DEDUCTION_NAME_LEN)]
+	pub name: String,
+	pub kind: DeductionKind,
+	/// Receives the withheld lamports.
+	pub remittance_vault: Pubkey,
+	pub version: u8,
+	pub _reserved: [u8; 32],
+}
+
+impl DeductionRule {
+	pub const VERSION: u8 = 1;
+
+	pub fn validate(name: &str, kind: &DeductionKind) -> Result<()> {
+		require!(name.len() <= MAX_DEDUCTION_NAME_LEN, EmployeePayrollError::NameTooLong);
+
+		match kind {
+			DeductionKind::Flat { .. } => {}
+			DeductionKind::Percentage { bps } => {
+				require!(*bps <= 10_000, EmployeePayrollError::InvalidDeductionRule);
+			}
+			DeductionKind::Tiered { brackets } => {
+				require!(
+					!brackets.is_empty() && brackets.len() <= MAX_TAX_BRACKETS,
+					EmployeePayrollError::InvalidDeductionRule
+				);
+				require!(
+					brackets.iter().all(|bracket| bracket.bps <= 10_000)
+						&& brackets.windows(2).all(|pair| pair[0].threshold < pair[1].threshold),
+					EmployeePayrollError::InvalidDeductionRule
+				);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Lamports withheld from a `gross` payout, before capping at what is left,
+	/// when `period_gross` has already been paid in the pay period. Flat and
+	/// tiered rules withhold what they owe on the period's pay so far less
+	/// what they already owed before this payout.
+	pub fn withholding(&self, period_gross: u64, gross: u64) -> u64 {
+		match &self.kind {
+			DeductionKind::Percentage { bps } => bps_of(gross, *bps),
+			_ => self
+				.period_withholding(period_gross.saturating_add(gross))
+				.saturating_sub(self.period_withholding(period_gross)),
+		}
+	}
+
+	/// Lamports owed on `period_gross` paid over a whole pay period.
+	fn period_withholding(&self, period_gross: u64) -> u64 {
+		match &self.kind {
+			DeductionKind::Flat { amount } if period_gross > 0 => *amount,
+			DeductionKind::Flat { .. } => 0,
+			DeductionKind::Percentage { bps } => bps_of(period_gross, *bps),
+			DeductionKind::Tiered { brackets } => brackets
+				.iter()
+				.enumerate()
+				.map(|(i, bracket)| {
+					let upper = brackets.get(i + 1).map_or(period_gross, |next| next.threshold.min(period_gross));
+					bps_of(upper.saturating_sub(bracket.threshold), bracket.bps)
+				})
+				.sum(),
+		}
+	}
+}
+
+fn bps_of(amount: u64, bps: u16) -> u64 {
+	((amount as u128) * (bps as u128) / 10_000) as u64
+}
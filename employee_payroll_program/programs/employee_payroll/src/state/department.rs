@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_DEPARTMENT_NAME_LEN;
+use crate::error::EmployeePayrollError;
+use crate::state::Employer;
+
+/// Cost center employees are assigned to. Payroll books the gross pay of
+/// its employees here and keeps each pay period within `budget_per_period`.
+#[account]
+#[derive(InitSpace)]
+pub struct Department {
+	pub employer: Pubkey,
+	/// Nonzero; `Employee::department_id` 0 means no department.
+	pub department_id: u64,
+	#[max_len(MAX_DEPARTMENT_NAME_LEN)]
+	pub name: String,
+	/// Max lamports of gross pay per pay period, 0 for no cap.
+	pub budget_per_period: u64,
+	/// Start of the pay period `period_spent` covers.
+	pub period_start: i64,
+	pub period_spent: u64,
+	pub total_spent: u64,
+	pub version: u8,
+	pub _reserved: [u8; 32],
+}
+
+impl Department {
+	pub const VERSION: u8 = 1;
+
+	pub fn validate(department_id: u64, name: &str) -> Result<()> {
+		require!(department_id != 0, EmployeePayrollError::InvalidDepartment);
+		require!(name.len() <= MAX_DEPARTMENT_NAME_LEN, EmployeePayrollError::NameTooLong);
+
+		Ok(())
+	}
+
+	/// Books `amount` of pay at `now`, starting over when the employer's pay
+	/// period rolls over. Going past the budget is only allowed with
+	/// `override_budget`, and still counts. Returns whether it was exceeded.
+	pub fn record_spend(&mut self, employer: &Employer, amount: u64, now: i64, override_budget: bool) -> Result<bool> {
+		let period_start = employer.current_period_start(now);
+		if period_start > self.period_start {
+			self.period_start = period_start;
+			self.period_spent = 0;
+		}
+
+		let period_spent = self
+			.period_spent
+			.checked_add(amount)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+		let exceeded = self.budget_per_period > 0 && period_spent > self.budget_per_period;
+		require!(!exceeded || override_budget, EmployeePayrollError::DepartmentBudgetExceeded);
+
+		self.period_spent = period_spent;
+		self.total_spent = self
+			.total_spent
+			.checked_add(amount)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+		Ok(exceeded)
+	}
+
+	/// Takes back `amount` of pay booked at `booked_at`, from the period spend
+	/// too while that pay period is still the one tracked.
+	pub fn reverse_spend(&mut self, employer: &Employer, amount: u64, booked_at: i64) {
+		if employer.current_period_start(booked_at) == self.period_start {
+			self.period_spent = self.period_spent.saturating_sub(amount);
+		}
+		self.total_spent = self.total_spent.saturating_sub(amount);
+	}
+}
@@ -1,14 +1,256 @@
 
 use anchor_lang::prelude::*;
 
+use crate::calendar::fiscal_year_start;
+use crate::constants::{MAX_EMPLOYEE_DEDUCTIONS, MAX_NAME_LEN, MAX_PAYOUT_SPLITS, MAX_POSITION_LEN};
+use crate::error::EmployeePayrollError;
+use crate::state::{Employer, PayFrequency};
+
 #[account]
+#[derive(InitSpace)]
 pub struct Employee {
+	#[max_len(MAX_NAME_LEN)]
 	pub name: String,
+	#[max_len(MAX_POSITION_LEN)]
 	pub position: String,
 	pub hourly_rate: u64,
+	/// Sum of checked-out `WorkSession::duration`, in seconds.
 	pub total_hours_worked: u64,
 	pub total_paid: u64,
 	pub authority: Pubkey,
 	pub employee_wallet: Pubkey,
 	pub is_active: bool,
+	/// Destination for payouts. Starts out equal to `employee_wallet`, which
+	/// stays the PDA identity and is never rewritten.
+	pub payout_wallet: Pubkey,
+	/// Wallet proposed by the employee that still has to sign to accept.
+	pub pending_payout_wallet: Option<Pubkey>,
+	pub employer: Pubkey,
+	/// Timelocked hourly rate increase, applied by `apply_rate_change`.
+	pub pending_hourly_rate: u64,
+	/// When the pending rate may be applied, 0 when nothing is pending.
+	pub pending_rate_effective_at: i64,
+	pub version: u8,
+	/// `DeductionRule`s applied, in order, to every payout.
+	#[max_len(MAX_EMPLOYEE_DEDUCTIONS)]
+	pub deduction_rules: Vec<Pubkey>,
+	/// Totals for the fiscal year that started at `ytd_start`.
+	pub ytd: EarningsTotals,
+	pub ytd_start: i64,
+	/// Totals for the pay period that started at `period_start`.
+	pub period_to_date: EarningsTotals,
+	pub period_start: i64,
+	/// How pay is worked out. Hourly employees are paid per `WorkSession` at
+	/// `hourly_rate`, others a fixed amount per pay period.
+	pub compensation: Compensation,
+	/// Start of the last pay period paid by `process_salary_payroll`.
+	pub last_salary_period: i64,
+	/// Negative `PayItem` amounts not yet recovered from a payout.
+	pub outstanding_adjustment: u64,
+	/// Lamports of approved `Expense`s paid out, kept apart from wages.
+	pub total_reimbursed: u64,
+	/// Advances paid by `request_advance` and not yet recovered from payroll.
+	pub outstanding_advance: u64,
+	/// Shares of each payroll payout sent to other wallets; the rest, rounding
+	/// dust included, goes to `payout_wallet`.
+	#[max_len(MAX_PAYOUT_SPLITS)]
+	pub payout_splits: Vec<PayoutSplit>,
+	/// Price account quoting SOL in the currency `hourly_rate` is set in, with
+	/// `REFERENCE_RATE_DECIMALS` decimals. Default when the rate is in lamports.
+	pub rate_price_feed: Pubkey,
+	/// Paid leave accrued per second worked, in basis points.
+	pub pto_accrual_bps: u16,
+	/// Paid leave available to request, in seconds.
+	pub pto_balance: u64,
+	/// Approved leave not yet paid by payroll, in seconds.
+	pub unpaid_leave: u64,
+	/// `Department` the employee's pay is booked against, 0 for none.
+	pub department_id: u64,
+	/// Set by `terminate_employee`; a terminated employee cannot be
+	/// reactivated and is only paid their final settlement.
+	pub is_terminated: bool,
+	/// When pay was last raised without the timelock or the rate approver.
+	/// Until `Employer::rate_increase_delay` has passed, any further raise
+	/// needs one, so small raises cannot add up past the threshold.
+	pub last_raise_at: i64,
+	/// Feed id the price updates in `rate_price_feed` have to carry, as its
+	/// write authority could otherwise post another feed to the account.
+	pub rate_feed_id: [u8; 32],
+	/// Work sessions checked in and not paid yet, open ones included. The
+	/// employee cannot be closed while any are left to pay. Sessions checked
+	/// in before the count was kept are not in it.
+	pub unpaid_sessions: u64,
+	/// Withholding already remitted on pay that was clawed back. The next
+	/// payout withholds as usual but sends this much less to the remittance
+	/// vaults, so the reversed pay is not withheld twice.
+	pub withholding_credit: u64,
+	pub _reserved: [u8; 8],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayoutSplit {
+	pub wallet: Pubkey,
+	/// Share of the payout, in basis points, rounded down.
+	pub bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum Compensation {
+	#[default]
+	Hourly,
+	/// Lamports per year, split evenly over the employer's pay periods.
+	Salaried { annual_salary: u64 },
+	/// Lamports per pay period.
+	ContractorFixed { period_amount: u64 },
+}
+
+impl Compensation {
+	/// Lamports owed for one pay period of `pay_frequency`, 0 for hourly
+	/// pay.
+	pub fn period_pay(&self, pay_frequency: PayFrequency) -> u64 {
+		match *self {
+			Compensation::Hourly => 0,
+			Compensation::Salaried { annual_salary } => annual_salary / pay_frequency.periods_per_year(),
+			Compensation::ContractorFixed { period_amount } => period_amount,
+		}
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Default, Debug, PartialEq)]
+pub struct EarningsTotals {
+	/// Lamports owed before deductions.
+	pub gross: u64,
+	/// Lamports sent to the employee's payout wallet.
+	pub net: u64,
+	/// Lamports withheld by deduction rules.
+	pub deductions: u64,
+	/// Checked-out work, in seconds.
+	pub worked: u64,
+	/// Part of `worked` past `OVERTIME_THRESHOLD` in a session, in seconds.
+	pub overtime: u64,
+}
+
+impl EarningsTotals {
+	fn add(&mut self, other: &EarningsTotals) -> Result<()> {
+		let add = |total: &mut u64, amount: u64| -> Result<()> {
+			*total = total.checked_add(amount).ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+			Ok(())
+		};
+
+		add(&mut self.gross, other.gross)?;
+		add(&mut self.net, other.net)?;
+		add(&mut self.deductions, other.deductions)?;
+		add(&mut self.worked, other.worked)?;
+		add(&mut self.overtime, other.overtime)
+	}
+
+	fn sub(&mut self, other: &EarningsTotals) {
+		self.gross = self.gross.saturating_sub(other.gross);
+		self.net = self.net.saturating_sub(other.net);
+		self.deductions = self.deductions.saturating_sub(other.deductions);
+		self.worked = self.worked.saturating_sub(other.worked);
+		self.overtime = self.overtime.saturating_sub(other.overtime);
+	}
+}
+
+impl Employee {
+	pub const VERSION: u8 = 1;
+
+	/// Adds `earnings` to the year- and period-to-date totals, starting either
+	/// over when `now` falls in a new fiscal year or pay period of `employer`.
+	pub fn record_earnings(&mut self, employer: &Employer, now: i64, earnings: &EarningsTotals) -> Result<()> {
+		let ytd_start = fiscal_year_start(now, employer.fiscal_year_start_month);
+		if ytd_start != self.ytd_start {
+			self.ytd = EarningsTotals::default();
+			self.ytd_start = ytd_start;
+		}
+
+		let period_start = employer.current_period_start(now);
+		if period_start != self.period_start {
+			self.period_to_date = EarningsTotals::default();
+			self.period_start = period_start;
+		}
+
+		self.ytd.add(earnings)?;
+		self.period_to_date.add(earnings)
+	}
+
+	/// Gross pay already recorded in the pay period of `employer` covering `now`.
+	pub fn period_gross(&self, employer: &Employer, now: i64) -> u64 {
+		if employer.current_period_start(now) == self.period_start {
+			self.period_to_date.gross
+		} else {
+			0
+		}
+	}
+
+	/// Takes `earnings` booked at `booked_at` back off the year- and
+	/// period-to-date totals that still cover that time.
+	pub fn reverse_earnings(&mut self, employer: &Employer, booked_at: i64, earnings: &EarningsTotals) {
+		if fiscal_year_start(booked_at, employer.fiscal_year_start_month) == self.ytd_start {
+			self.ytd.sub(earnings);
+		}
+		if employer.current_period_start(booked_at) == self.period_start {
+			self.period_to_date.sub(earnings);
+		}
+	}
+
+	/// Whether raising pay from `current` to `new` at `now` has to wait for
+	/// the timelock or the rate approver: it is above the employer's threshold,
+	/// or comes within `rate_increase_delay` of the last unreviewed raise.
+	pub fn raise_needs_review(&self, employer: &Employer, current: u64, new: u64, now: i64) -> bool {
+		let increase = new.saturating_sub(current) as u128;
+		let allowed_increase = (current as u128) * (employer.rate_increase_threshold_bps as u128) / 10_000;
+
+		increase > allowed_increase || (increase > 0 && now < self.last_raise_at.saturating_add(employer.rate_increase_delay))
+	}
+
+	/// Checks there are at most `MAX_PAYOUT_SPLITS` splits to distinct
+	/// wallets other than the payout wallet, with non-zero shares adding up
+	/// to no more than the whole payout.
+	pub fn validate_payout_splits(&self, payout_splits: &[PayoutSplit]) -> Result<()> {
+		require!(payout_splits.len() <= MAX_PAYOUT_SPLITS, EmployeePayrollError::TooManyPayoutSplits);
+
+		let mut total_bps = 0u32;
+		for (i, split) in payout_splits.iter().enumerate() {
+			require!(
+				split.bps > 0
+					&& split.wallet != Pubkey::default()
+					&& split.wallet != self.payout_wallet
+					&& payout_splits[..i].iter().all(|other| other.wallet != split.wallet),
+				EmployeePayrollError::InvalidPayoutSplits
+			);
+			total_bps += split.bps as u32;
+		}
+		require!(total_bps <= 10_000, EmployeePayrollError::InvalidPayoutSplits);
+
+		Ok(())
+	}
+
+	/// Divides `amount` by `payout_splits`, rounding each share down, and
+	/// returns the split amounts in order followed by the remainder for
+	/// `payout_wallet`.
+	pub fn split_payout(&self, amount: u64) -> (Vec<u64>, u64) {
+		let shares: Vec<u64> = self
+			.payout_splits
+			.iter()
+			.map(|split| ((amount as u128) * (split.bps as u128) / 10_000) as u64)
+			.collect();
+		let remainder = amount - shares.iter().sum::<u64>();
+		(shares, remainder)
+	}
+
+	/// Checks `name` and `position` fit their `#[max_len]` in bytes and hold
+	/// no control characters. The name may not be blank.
+	pub fn validate_profile(name: &str, position: &str) -> Result<()> {
+		require!(!name.trim().is_empty(), EmployeePayrollError::EmptyName);
+		require!(name.len() <= MAX_NAME_LEN, EmployeePayrollError::NameTooLong);
+		require!(position.len() <= MAX_POSITION_LEN, EmployeePayrollError::PositionTooLong);
+		require!(
+			!name.chars().chain(position.chars()).any(char::is_control),
+			EmployeePayrollError::InvalidProfileCharacters
+		);
+
+		Ok(())
+	}
 }
@@ -0,0 +1,241 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::calendar::{civil_date, civil_to_timestamp, fiscal_year_start};
+use crate::error::EmployeePayrollError;
+
+/// How `process_payroll` and friends hand out net pay.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum PayoutMode {
+	/// Transfer straight to the employee's payout wallets.
+	#[default]
+	Push,
+	/// Credit the employee's `ClaimableBalance`, to be pulled with `claim`.
+	Claim,
+}
+
+/// How often pay periods opened by `open_next_pay_period` recur.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayFrequency {
+	Weekly,
+	Biweekly,
+	/// From the 1st to the 15th and from the 16th to the end of the month.
+	SemiMonthly,
+	/// On the anchor's day of the month, which is at most the 28th.
+	Monthly,
+}
+
+impl PayFrequency {
+	/// Checks `anchor` can start a period of this frequency.
+	pub fn validate_anchor(&self, anchor: i64) -> Result<()> {
+		let (_, _, day) = civil_date(anchor);
+		let valid = match self {
+			PayFrequency::Weekly | PayFrequency::Biweekly => true,
+			PayFrequency::SemiMonthly => day == 1 || day == 16,
+			PayFrequency::Monthly => day <= 28,
+		};
+		require!(valid, EmployeePayrollError::InvalidPaySchedule);
+
+		Ok(())
+	}
+
+	/// Start of the period after the one starting at `period_start`, at the
+	/// same time of day.
+	pub fn next_period_start(&self, period_start: i64) -> i64 {
+		let time_of_day = period_start.rem_euclid(86_400);
+		let (year, month, day) = civil_date(period_start);
+		let (next_month_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+
+		match self {
+			PayFrequency::Weekly => period_start + 7 * 86_400,
+			PayFrequency::Biweekly => period_start + 14 * 86_400,
+			PayFrequency::SemiMonthly if day < 16 => civil_to_timestamp(year, month, 16) + time_of_day,
+			PayFrequency::SemiMonthly => civil_to_timestamp(next_month_year, next_month, 1) + time_of_day,
+			PayFrequency::Monthly => civil_to_timestamp(next_month_year, next_month, day) + time_of_day,
+		}
+	}
+
+	pub fn periods_per_year(&self) -> u64 {
+		match self {
+			PayFrequency::Weekly => 52,
+			PayFrequency::Biweekly => 26,
+			PayFrequency::SemiMonthly => 24,
+			PayFrequency::Monthly => 12,
+		}
+	}
+
+	/// Start of the period containing `now` on the schedule through `anchor`,
+	/// which can be any period start before or after `now`.
+	pub fn period_start_at(&self, anchor: i64, now: i64) -> i64 {
+		let time_of_day = anchor.rem_euclid(86_400);
+		let (year, month, day) = civil_date(now - time_of_day);
+		let (last_month_year, last_month) = if month == 1 { (year - 1, 12) } else { (year, month - 1) };
+
+		match self {
+			PayFrequency::Weekly | PayFrequency::Biweekly => {
+				let period_length = self.next_period_start(anchor) - anchor;
+				anchor + (now - anchor).div_euclid(period_length) * period_length
+			},
+			PayFrequency::SemiMonthly if day >= 16 => civil_to_timestamp(year, month, 16) + time_of_day,
+			PayFrequency::SemiMonthly => civil_to_timestamp(year, month, 1) + time_of_day,
+			PayFrequency::Monthly => {
+				let (_, _, anchor_day) = civil_date(anchor);
+				if day >= anchor_day {
+					civil_to_timestamp(year, month, anchor_day) + time_of_day
+				} else {
+					civil_to_timestamp(last_month_year, last_month, anchor_day) + time_of_day
+				}
+			},
+		}
+	}
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Employer {
+	pub authority: Pubkey,
+	/// Set by the owner or the program guardian to freeze payouts, vault
+	/// withdrawals and check-ins for this employer.
+	pub paused: bool,
+	/// A guardian pause can only be lifted by the guardian.
+	pub paused_by_guardian: bool,
+	/// May run `process_payroll` without the owner. Defaults to the owner.
+	pub payroll_manager: Pubkey,
+	/// Max lamports leaving the vault in any rolling 24 hours, 0 for no cap.
+	pub daily_limit: u64,
+	/// Max lamports leaving the vault per limit period, 0 for no cap.
+	pub period_limit: u64,
+	/// Length of the window `period_limit` applies to. It only paces vault
+	/// outflow; pay periods come from the pay schedule.
+	pub period_duration: i64,
+	pub period_start: i64,
+	pub period_spent: u64,
+	/// Vault outflow per hour of the rolling day, indexed by `hour % 24`.
+	pub hourly_outflow: [u64; 24],
+	pub last_outflow_hour: i64,
+	/// Hourly rate increases above this many basis points are timelocked.
+	pub rate_increase_threshold_bps: u16,
+	/// Seconds a large rate increase waits before it can be applied.
+	pub rate_increase_delay: i64,
+	/// Can veto pending rate increases and must co-sign policy changes.
+	pub rate_approver: Pubkey,
+	/// Concurrent Merkle tree holding compressed work sessions, default until
+	/// `initialize_session_tree` runs.
+	pub session_tree: Pubkey,
+	/// Leaves appended to `session_tree`, i.e. the next leaf index.
+	pub compressed_session_count: u64,
+	pub version: u8,
+	/// First month (1-12) of the fiscal year used for year-to-date totals.
+	pub fiscal_year_start_month: u8,
+	/// Share of earned, unpaid wages, in basis points, employees may take as
+	/// an advance. 0 disables `request_advance`.
+	pub advance_limit_bps: u16,
+	pub payout_mode: PayoutMode,
+	/// Oldest price, in seconds, accepted to convert reference currency rates.
+	pub max_price_age: i64,
+	/// Widest price confidence interval accepted, in basis points of the price.
+	pub max_price_conf_bps: u16,
+	/// Schedule `open_next_pay_period` follows, none until `set_pay_schedule`.
+	pub pay_frequency: Option<PayFrequency>,
+	/// Start of the next pay period `open_next_pay_period` opens.
+	pub next_pay_period_start: i64,
+	/// Lamports paid from the vault to whoever pays a session with
+	/// `crank_payroll`.
+	pub crank_tip: u64,
+	/// Seconds after a batch completes during which unclaimed credits it
+	/// paid can be clawed back, 0 to disallow clawbacks.
+	pub reversal_window: i64,
+	/// Pay out an employee's remaining leave balance in their final
+	/// settlement when they are terminated.
+	pub pto_payout_on_termination: bool,
+	/// Whether `open_next_pay_period` has opened a period, after which the
+	/// schedule can no longer be moved back.
+	pub pay_period_opened: bool,
+	pub _reserved: [u8; 22],
+}
+
+impl Employer {
+	pub const VERSION: u8 = 1;
+
+	/// Pay period of the pay schedule containing `now`, as `(start, end)`,
+	/// none until `set_pay_schedule`.
+	pub fn pay_period_at(&self, now: i64) -> Option<(i64, i64)> {
+		self.pay_frequency.map(|pay_frequency| {
+			let period_start = pay_frequency.period_start_at(self.next_pay_period_start, now);
+			(period_start, pay_frequency.next_period_start(period_start))
+		})
+	}
+
+	/// Start of the pay period containing `now`, or of the fiscal year when
+	/// no pay schedule is set.
+	pub fn current_period_start(&self, now: i64) -> i64 {
+		match self.pay_period_at(now) {
+			Some((period_start, _)) => period_start,
+			None => fiscal_year_start(now, self.fiscal_year_start_month),
+		}
+	}
+
+	/// Batch id of `employer`'s scheduled pay period starting at
+	/// `period_start`. Batch addresses are seeded with the employer as well, so
+	/// no other employer can open a batch at it first.
+	pub fn pay_period_batch_id(employer: &Pubkey, period_start: i64) -> u64 {
+		let digest = hashv(&[b"pay_period", employer.as_ref(), &period_start.to_le_bytes()]).to_bytes();
+		u64::from_le_bytes(digest[..8].try_into().unwrap())
+	}
+
+	/// Books `amount` leaving the vault at `now` against the daily and period
+	/// limits. Outflows past a limit are only allowed when the owner signed,
+	/// and still count towards it. Returns whether a limit was exceeded.
+	pub fn record_outflow(&mut self, amount: u64, now: i64, owner_signed: bool) -> Result<bool> {
+		let hour = now.div_euclid(3_600);
+		if hour > self.last_outflow_hour {
+			let stale_hours = (hour - self.last_outflow_hour).min(24);
+			for offset in 0..stale_hours {
+				let slot = (hour - offset).rem_euclid(24) as usize;
+				self.hourly_outflow[slot] = 0;
+			}
+			self.last_outflow_hour = hour;
+		}
+
+		if self.period_duration > 0 && now >= self.period_start + self.period_duration {
+			let elapsed_periods = (now - self.period_start) / self.period_duration;
+			self.period_start += elapsed_periods * self.period_duration;
+			self.period_spent = 0;
+		}
+
+		let daily_outflow = self
+			.hourly_outflow
+			.iter()
+			.try_fold(amount, |total, spent| total.checked_add(*spent))
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+		let period_outflow = self
+			.period_spent
+			.checked_add(amount)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+		let exceeded = (self.daily_limit > 0 && daily_outflow > self.daily_limit)
+			|| (self.period_limit > 0 && period_outflow > self.period_limit);
+		require!(!exceeded || owner_signed, EmployeePayrollError::SpendingLimitExceeded);
+
+		let slot = hour.rem_euclid(24) as usize;
+		self.hourly_outflow[slot] += amount;
+		self.period_spent = period_outflow;
+
+		Ok(exceeded)
+	}
+
+	/// Takes `amount` returned to the vault off the outflow booked at
+	/// `booked_at`, as far as the daily window and pay period still hold it.
+	pub fn reverse_outflow(&mut self, amount: u64, booked_at: i64) {
+		let hour = booked_at.div_euclid(3_600);
+		if self.last_outflow_hour - hour < 24 {
+			let slot = hour.rem_euclid(24) as usize;
+			self.hourly_outflow[slot] = self.hourly_outflow[slot].saturating_sub(amount);
+		}
+
+		if booked_at >= self.period_start {
+			self.period_spent = self.period_spent.saturating_sub(amount);
+		}
+	}
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpenseCategory {
+	Travel,
+	Equipment,
+	Meals,
+	Other,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpenseStatus {
+	Submitted,
+	Approved,
+	Rejected,
+	Paid,
+}
+
+/// Reimbursement claimed by an employee. Approved expenses are paid by
+/// `process_payroll` without deductions and are not counted as wages.
+#[account]
+#[derive(InitSpace)]
+pub struct Expense {
+	pub employer: Pubkey,
+	pub employee: Pubkey,
+	pub expense_id: u64,
+	pub category: ExpenseCategory,
+	/// Lamports to reimburse.
+	pub amount: u64,
+	/// Hash of the receipt kept off-chain.
+	pub receipt_hash: [u8; 32],
+	pub status: ExpenseStatus,
+	pub submitted_at: i64,
+	/// Manager who approved or rejected the claim, default until reviewed.
+	pub reviewed_by: Pubkey,
+	pub reviewed_at: i64,
+	/// `PayrollBatch` the expense was paid in, default until paid.
+	pub batch: Pubkey,
+	pub version: u8,
+	pub _reserved: [u8; 32],
+}
+
+impl Expense {
+	pub const VERSION: u8 = 1;
+}
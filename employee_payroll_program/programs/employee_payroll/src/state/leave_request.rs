@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaveStatus {
+	Requested,
+	Approved,
+}
+
+/// Paid time off requested by an employee. Approval takes `duration` from
+/// the employee's `pto_balance`; hourly employees are paid for it with their
+/// next work session.
+#[account]
+#[derive(InitSpace)]
+pub struct LeaveRequest {
+	pub employer: Pubkey,
+	pub employee: Pubkey,
+	pub request_id: u64,
+	pub start_time: i64,
+	/// Seconds of leave.
+	pub duration: u64,
+	pub status: LeaveStatus,
+	pub requested_at: i64,
+	/// Manager who approved the request, default until approved.
+	pub reviewed_by: Pubkey,
+	pub reviewed_at: i64,
+	pub version: u8,
+	pub _reserved: [u8; 32],
+}
+
+impl LeaveRequest {
+	pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,151 @@
+//! Previous account layouts, read by `migrate_account`. Version 0 is the
+//! original layout, before accounts carried a `version` byte; later versions
+//! are named after the `VERSION` they were written with.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_NAME_LEN, MAX_POSITION_LEN};
+use crate::state::*;
+
+// The original employee, allocated with a fixed 205 bytes and not tied to an
+// employer.
+#[derive(AnchorDeserialize)]
+pub struct EmployeeV0 {
+	pub name: String,
+	pub position: String,
+	pub hourly_rate: u64,
+	pub total_hours_worked: u64,
+	pub total_paid: u64,
+	pub authority: Pubkey,
+	pub employee_wallet: Pubkey,
+	pub is_active: bool,
+}
+
+impl Space for EmployeeV0 {
+	const INIT_SPACE: usize = 205 - 8;
+}
+
+impl EmployeeV0 {
+	/// Attaches the employee to `employer`. Names and positions were not
+	/// bounded then, so they are cut to the current limits.
+	pub fn upgrade(self, employer: Pubkey) -> Employee {
+		Employee {
+			name: truncate_utf8(self.name, MAX_NAME_LEN),
+			position: truncate_utf8(self.position, MAX_POSITION_LEN),
+			hourly_rate: self.hourly_rate,
+			total_hours_worked: self.total_hours_worked,
+			total_paid: self.total_paid,
+			authority: self.authority,
+			employee_wallet: self.employee_wallet,
+			is_active: self.is_active,
+			payout_wallet: self.employee_wallet,
+			pending_payout_wallet: None,
+			employer,
+			pending_hourly_rate: 0,
+			pending_rate_effective_at: 0,
+			version: Employee::VERSION,
+			deduction_rules: vec![],
+			ytd: EarningsTotals::default(),
+			ytd_start: 0,
+			period_to_date: EarningsTotals::default(),
+			period_start: 0,
+			compensation: Compensation::Hourly,
+			last_salary_period: 0,
+			outstanding_adjustment: 0,
+			total_reimbursed: 0,
+			outstanding_advance: 0,
+			payout_splits: vec![],
+			rate_price_feed: Pubkey::default(),
+			pto_accrual_bps: 0,
+			pto_balance: 0,
+			unpaid_leave: 0,
+			department_id: 0,
+			is_terminated: false,
+			last_raise_at: 0,
+			rate_feed_id: [0; 32],
+			unpaid_sessions: 0,
+			withholding_credit: 0,
+			_reserved: [0; 8],
+		}
+	}
+}
+
+fn truncate_utf8(mut text: String, max_len: usize) -> String {
+	if text.len() > max_len {
+		let mut len = max_len;
+		while !text.is_char_boundary(len) {
+			len -= 1;
+		}
+		text.truncate(len);
+	}
+	text
+}
+
+// The original work session, allocated with a fixed 105 bytes.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct WorkSessionV0 {
+	pub employee: Pubkey,
+	pub check_in_time: i64,
+	pub check_out_time: i64,
+	pub duration: u64,
+	pub is_paid: bool,
+	pub authority: Pubkey,
+	pub session_id: u64,
+}
+
+impl From<WorkSessionV0> for WorkSession {
+	fn from(old: WorkSessionV0) -> Self {
+		// The authority paid the rent when checking in.
+		Self {
+			employee: old.employee,
+			check_in_time: old.check_in_time,
+			check_out_time: old.check_out_time,
+			duration: old.duration,
+			is_paid: old.is_paid,
+			authority: old.authority,
+			session_id: old.session_id,
+			batch: Pubkey::default(),
+			rent_payer: old.authority,
+			version: WorkSession::VERSION,
+			exchange_rate: 0,
+			project_id: 0,
+			credited: 0,
+			is_approved: false,
+			payment: PaymentRecord::default(),
+			hourly_rate: 0,
+			_reserved: [0; 7],
+		}
+	}
+}
+
+// The original payroll batch, allocated with a fixed 73 bytes and not tied to
+// an employer.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct PayrollBatchV0 {
+	pub batch_id: u64,
+	pub total_amount: u64,
+	pub created_at: i64,
+	pub processed_at: i64,
+	pub is_processed: bool,
+	pub authority: Pubkey,
+}
+
+impl PayrollBatchV0 {
+	pub fn upgrade(self, employer: Pubkey) -> PayrollBatch {
+		PayrollBatch {
+			batch_id: self.batch_id,
+			total_amount: self.total_amount,
+			created_at: self.created_at,
+			processed_at: self.processed_at,
+			is_processed: self.is_processed,
+			authority: self.authority,
+			employer,
+			version: PayrollBatch::VERSION,
+			period_start: 0,
+			period_end: 0,
+			pay_date: 0,
+			is_cancelled: false,
+			_reserved: [0; 7],
+		}
+	}
+}
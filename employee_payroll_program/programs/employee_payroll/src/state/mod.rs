@@ -1,10 +1,37 @@
 
+//! Program accounts. Every account ends with a `version` byte and zeroed
+//! `_reserved` bytes. New fields go between the two and are carved out of the
+//! reserved bytes, so existing accounts read them as zero. When that does not
+//! fit, the type grows, `VERSION` is bumped, and the previous layout is kept
+//! in `legacy` so `migrate_account` can rewrite old accounts.
+
 use anchor_lang::prelude::*;
 
+pub mod employer;
+pub mod program_config;
 pub mod employee;
 pub mod work_session;
 pub mod payroll_batch;
+pub mod deduction_rule;
+pub mod pay_item;
+pub mod expense;
+pub mod pay_stream;
+pub mod claimable_balance;
+pub mod leave_request;
+pub mod department;
+pub mod project;
+pub mod legacy;
 
+pub use employer::*;
+pub use program_config::*;
 pub use employee::*;
 pub use work_session::*;
 pub use payroll_batch::*;
+pub use deduction_rule::*;
+pub use pay_item::*;
+pub use expense::*;
+pub use pay_stream::*;
+pub use claimable_balance::*;
+pub use leave_request::*;
+pub use department::*;
+pub use project::*;
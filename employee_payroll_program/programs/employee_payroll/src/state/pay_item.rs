@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayItemKind {
+	Bonus,
+	Commission,
+	RetroAdjustment,
+	Correction,
+	/// Final pay worked out by `terminate_employee`.
+	FinalSettlement,
+}
+
+/// One-off amount paid by `process_payroll` on top of hours-based pay.
+#[account]
+#[derive(InitSpace)]
+pub struct PayItem {
+	pub employer: Pubkey,
+	pub employee: Pubkey,
+	pub item_id: u64,
+	pub kind: PayItemKind,
+	/// Lamports added to the payout; negative amounts are recovered from it.
+	pub amount: i64,
+	/// Hash of the off-chain memo explaining the item.
+	pub memo_hash: [u8; 32],
+	/// `PayrollBatch` the item is paid in, default until attached.
+	pub batch: Pubkey,
+	pub is_paid: bool,
+	pub created_at: i64,
+	pub version: u8,
+	pub _reserved: [u8; 32],
+}
+
+impl PayItem {
+	pub const VERSION: u8 = 1;
+}
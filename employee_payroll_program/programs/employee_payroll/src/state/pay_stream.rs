@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::error::EmployeePayrollError;
+
+/// Pay that accrues every second from the employer's vault and is pulled by
+/// the employee with `withdraw_streamed`.
+#[account]
+#[derive(InitSpace)]
+pub struct PayStream {
+	pub employer: Pubkey,
+	pub employee: Pubkey,
+	/// Lamports accrued per second while the stream runs.
+	pub rate_per_second: u64,
+	pub started_at: i64,
+	/// Time up to which `accrued` has been brought forward.
+	pub last_accrual_at: i64,
+	/// Lamports accrued and not yet withdrawn.
+	pub accrued: u64,
+	pub total_withdrawn: u64,
+	pub is_paused: bool,
+	/// Cancelled streams stop accruing for good; what already accrued can
+	/// still be withdrawn.
+	pub is_cancelled: bool,
+	pub version: u8,
+	pub _reserved: [u8; 32],
+}
+
+impl PayStream {
+	pub const VERSION: u8 = 1;
+
+	/// Brings `accrued` forward to `now`. Nothing accrues while the stream
+	/// is paused or cancelled, or for time before `last_accrual_at`.
+	pub fn accrue(&mut self, now: i64) -> Result<()> {
+		if !self.is_paused && !self.is_cancelled && now > self.last_accrual_at {
+			let elapsed = (now - self.last_accrual_at) as u64;
+			self.accrued = elapsed
+				.checked_mul(self.rate_per_second)
+				.and_then(|amount| amount.checked_add(self.accrued))
+				.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+		}
+		self.last_accrual_at = self.last_accrual_at.max(now);
+
+		Ok(())
+	}
+}
@@ -2,6 +2,7 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(InitSpace)]
 pub struct PayrollBatch {
 	pub batch_id: u64,
 	pub total_amount: u64,
@@ -9,4 +10,37 @@ pub struct PayrollBatch {
 	pub processed_at: i64,
 	pub is_processed: bool,
 	pub authority: Pubkey,
+	pub employer: Pubkey,
+	pub version: u8,
+	/// Pay period the batch pays, from `open_next_pay_period`. Both are 0 for
+	/// batches created with `create_payroll_batch`, which pay any session and
+	/// cannot be approved for cranking.
+	pub period_start: i64,
+	pub period_end: i64,
+	/// When `crank_payroll` may start paying the batch, 0 until approved.
+	pub pay_date: i64,
+	/// Set by `cancel_batch`; a cancelled batch pays nothing more.
+	pub is_cancelled: bool,
+	pub _reserved: [u8; 7],
+}
+
+impl PayrollBatch {
+	pub const VERSION: u8 = 1;
+
+	/// Whether a session worked from `check_in_time` to `check_out_time` falls
+	/// entirely in the batch's pay period.
+	pub fn covers(&self, check_in_time: i64, check_out_time: i64) -> bool {
+		!self.has_pay_period()
+			|| (check_in_time >= self.period_start && check_out_time <= self.period_end)
+	}
+
+	/// Whether the batch will pay nothing more, having completed or been
+	/// cancelled.
+	pub fn is_closed(&self) -> bool {
+		self.is_processed || self.is_cancelled
+	}
+
+	pub fn has_pay_period(&self) -> bool {
+		self.period_end != 0
+	}
 }
@@ -0,0 +1,17 @@
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+	/// Key allowed to pause the whole program or any single employer.
+	pub guardian: Pubkey,
+	pub paused: bool,
+	pub bump: u8,
+	pub version: u8,
+	pub _reserved: [u8; 32],
+}
+
+impl ProgramConfig {
+	pub const VERSION: u8 = 1;
+}
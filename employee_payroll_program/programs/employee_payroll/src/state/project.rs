@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PROJECT_NAME_LEN;
+use crate::error::EmployeePayrollError;
+
+/// Client project work sessions are tagged with at check-in. Collects the
+/// time worked on it, what that time bills to the client and what payroll
+/// paid for it.
+#[account]
+#[derive(InitSpace)]
+pub struct Project {
+	pub employer: Pubkey,
+	/// Nonzero; `WorkSession::project_id` 0 means no project.
+	pub project_id: u64,
+	#[max_len(MAX_PROJECT_NAME_LEN)]
+	pub name: String,
+	/// Lamports per hour billed to the client, 0 for internal work.
+	pub bill_rate: u64,
+	/// Whether new sessions may be tagged with the project.
+	pub is_active: bool,
+	/// Checked-out work on the project, in seconds.
+	pub seconds_worked: u64,
+	/// Part of `seconds_worked` done while `bill_rate` was nonzero, in seconds.
+	pub billable_seconds: u64,
+	/// Lamports to invoice the client for `billable_seconds`.
+	pub billable_amount: u64,
+	/// Lamports of wages payroll paid for sessions on the project.
+	pub labor_cost: u64,
+	pub version: u8,
+	pub _reserved: [u8; 32],
+}
+
+impl Project {
+	pub const VERSION: u8 = 1;
+
+	pub fn validate(project_id: u64, name: &str) -> Result<()> {
+		require!(project_id != 0, EmployeePayrollError::InvalidProject);
+		require!(name.len() <= MAX_PROJECT_NAME_LEN, EmployeePayrollError::NameTooLong);
+
+		Ok(())
+	}
+
+	/// Adds `duration` seconds of checked-out work, billed at the current
+	/// `bill_rate`.
+	pub fn record_hours(&mut self, duration: u64) -> Result<()> {
+		self.seconds_worked = self
+			.seconds_worked
+			.checked_add(duration)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+		if self.bill_rate > 0 {
+			let billed = u64::try_from((duration as u128) * (self.bill_rate as u128) / 3_600)
+				.map_err(|_| EmployeePayrollError::ArithmeticOverflow)?;
+			self.billable_seconds = self
+				.billable_seconds
+				.checked_add(duration)
+				.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+			self.billable_amount = self
+				.billable_amount
+				.checked_add(billed)
+				.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+		}
+
+		Ok(())
+	}
+
+	pub fn record_labor_cost(&mut self, amount: u64) -> Result<()> {
+		self.labor_cost = self
+			.labor_cost
+			.checked_add(amount)
+			.ok_or(EmployeePayrollError::ArithmeticOverflow)?;
+
+		Ok(())
+	}
+
+	pub fn reverse_labor_cost(&mut self, amount: u64) {
+		self.labor_cost = self.labor_cost.saturating_sub(amount);
+	}
+}
@@ -2,12 +2,74 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(InitSpace)]
 pub struct WorkSession {
 	pub employee: Pubkey,
 	pub check_in_time: i64,
 	pub check_out_time: i64,
+	/// Seconds between check-in and check-out.
 	pub duration: u64,
 	pub is_paid: bool,
 	pub authority: Pubkey,
 	pub session_id: u64,
+	/// Batch the session is attached to or was paid in, default when marked
+	/// paid outside a batch.
+	pub batch: Pubkey,
+	/// Receives the rent back when the session is closed.
+	pub rent_payer: Pubkey,
+	pub version: u8,
+	/// Lamports per unit of the employee's reference currency the session was
+	/// paid at, 0 when the rate is in lamports.
+	pub exchange_rate: u64,
+	/// `Project` the session was worked on, 0 for none.
+	pub project_id: u64,
+	/// Lamports of the payout credited to the employee's `ClaimableBalance`
+	/// and not clawed back, 0 when it was sent directly.
+	pub credited: u64,
+	/// Set by a manager once the hours have been reviewed; only approved
+	/// sessions back an advance.
+	pub is_approved: bool,
+	/// What paying the session booked, default while it is unpaid.
+	pub payment: PaymentRecord,
+	/// Employee's `hourly_rate` when the session was checked out, which the
+	/// hours are paid at. 0 for sessions checked out before it was recorded,
+	/// paid at the current rate.
+	pub hourly_rate: u64,
+	pub _reserved: [u8; 7],
+}
+
+impl WorkSession {
+	pub const VERSION: u8 = 1;
+
+	/// Whether the session's credit can still be clawed back at `now`, which
+	/// keeps the session from being closed.
+	pub fn is_reversible(&self, now: i64) -> bool {
+		self.credited > 0 && now < self.payment.reversible_until
+	}
+}
+
+/// Amounts `pay_work_session` booked for a session, kept so
+/// `claw_back_credit` can reverse the payment.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Default, Debug, PartialEq)]
+pub struct PaymentRecord {
+	pub paid_at: i64,
+	/// Until when the credit may be clawed back, `paid_at` when it may not.
+	pub reversible_until: i64,
+	/// Wages, leave and pay items before deductions.
+	pub gross: u64,
+	pub withheld: u64,
+	pub reimbursed: u64,
+	pub advance_recovered: u64,
+	/// Decrease of `Employee::outstanding_adjustment`, negative when negative
+	/// pay items left more of it to recover.
+	pub adjustment_recovered: i64,
+	/// Approved leave paid with the session, in seconds.
+	pub leave_paid: u64,
+	/// Part of `gross` booked against the session's project.
+	pub labor_cost: u64,
+	/// `Department` charged `gross`, 0 for none.
+	pub department_id: u64,
+	/// Hash of the `PayItem`s and `Expense`s paid with the session, in the
+	/// order they were passed.
+	pub paid_with: [u8; 32],
 }
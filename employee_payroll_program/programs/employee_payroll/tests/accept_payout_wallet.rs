@@ -0,0 +1,154 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn accept_payout_wallet_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let name: String = String::from("Alice");
+	let position: String = String::from("Engineer");
+	let hourly_rate: u64 = 1_000;
+	
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let employee_wallet_keypair = Keypair::new();
+	let new_payout_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let new_payout_wallet = new_payout_wallet_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		new_payout_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let employer_pubkey = Pubkey::new_unique();
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::register_employee_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		system_program_pubkey,
+		&name,
+		&position,
+		hourly_rate,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let ix = employee_payroll_ix_interface::propose_payout_wallet_ix_setup(
+		&employee_wallet_keypair,
+		employee_pda,
+		employee_wallet,
+		new_payout_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	// Only the proposed wallet itself can accept
+	let ix = employee_payroll_ix_interface::accept_payout_wallet_ix_setup(
+		&employee_wallet_keypair,
+		employee_pda,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::accept_payout_wallet_ix_setup(
+		&new_payout_wallet_keypair,
+		employee_pda,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+}
@@ -0,0 +1,102 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn apply_rate_change_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let rate_approver_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let rate_approver_pubkey = rate_approver_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		rate_approver_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000);
+	employee.pending_hourly_rate = 100_000;
+	employee.pending_rate_effective_at = 1;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::apply_rate_change_ix_setup(
+		&authority_keypair,
+		employee_pda,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+}
@@ -0,0 +1,120 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		expense_state,
+	},
+    employee_payroll::{Expense, ExpenseStatus},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn approve_expense_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	let expense_id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000_000)));
+
+	let (expense_pda, expense) = expense_state(employer_pubkey, employee_pda, expense_id, 420_000, ExpenseStatus::Submitted);
+	program_test.add_account(expense_pda, program_account(&expense));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::approve_expense_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		employee_pda,
+		expense_pda,
+		employee_wallet,
+		expense_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let expense_account = banks_client.get_account(expense_pda).await.unwrap().unwrap();
+	let expense = Expense::try_deserialize(&mut expense_account.data.as_slice()).unwrap();
+	assert_eq!(expense.status, ExpenseStatus::Approved);
+	assert_eq!(expense.reviewed_by, payroll_manager_pubkey);
+
+	// Reviewed expenses cannot be reviewed again
+	let ix = employee_payroll_ix_interface::reject_expense_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		employee_pda,
+		expense_pda,
+		employee_wallet,
+		expense_id,
+		banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap(),
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+}
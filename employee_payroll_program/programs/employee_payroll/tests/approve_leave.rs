@@ -0,0 +1,130 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		leave_request_state,
+	},
+    employee_payroll::{Employee, LeaveRequest, LeaveStatus},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn approve_leave_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let request_id: u64 = 1;
+	let duration: u64 = 4 * 3_600;
+	let pto_balance: u64 = 6 * 3_600;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+	program_test.add_account(
+		payroll_manager_keypair.pubkey(),
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_keypair.pubkey();
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000);
+	employee.pto_balance = pto_balance;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (leave_request_pda, leave_request) = leave_request_state(employer_pubkey, employee_pda, request_id, duration);
+	program_test.add_account(leave_request_pda, program_account(&leave_request));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let approve_ix = |recent_blockhash| employee_payroll_ix_interface::approve_leave_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		employee_pda,
+		leave_request_pda,
+		employee_wallet,
+		request_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(approve_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let leave_request_account = banks_client.get_account(leave_request_pda).await.unwrap().unwrap();
+	let leave_request = LeaveRequest::try_deserialize(&mut leave_request_account.data.as_slice()).unwrap();
+	assert_eq!(leave_request.status, LeaveStatus::Approved);
+	assert_eq!(leave_request.reviewed_by, payroll_manager_keypair.pubkey());
+
+	// Approved leave moves from the balance to the next payroll run
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.pto_balance, pto_balance - duration);
+	assert_eq!(employee.unpaid_leave, duration);
+
+	let recent_blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap();
+	let result = banks_client.process_transaction(approve_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+}
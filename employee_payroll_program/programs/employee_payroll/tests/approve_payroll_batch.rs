@@ -0,0 +1,115 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		payroll_batch_state,
+	},
+    employee_payroll::PayrollBatch,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn approve_payroll_batch_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = 3;
+	let pay_date: i64 = 1_700_000_000;
+
+	// KEYPAIR
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(Pubkey::new_unique());
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, payroll_manager_pubkey, batch_id);
+	payroll_batch.period_start = pay_date - 14 * 86_400;
+	payroll_batch.period_end = pay_date;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::approve_payroll_batch_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		batch_id,
+		pay_date - 86_400,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::approve_payroll_batch_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		batch_id,
+		pay_date,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let payroll_batch_account = banks_client.get_account(payroll_batch_pda).await.unwrap().unwrap();
+	let payroll_batch = PayrollBatch::try_deserialize(&mut payroll_batch_account.data.as_slice()).unwrap();
+	assert_eq!(payroll_batch.pay_date, pay_date);
+}
@@ -0,0 +1,124 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		work_session_state,
+	},
+    employee_payroll::WorkSession,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn approve_work_session_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let session_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [payroll_manager_pubkey, employee_wallet] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	
+	}
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000_000)));
+	program_test.add_account(work_session_pda, program_account(&work_session_state(employee_pda, authority_pubkey, session_id, 0, 3_600)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let approve_ix = |signer: &Keypair, recent_blockhash| employee_payroll_ix_interface::approve_work_session_ix_setup(
+		signer,
+		employer_pubkey,
+		employee_pda,
+		work_session_pda,
+		employee_wallet,
+		session_id,
+		recent_blockhash,
+	);
+
+	// Employees cannot approve their own hours
+	let result = banks_client.process_transaction(approve_ix(&employee_wallet_keypair, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(approve_ix(&payroll_manager_keypair, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let work_session_account = banks_client.get_account(work_session_pda).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert!(work_session.is_approved);
+
+	let recent_blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap();
+	let result = banks_client.process_transaction(approve_ix(&payroll_manager_keypair, recent_blockhash)).await;
+	assert!(result.is_err());
+}
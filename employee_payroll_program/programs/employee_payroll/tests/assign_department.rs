@@ -0,0 +1,141 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		department_state,
+	},
+    employee_payroll::Employee,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn assign_department_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let department_id: u64 = 7;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let other_employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	program_test.add_account(
+		employee_pda,
+		program_account(&employee_state(employer_pubkey, Pubkey::new_unique(), employee_wallet, 1_000)),
+	);
+
+	let (department_pda, department) = department_state(employer_pubkey, department_id, 0);
+	program_test.add_account(department_pda, program_account(&department));
+
+	let (other_department_pda, other_department) = department_state(other_employer_pubkey, department_id, 0);
+	program_test.add_account(other_department_pda, program_account(&other_department));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::assign_department_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		employee_pda,
+		Some(other_department_pda),
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::assign_department_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		employee_pda,
+		Some(department_pda),
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.department_id, department_id);
+
+	let recent_blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap();
+	let ix = employee_payroll_ix_interface::assign_department_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		employee_pda,
+		None,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.department_id, 0);
+}
@@ -0,0 +1,122 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		deduction_rule_state,
+	},
+    employee_payroll::{DeductionKind, Employee},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn attach_deduction_rule_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	let rule_id: u64 = Default::default();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (deduction_rule_pda, deduction_rule) = deduction_rule_state(
+		employer_pubkey,
+		rule_id,
+		DeductionKind::Percentage { bps: 500 },
+		Pubkey::new_unique(),
+	);
+	program_test.add_account(deduction_rule_pda, program_account(&deduction_rule));
+
+	let employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000);
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::attach_deduction_rule_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		deduction_rule_pda,
+		employee_wallet,
+		rule_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.deduction_rules, vec![deduction_rule_pda]);
+
+	// A second attach of the same rule is rejected
+	let ix = employee_payroll_ix_interface::attach_deduction_rule_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		deduction_rule_pda,
+		employee_wallet,
+		rule_id,
+		banks_client.get_latest_blockhash().await.unwrap(),
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+}
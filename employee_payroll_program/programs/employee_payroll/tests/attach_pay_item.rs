@@ -0,0 +1,115 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		payroll_batch_state,
+		pay_item_state,
+	},
+    employee_payroll::{PayItem, PayItemKind},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn attach_pay_item_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	let item_id: u64 = 1;
+	let batch_id: u64 = 4;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000_000)));
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+
+	let (pay_item_pda, pay_item) = pay_item_state(employer_pubkey, employee_pda, item_id, PayItemKind::Commission, 75_000, Pubkey::default());
+	program_test.add_account(pay_item_pda, program_account(&pay_item));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::attach_pay_item_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		pay_item_pda,
+		payroll_batch_pda,
+		employee_wallet,
+		item_id,
+		batch_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let pay_item_account = banks_client.get_account(pay_item_pda).await.unwrap().unwrap();
+	let pay_item = PayItem::try_deserialize(&mut pay_item_account.data.as_slice()).unwrap();
+	assert_eq!(pay_item.batch, payroll_batch_pda);
+}
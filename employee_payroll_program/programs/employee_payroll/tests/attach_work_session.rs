@@ -0,0 +1,150 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		payroll_batch_state,
+		work_session_state,
+	},
+    employee_payroll::WorkSession,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn attach_work_session_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	let session_id: u64 = 0;
+	let batch_id: u64 = 1;
+	let period_start: i64 = 1_708_041_600;
+	let period_end: i64 = 1_709_251_200;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let early_session_id: u64 = 1;
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (early_work_session_pda, _early_work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			early_session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000_000)));
+
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, payroll_manager_pubkey, batch_id);
+	payroll_batch.period_start = period_start;
+	payroll_batch.period_end = period_end;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+
+	program_test.add_account(work_session_pda, program_account(&work_session_state(employee_pda, authority_pubkey, session_id, period_start, period_start + 3_600)));
+	program_test.add_account(early_work_session_pda, program_account(&work_session_state(employee_pda, authority_pubkey, early_session_id, period_start - 3_600, period_start)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let attach_ix = |work_session: Pubkey, session_id: u64| employee_payroll_ix_interface::attach_work_session_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		employee_pda,
+		work_session,
+		payroll_batch_pda,
+		employee_wallet,
+		session_id,
+		batch_id,
+		recent_blockhash,
+	);
+
+	// Sessions worked before the period belong to an earlier batch
+	let result = banks_client.process_transaction(attach_ix(early_work_session_pda, early_session_id)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(attach_ix(work_session_pda, session_id)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let work_session_account = banks_client.get_account(work_session_pda).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert_eq!(work_session.batch, payroll_batch_pda);
+	assert!(work_session.is_approved);
+}
@@ -0,0 +1,174 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		payroll_batch_state,
+		pay_item_state,
+		work_session_state,
+	},
+    employee_payroll::{PayItem, PayItemKind, PayrollBatch, WorkSession},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn cancel_batch_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = 3;
+	let item_id: u64 = 1;
+	let paid_item_id: u64 = 2;
+
+	// KEYPAIR
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (paid_session_pda, _paid_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			0u64.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			1u64.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(Pubkey::new_unique());
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	// The batch has already paid one session
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, payroll_manager_pubkey, batch_id);
+	payroll_batch.total_amount = 1_000;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+
+	let mut paid_session = work_session_state(employee_pubkey, employee_wallet, 0, 1_700_000_000, 1_700_003_600);
+	paid_session.is_paid = true;
+	paid_session.batch = payroll_batch_pda;
+	program_test.add_account(paid_session_pda, program_account(&paid_session));
+
+	let mut work_session = work_session_state(employee_pubkey, employee_wallet, 1, 1_700_086_400, 1_700_090_000);
+	work_session.batch = payroll_batch_pda;
+	work_session.is_approved = true;
+	program_test.add_account(work_session_pda, program_account(&work_session));
+
+	let (pay_item_pda, pay_item) = pay_item_state(employer_pubkey, employee_pubkey, item_id, PayItemKind::Bonus, 50_000, payroll_batch_pda);
+	program_test.add_account(pay_item_pda, program_account(&pay_item));
+
+	let (paid_item_pda, mut paid_item) = pay_item_state(employer_pubkey, employee_pubkey, paid_item_id, PayItemKind::Bonus, 1_000, payroll_batch_pda);
+	paid_item.is_paid = true;
+	program_test.add_account(paid_item_pda, program_account(&paid_item));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	// What the batch paid stays paid, to be clawed back instead
+	for released in [paid_session_pda, paid_item_pda] {
+		let ix = employee_payroll_ix_interface::cancel_batch_ix_setup(
+			&payroll_manager_keypair,
+			employer_pubkey,
+			payroll_batch_pda,
+			&[pay_item_pda, released],
+			batch_id,
+			recent_blockhash,
+		);
+
+		let result = banks_client.process_transaction(ix).await;
+		assert!(result.is_err());
+	}
+
+	let ix = employee_payroll_ix_interface::cancel_batch_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		&[pay_item_pda, work_session_pda],
+		batch_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payroll_batch_account = banks_client.get_account(payroll_batch_pda).await.unwrap().unwrap();
+	let payroll_batch = PayrollBatch::try_deserialize(&mut payroll_batch_account.data.as_slice()).unwrap();
+	assert!(payroll_batch.is_cancelled);
+	assert_eq!(payroll_batch.total_amount, 1_000);
+
+	let pay_item_account = banks_client.get_account(pay_item_pda).await.unwrap().unwrap();
+	let pay_item = PayItem::try_deserialize(&mut pay_item_account.data.as_slice()).unwrap();
+	assert_eq!(pay_item.batch, Pubkey::default());
+	assert!(!pay_item.is_paid);
+
+	let work_session_account = banks_client.get_account(work_session_pda).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert_eq!(work_session.batch, Pubkey::default());
+	assert!(!work_session.is_paid);
+
+	let paid_session_account = banks_client.get_account(paid_session_pda).await.unwrap().unwrap();
+	let paid_session = WorkSession::try_deserialize(&mut paid_session_account.data.as_slice()).unwrap();
+	assert_eq!(paid_session.batch, payroll_batch_pda);
+	assert!(paid_session.is_paid);
+}
@@ -5,7 +5,15 @@ use {
     common::{
 		get_program_test,
 		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		employee_state,
+		project_state,
+		set_clock,
 	},
+    employee_payroll::{Compensation, Employee, WorkSession},
+    anchor_lang::AccountDeserialize,
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -33,7 +41,6 @@ async fn check_in_ix_success() {
 	);
 
 	// DATA
-	let employee_wallet: Pubkey = Pubkey::default();
 	let session_id: u64 = Default::default();
 	let timestamp: i64 = Default::default();
 
@@ -42,6 +49,8 @@ async fn check_in_ix_success() {
 
 	// PUBKEY
 	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = authority_pubkey;
+	let employer_authority_pubkey = Pubkey::new_unique();
 
 	// EXECUTABLE PUBKEY
 	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
@@ -68,7 +77,7 @@ async fn check_in_ix_success() {
 	program_test.add_account(
 		authority_pubkey,
 		Account {
-			lamports: 0,
+			lamports: 1_000_000_000,
 			data: vec![],
 			owner: system_program::ID,
 			executable: false,
@@ -76,13 +85,25 @@ async fn check_in_ix_success() {
 		},
 	);
 
+	// PROGRAM STATE
+	let employer_pubkey = Pubkey::new_unique();
+	program_test.add_account(employer_pubkey, program_account(&employer_state(employer_authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, employer_authority_pubkey, employee_wallet, 1_000)));
+
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
 	let ix = employee_payroll_ix_interface::check_in_ix_setup(
 		&authority_keypair,
+		employer_pubkey,
+		program_config_pda,
 		employee_pda,
 		work_session_pda,
+		None,
 		system_program_pubkey,
 		employee_wallet,
 		session_id,
@@ -96,3 +117,214 @@ async fn check_in_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn check_in_ix_project() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let session_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let now: i64 = 1_700_000_000;
+	let project_id: u64 = 3;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = authority_pubkey;
+	let employer_authority_pubkey = Pubkey::new_unique();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(employer_authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, employer_authority_pubkey, employee_wallet, 1_000)));
+
+	let (project_pda, project) = project_state(employer_pubkey, project_id, 0);
+	program_test.add_account(project_pda, program_account(&project));
+
+	let (closed_project_pda, mut closed_project) = project_state(employer_pubkey, project_id + 1, 0);
+	closed_project.is_active = false;
+	program_test.add_account(closed_project_pda, program_account(&closed_project));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, now).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let check_in_ix = |project: Pubkey| employee_payroll_ix_interface::check_in_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		work_session_pda,
+		Some(project),
+		system_program_pubkey,
+		employee_wallet,
+		session_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// Inactive projects take no new sessions
+	let result = context.banks_client.process_transaction(check_in_ix(closed_project_pda)).await;
+	assert!(result.is_err());
+
+	let result = context.banks_client.process_transaction(check_in_ix(project_pda)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let work_session_account = context.banks_client.get_account(work_session_pda).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert_eq!(work_session.project_id, project_id);
+
+	// The check-in time comes from the clock, not the employee
+	assert_eq!(work_session.check_in_time, now);
+
+	// Owed until paid
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.unpaid_sessions, 1);
+}
+
+#[tokio::test]
+async fn check_in_ix_salaried() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let session_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = authority_pubkey;
+	let employer_authority_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let employer_pubkey = Pubkey::new_unique();
+	program_test.add_account(employer_pubkey, program_account(&employer_state(employer_authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	// A session would be left for no payroll to pay, keeping the employee from being closed
+	let mut employee = employee_state(employer_pubkey, employer_authority_pubkey, employee_wallet, 0);
+	employee.compensation = Compensation::Salaried { annual_salary: 52_000_000 };
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::check_in_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		work_session_pda,
+		None,
+		system_program_pubkey,
+		employee_wallet,
+		session_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_err());
+	assert!(banks_client.get_account(work_session_pda).await.unwrap().is_none());
+}
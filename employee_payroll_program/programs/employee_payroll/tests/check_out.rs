@@ -5,7 +5,15 @@ use {
     common::{
 		get_program_test,
 		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		work_session_state,
+		project_state,
+		set_clock,
 	},
+    employee_payroll::{Employee, Project, WorkSession},
+    anchor_lang::AccountDeserialize,
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -42,6 +50,7 @@ async fn check_out_ix_success() {
 
 	// PUBKEY
 	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
 
 	// PDA
 	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
@@ -78,8 +87,10 @@ async fn check_out_ix_success() {
 
 	let ix = employee_payroll_ix_interface::check_out_ix_setup(
 		&authority_keypair,
+		employer_pubkey,
 		employee_pda,
 		work_session_pda,
+		None,
 		employee_wallet,
 		session_id,
 		timestamp,
@@ -92,3 +103,220 @@ async fn check_out_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn check_out_ix_accrues_leave() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let session_id: u64 = 1;
+	let check_in_time: i64 = 1_700_000_000;
+	let duration: i64 = 8 * 3_600;
+	let pto_accrual_bps: u16 = 500;
+
+	// KEYPAIR
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let authority_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000);
+	employee.pto_accrual_bps = pto_accrual_bps;
+	employee.pto_balance = 100;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	program_test.add_account(
+		work_session_pda,
+		program_account(&work_session_state(employee_pda, employee_wallet, session_id, check_in_time, 0)),
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, check_in_time + duration).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = employee_payroll_ix_interface::check_out_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		employee_pda,
+		work_session_pda,
+		None,
+		employee_wallet,
+		session_id,
+		check_in_time + 100 * duration,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// 5% of eight hours worked, timed by the clock rather than the employee's timestamp
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.pto_balance, 100 + 1_440);
+
+	// Paid at the rate it was checked out at
+	let work_session_account = context.banks_client.get_account(work_session_pda).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert_eq!(work_session.hourly_rate, 1_000);
+}
+
+#[tokio::test]
+async fn check_out_ix_project_hours() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let session_id: u64 = 1;
+	let check_in_time: i64 = 1_700_000_000;
+	let duration: i64 = 2 * 3_600;
+	let project_id: u64 = 3;
+	let bill_rate: u64 = 90_000;
+
+	// KEYPAIR
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let authority_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000)));
+
+	let mut work_session = work_session_state(employee_pda, employee_wallet, session_id, check_in_time, 0);
+	work_session.project_id = project_id;
+	program_test.add_account(work_session_pda, program_account(&work_session));
+
+	let (project_pda, project) = project_state(employer_pubkey, project_id, bill_rate);
+	program_test.add_account(project_pda, program_account(&project));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, check_in_time + duration).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let check_out_ix = |project: Option<Pubkey>| employee_payroll_ix_interface::check_out_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		employee_pda,
+		work_session_pda,
+		project,
+		employee_wallet,
+		session_id,
+		check_in_time + duration,
+		recent_blockhash,
+	);
+
+	// The session's project has to be passed
+	let result = context.banks_client.process_transaction(check_out_ix(None)).await;
+	assert!(result.is_err());
+
+	let result = context.banks_client.process_transaction(check_out_ix(Some(project_pda))).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let project_account = context.banks_client.get_account(project_pda).await.unwrap().unwrap();
+	let project = Project::try_deserialize(&mut project_account.data.as_slice()).unwrap();
+	assert_eq!(project.seconds_worked, duration as u64);
+	assert_eq!(project.billable_seconds, duration as u64);
+	assert_eq!(project.billable_amount, 2 * bill_rate);
+}
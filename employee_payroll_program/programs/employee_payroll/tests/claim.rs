@@ -0,0 +1,493 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		set_clock,
+		employer_state,
+		program_config_state,
+		employee_state,
+		claimable_balance_state,
+		payroll_batch_state,
+		work_session_state,
+	},
+    employee_payroll::{ClaimableBalance, CreditLock, PayoutMode, PayoutSplit},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn claim_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let amount: u64 = 1_000_003;
+
+	// KEYPAIR
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let payout_wallet = Pubkey::new_unique();
+	let savings_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	for wallet in [payout_wallet, savings_wallet] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 1_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(Pubkey::new_unique())));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut employee = employee_state(employer_pubkey, Pubkey::new_unique(), employee_wallet, 1_000);
+	employee.payout_wallet = payout_wallet;
+	employee.payout_splits = vec![PayoutSplit { wallet: savings_wallet, bps: 2_000 }];
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (claimable_balance_pda, claimable_balance) = claimable_balance_state(employer_pubkey, employee_pda, amount);
+	let mut claimable_balance_account = program_account(&claimable_balance);
+	let base_lamports = claimable_balance_account.lamports;
+	claimable_balance_account.lamports += amount;
+	program_test.add_account(claimable_balance_pda, claimable_balance_account);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let claim_ix = |payout_splits: &[Pubkey], recent_blockhash| employee_payroll_ix_interface::claim_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		claimable_balance_pda,
+		payout_wallet,
+		payout_splits,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	// Split wallets still have to match the employee's configuration
+	let result = banks_client.process_transaction(claim_ix(&[], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(claim_ix(&[savings_wallet], recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	assert_eq!(banks_client.get_balance(savings_wallet).await.unwrap(), 1_000_000_000 + 200_000);
+	assert_eq!(banks_client.get_balance(payout_wallet).await.unwrap(), 1_000_000_000 + 800_003);
+
+	// Only the credited lamports leave the balance
+	let claimable_balance_account = banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	assert_eq!(claimable_balance_account.lamports, base_lamports);
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.amount, 0);
+	assert_eq!(claimable_balance.total_claimed, amount);
+
+	let recent_blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap();
+	let result = banks_client.process_transaction(claim_ix(&[savings_wallet], recent_blockhash)).await;
+	assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn claim_ix_employer_paused() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let amount: u64 = 1_000_000;
+
+	// KEYPAIR
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let payout_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [employee_wallet, payout_wallet] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 1_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// PROGRAM STATE
+	let mut employer = employer_state(Pubkey::new_unique());
+	employer.paused = true;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut employee = employee_state(employer_pubkey, Pubkey::new_unique(), employee_wallet, 1_000);
+	employee.payout_wallet = payout_wallet;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (claimable_balance_pda, claimable_balance) = claimable_balance_state(employer_pubkey, employee_pda, amount);
+	let mut claimable_balance_account = program_account(&claimable_balance);
+	claimable_balance_account.lamports += amount;
+	program_test.add_account(claimable_balance_pda, claimable_balance_account);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let claim_ix = employee_payroll_ix_interface::claim_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		claimable_balance_pda,
+		payout_wallet,
+		&[],
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(claim_ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_err());
+
+	assert_eq!(banks_client.get_balance(payout_wallet).await.unwrap(), 1_000_000_000);
+	let claimable_balance_account = banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.amount, amount);
+}
+
+#[tokio::test]
+async fn claim_ix_locked_credit() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let amount: u64 = 1_000_000;
+	let locked: u64 = 400_000;
+	let now: i64 = 1_700_000_000;
+
+	// KEYPAIR
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let payout_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [employee_wallet, payout_wallet] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 1_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(Pubkey::new_unique())));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut employee = employee_state(employer_pubkey, Pubkey::new_unique(), employee_wallet, 1_000);
+	employee.payout_wallet = payout_wallet;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// Part of the balance was credited inside the reversal window
+	let (claimable_balance_pda, mut claimable_balance) = claimable_balance_state(employer_pubkey, employee_pda, amount);
+	claimable_balance.locks[0] = CreditLock { amount: locked, until: now + 3_600 };
+	let mut claimable_balance_account = program_account(&claimable_balance);
+	claimable_balance_account.lamports += amount;
+	program_test.add_account(claimable_balance_pda, claimable_balance_account);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	let claim_ix = |recent_blockhash| employee_payroll_ix_interface::claim_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		claimable_balance_pda,
+		payout_wallet,
+		&[],
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	set_clock(&mut context, now).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = context.banks_client.process_transaction(claim_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	assert_eq!(context.banks_client.get_balance(payout_wallet).await.unwrap(), 1_000_000_000 + amount - locked);
+	let claimable_balance_account = context.banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.amount, locked);
+
+	// Nothing more until the window closes
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(claim_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+
+	set_clock(&mut context, now + 3_600).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = context.banks_client.process_transaction(claim_ix(recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	assert_eq!(context.banks_client.get_balance(payout_wallet).await.unwrap(), 1_000_000_000 + amount);
+}
+
+#[tokio::test]
+async fn claim_ix_credits_released_separately() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let reversal_window: i64 = 86_400;
+	let now: i64 = 1_700_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let work_session_pubkeys = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for (pubkey, lamports) in [
+		(authority_pubkey, 1_000_000_000_000),
+		(employee_wallet, 1_000_000_000),
+		(vault_pda, 10_000_000_000),
+	] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payout_mode = PayoutMode::Claim;
+	employer.reversal_window = reversal_window;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	for (session_id, work_session_pubkey) in work_session_pubkeys.iter().enumerate() {
+		let check_in_time = now - 7_200 + 3_600 * session_id as i64;
+		let work_session = work_session_state(employee_pda, employee_wallet, session_id as u64, check_in_time, check_in_time + 3_600);
+		program_test.add_account(*work_session_pubkey, program_account(&work_session));
+	}
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.unpaid_sessions = 2;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (claimable_balance_pda, claimable_balance) = claimable_balance_state(employer_pubkey, employee_pda, 0);
+	program_test.add_account(claimable_balance_pda, program_account(&claimable_balance));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	let pay_ix = |work_session, recent_blockhash| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session,
+		vault_pda,
+		employee_wallet,
+		Some(claimable_balance_pda),
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let claim_ix = |recent_blockhash| employee_payroll_ix_interface::claim_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		claimable_balance_pda,
+		employee_wallet,
+		&[],
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	// Credited half a reversal window apart
+	for (work_session_pubkey, paid_at) in work_session_pubkeys.into_iter().zip([now, now + reversal_window / 2]) {
+		set_clock(&mut context, paid_at).await;
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+		let result = context.banks_client.process_transaction(pay_ix(work_session_pubkey, recent_blockhash)).await;
+		assert!(result.is_ok());
+	}
+
+	let claimable_balance_account = context.banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.locks[0], CreditLock { amount: hourly_rate, until: now + reversal_window });
+	assert_eq!(claimable_balance.locks[1], CreditLock { amount: hourly_rate, until: now + reversal_window * 3 / 2 });
+
+	// The first credit is released when its own window closes
+	set_clock(&mut context, now + reversal_window).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = context.banks_client.process_transaction(claim_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let claimable_balance_account = context.banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.amount, hourly_rate);
+	assert_eq!(claimable_balance.total_claimed, hourly_rate);
+}
@@ -0,0 +1,493 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		program_config_state,
+		deduction_rule_state,
+		set_clock,
+		employer_state,
+		employee_state,
+		payroll_batch_state,
+		work_session_state,
+		claimable_balance_state,
+		pay_item_state,
+		expense_state,
+		department_state,
+		project_state,
+	},
+    employee_payroll::{calendar::fiscal_year_start, ClaimableBalance, CreditLock, DeductionKind, Department, EarningsTotals, Employee, Employer, Expense, ExpenseStatus, PayItem, PayItemKind, PaymentRecord, PayoutMode, PayrollBatch, Project, WorkSession},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, hash::hashv, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn claw_back_credit_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let session_id: u64 = 1;
+	let batch_id: u64 = 3;
+	let item_id: u64 = 1;
+	let expense_id: u64 = 1;
+	let department_id: u64 = 1;
+	let project_id: u64 = 7;
+	let paid_at: i64 = 1_700_000_000;
+	let reversal_window: i64 = 86_400;
+	let payment = PaymentRecord {
+		paid_at,
+		reversible_until: paid_at + reversal_window,
+		gross: 50_000,
+		withheld: 5_000,
+		reimbursed: 2_000,
+		advance_recovered: 3_000,
+		adjustment_recovered: 0,
+		leave_paid: 600,
+		labor_cost: 30_000,
+		department_id,
+		paid_with: [0; 32],
+	};
+	let credited: u64 = 44_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	// Everything the payment booked, the withholding and the credit included
+	let hour_slot = paid_at.div_euclid(3_600).rem_euclid(24) as usize;
+	let mut employer = employer_state(authority_pubkey);
+	employer.reversal_window = reversal_window;
+	employer.payout_mode = PayoutMode::Claim;
+	employer.last_outflow_hour = paid_at.div_euclid(3_600);
+	employer.hourly_outflow[hour_slot] = 49_000;
+	employer.period_spent = 60_000;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, authority_pubkey, batch_id);
+	payroll_batch.total_amount = 52_000;
+	payroll_batch.processed_at = paid_at;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+
+	let year_start = fiscal_year_start(paid_at, employer.fiscal_year_start_month);
+	let earnings = EarningsTotals {
+		gross: 50_000,
+		net: 45_000,
+		deductions: 5_000,
+		..Default::default()
+	};
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000);
+	employee.department_id = department_id;
+	employee.total_paid = 100_000;
+	employee.total_reimbursed = 2_000;
+	employee.ytd = earnings.clone();
+	employee.ytd_start = year_start;
+	employee.period_to_date = earnings;
+	employee.period_start = year_start;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (pay_item_pda, mut pay_item) = pay_item_state(employer_pubkey, employee_pda, item_id, PayItemKind::Bonus, 10_000, payroll_batch_pda);
+	pay_item.is_paid = true;
+	program_test.add_account(pay_item_pda, program_account(&pay_item));
+
+	let (expense_pda, mut expense) = expense_state(employer_pubkey, employee_pda, expense_id, 2_000, ExpenseStatus::Paid);
+	expense.batch = payroll_batch_pda;
+	program_test.add_account(expense_pda, program_account(&expense));
+
+	let (department_pda, mut department) = department_state(employer_pubkey, department_id, 0);
+	department.period_start = year_start;
+	department.period_spent = 50_000;
+	department.total_spent = 80_000;
+	program_test.add_account(department_pda, program_account(&department));
+
+	let (project_pda, mut project) = project_state(employer_pubkey, project_id, 0);
+	project.labor_cost = 30_000;
+	program_test.add_account(project_pda, program_account(&project));
+
+	let mut work_session = work_session_state(employee_pda, employee_wallet, session_id, paid_at - 3_600, paid_at - 60);
+	work_session.is_paid = true;
+	work_session.batch = payroll_batch_pda;
+	work_session.project_id = project_id;
+	work_session.credited = credited;
+	work_session.payment = PaymentRecord {
+		paid_with: hashv(&[pay_item_pda.as_ref(), expense_pda.as_ref()]).to_bytes(),
+		..payment
+	};
+	program_test.add_account(work_session_pda, program_account(&work_session));
+
+	let (claimable_balance_pda, mut claimable_balance) = claimable_balance_state(employer_pubkey, employee_pda, credited);
+	claimable_balance.locks[0] = CreditLock { amount: credited, until: paid_at + reversal_window };
+	program_test.add_account(claimable_balance_pda, program_account(&claimable_balance));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	let claw_back_ix = |pay_items: &[Pubkey], recent_blockhash| employee_payroll_ix_interface::claw_back_credit_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pda,
+		claimable_balance_pda,
+		vault_pda,
+		Some(department_pda),
+		Some(project_pda),
+		pay_items,
+		&[expense_pda],
+		employee_wallet,
+		session_id,
+		batch_id,
+		"Duplicate timesheet".to_string(),
+		recent_blockhash,
+	);
+
+	// Too late once the reversal window has passed
+	set_clock(&mut context, paid_at + reversal_window).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = context.banks_client.process_transaction(claw_back_ix(&[pay_item_pda], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	set_clock(&mut context, paid_at + reversal_window / 2).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	// The pay items paid with the session have to be passed to reverse it
+	let result = context.banks_client.process_transaction(claw_back_ix(&[], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = context.banks_client.process_transaction(claw_back_ix(&[pay_item_pda], recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	assert_eq!(context.banks_client.get_balance(vault_pda).await.unwrap(), 1_000_000_000 + credited);
+
+	let claimable_balance_account = context.banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	assert_eq!(claimable_balance_account.lamports, 1_000_000_000 - credited);
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.amount, 0);
+	assert_eq!(claimable_balance.locks[0].amount, 0);
+
+	// The session can be paid again
+	let work_session_account = context.banks_client.get_account(work_session_pda).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert_eq!(work_session.credited, 0);
+	assert!(!work_session.is_paid);
+	assert_eq!(work_session.batch, Pubkey::default());
+	assert_eq!(work_session.payment, PaymentRecord::default());
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, 50_000);
+	assert_eq!(employee.total_reimbursed, 0);
+	assert_eq!(employee.outstanding_advance, 3_000);
+	assert_eq!(employee.unpaid_leave, 600);
+	assert_eq!(employee.ytd, EarningsTotals::default());
+	assert_eq!(employee.period_to_date, EarningsTotals::default());
+	// The session is owed again
+	assert_eq!(employee.unpaid_sessions, 1);
+	// without sending its withholding to the remittance vaults twice
+	assert_eq!(employee.withholding_credit, 5_000);
+
+	// Only the credit came back; withholding stays with the remittance vaults
+	let employer_account = context.banks_client.get_account(employer_pubkey).await.unwrap().unwrap();
+	let employer = Employer::try_deserialize(&mut employer_account.data.as_slice()).unwrap();
+	assert_eq!(employer.hourly_outflow[hour_slot], 49_000 - credited);
+	assert_eq!(employer.period_spent, 60_000 - credited);
+
+	let department_account = context.banks_client.get_account(department_pda).await.unwrap().unwrap();
+	let department = Department::try_deserialize(&mut department_account.data.as_slice()).unwrap();
+	assert_eq!(department.period_spent, 0);
+	assert_eq!(department.total_spent, 30_000);
+
+	let project_account = context.banks_client.get_account(project_pda).await.unwrap().unwrap();
+	let project = Project::try_deserialize(&mut project_account.data.as_slice()).unwrap();
+	assert_eq!(project.labor_cost, 0);
+
+	let payroll_batch_account = context.banks_client.get_account(payroll_batch_pda).await.unwrap().unwrap();
+	let payroll_batch = PayrollBatch::try_deserialize(&mut payroll_batch_account.data.as_slice()).unwrap();
+	assert_eq!(payroll_batch.total_amount, 0);
+
+	let pay_item_account = context.banks_client.get_account(pay_item_pda).await.unwrap().unwrap();
+	let pay_item = PayItem::try_deserialize(&mut pay_item_account.data.as_slice()).unwrap();
+	assert!(!pay_item.is_paid);
+	assert_eq!(pay_item.batch, payroll_batch_pda);
+
+	let expense_account = context.banks_client.get_account(expense_pda).await.unwrap().unwrap();
+	let expense = Expense::try_deserialize(&mut expense_account.data.as_slice()).unwrap();
+	assert_eq!(expense.status, ExpenseStatus::Approved);
+	assert_eq!(expense.batch, Pubkey::default());
+}
+
+#[tokio::test]
+async fn claw_back_credit_ix_pay_again() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let session_id: u64 = 1;
+	let batch_id: u64 = 3;
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let withheld: u64 = 100_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let remittance_vault = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for (pubkey, lamports) in [
+		(authority_pubkey, 1_000_000_000_000),
+		(employee_wallet, 1_000_000_000),
+		(remittance_vault, 1_000_000_000),
+		(vault_pda, 10_000_000_000),
+	] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payout_mode = PayoutMode::Claim;
+	employer.reversal_window = 86_400;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pda, program_account(&work_session_state(employee_pda, employee_wallet, session_id, 0, 3_600)));
+
+	let (deduction_rule_pda, deduction_rule) = deduction_rule_state(employer_pubkey, 0, DeductionKind::Percentage { bps: 1_000 }, remittance_vault);
+	program_test.add_account(deduction_rule_pda, program_account(&deduction_rule));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.deduction_rules = vec![deduction_rule_pda];
+	employee.unpaid_sessions = 1;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (claimable_balance_pda, claimable_balance) = claimable_balance_state(employer_pubkey, employee_pda, 0);
+	program_test.add_account(claimable_balance_pda, program_account(&claimable_balance));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	let pay_ix = |recent_blockhash| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pda,
+		vault_pda,
+		employee_wallet,
+		Some(claimable_balance_pda),
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[(deduction_rule_pda, remittance_vault)],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(pay_ix(recent_blockhash)).await;
+	assert!(result.is_ok());
+	assert_eq!(context.banks_client.get_balance(remittance_vault).await.unwrap(), 1_000_000_000 + withheld);
+
+	let ix = employee_payroll_ix_interface::claw_back_credit_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pda,
+		claimable_balance_pda,
+		vault_pda,
+		None,
+		None,
+		&[],
+		&[],
+		employee_wallet,
+		session_id,
+		batch_id,
+		"Paid to the wrong period".to_string(),
+		recent_blockhash,
+	);
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(pay_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// The withholding was remitted once, by the first payment
+	assert_eq!(context.banks_client.get_balance(remittance_vault).await.unwrap(), 1_000_000_000 + withheld);
+
+	let claimable_balance_account = context.banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.amount, hourly_rate - withheld);
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.withholding_credit, 0);
+	assert_eq!(employee.total_paid, hourly_rate);
+	assert_eq!(employee.ytd.deductions, withheld);
+	assert_eq!(employee.unpaid_sessions, 0);
+}
@@ -0,0 +1,159 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		pay_item_state,
+		claimable_balance_state,
+	},
+    employee_payroll::PayItemKind,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn close_employee_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let item_id: u64 = 1;
+	let paid_item_id: u64 = 2;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let owed_employee_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (owed_employee_pda, _owed_employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			owed_employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000);
+	employee.is_active = false;
+	employee.is_terminated = true;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (final_settlement_pda, final_settlement) = pay_item_state(employer_pubkey, employee_pda, item_id, PayItemKind::FinalSettlement, 5_000, Pubkey::new_unique());
+	program_test.add_account(final_settlement_pda, program_account(&final_settlement));
+
+	let (paid_settlement_pda, mut paid_settlement) = pay_item_state(employer_pubkey, employee_pda, paid_item_id, PayItemKind::FinalSettlement, 5_000, Pubkey::new_unique());
+	paid_settlement.is_paid = true;
+	program_test.add_account(paid_settlement_pda, program_account(&paid_settlement));
+
+	let (claimable_balance_pda, claimable_balance) = claimable_balance_state(employer_pubkey, employee_pda, 0);
+	program_test.add_account(claimable_balance_pda, program_account(&claimable_balance));
+
+	// Settled, but with a session left to pay
+	let mut owed_employee = employee_state(employer_pubkey, authority_pubkey, owed_employee_wallet, 1_000);
+	owed_employee.is_active = false;
+	owed_employee.is_terminated = true;
+	owed_employee.unpaid_sessions = 1;
+	program_test.add_account(owed_employee_pda, program_account(&owed_employee));
+
+	let (owed_settlement_pda, mut owed_settlement) = pay_item_state(employer_pubkey, owed_employee_pda, paid_item_id, PayItemKind::FinalSettlement, 5_000, Pubkey::new_unique());
+	owed_settlement.is_paid = true;
+	program_test.add_account(owed_settlement_pda, program_account(&owed_settlement));
+
+	let (owed_claimable_balance_pda, _owed_claimable_balance_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"claimable_balance",
+			owed_employee_pda.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let close_ix = |final_settlement: Pubkey, item_id: u64| employee_payroll_ix_interface::close_employee_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		final_settlement,
+		claimable_balance_pda,
+		employee_wallet,
+		item_id,
+		recent_blockhash,
+	);
+
+	// Not before the final settlement has been paid
+	let result = banks_client.process_transaction(close_ix(final_settlement_pda, item_id)).await;
+	assert!(result.is_err());
+
+	// Nor while a work session is still to be paid
+	let ix = employee_payroll_ix_interface::close_employee_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		owed_employee_pda,
+		owed_settlement_pda,
+		owed_claimable_balance_pda,
+		owed_employee_wallet,
+		paid_item_id,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(close_ix(paid_settlement_pda, paid_item_id)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(banks_client.get_account(employee_pda).await.unwrap().is_none());
+	assert!(banks_client.get_account(paid_settlement_pda).await.unwrap().is_none());
+	assert!(banks_client.get_account(claimable_balance_pda).await.unwrap().is_none());
+}
@@ -0,0 +1,147 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		payroll_batch_state,
+		work_session_state,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn close_work_session_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::default();
+	let session_id: u64 = Default::default();
+	let batch_id: u64 = Default::default();
+	let emit_archive: bool = true;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let rent_payer_pubkey = Pubkey::new_unique();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		rent_payer_pubkey,
+		Account {
+			lamports: 1_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000)));
+
+	// Sessions a cancelled batch paid before it was called off close the same
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, authority_pubkey, batch_id);
+	payroll_batch.is_cancelled = true;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+
+	// once their credit can no longer be clawed back
+	let mut work_session = work_session_state(employee_pda, authority_pubkey, session_id, 0, 3_600);
+	work_session.is_paid = true;
+	work_session.batch = payroll_batch_pda;
+	work_session.rent_payer = rent_payer_pubkey;
+	work_session.credited = 1_000;
+	work_session.payment.paid_at = 3_600;
+	work_session.payment.reversible_until = 3_600 + 86_400;
+	let work_session_account = program_account(&work_session);
+	let session_lamports = work_session_account.lamports;
+	program_test.add_account(work_session_pda, work_session_account);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::close_work_session_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		work_session_pda,
+		Some(payroll_batch_pda),
+		rent_payer_pubkey,
+		employee_wallet,
+		session_id,
+		emit_archive,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(banks_client.get_account(work_session_pda).await.unwrap().is_none());
+	assert_eq!(banks_client.get_balance(rent_payer_pubkey).await.unwrap(), 1_000_000 + session_lamports);
+}
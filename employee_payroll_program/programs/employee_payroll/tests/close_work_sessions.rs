@@ -0,0 +1,148 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		payroll_batch_state,
+		work_session_state,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn close_work_sessions_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let emit_archive: bool = false;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, authority_pubkey, batch_id);
+	payroll_batch.is_processed = true;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+
+	let mut sessions = Vec::new();
+	for session_id in 0..3u64 {
+		let work_session_pubkey = Pubkey::new_unique();
+		let rent_payer_pubkey = Pubkey::new_unique();
+
+		let mut work_session = work_session_state(Pubkey::new_unique(), rent_payer_pubkey, session_id, 0, 3_600);
+		work_session.is_paid = true;
+		work_session.batch = payroll_batch_pda;
+		program_test.add_account(work_session_pubkey, program_account(&work_session));
+		program_test.add_account(
+			rent_payer_pubkey,
+			Account {
+				lamports: 1_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+
+		sessions.push((work_session_pubkey, rent_payer_pubkey));
+	}
+
+	// A credit that can still be clawed back keeps its session open
+	let reversible_session_pubkey = Pubkey::new_unique();
+	let mut reversible_session = work_session_state(Pubkey::new_unique(), authority_pubkey, 3, 0, 3_600);
+	reversible_session.is_paid = true;
+	reversible_session.batch = payroll_batch_pda;
+	reversible_session.credited = 1_000;
+	reversible_session.payment.reversible_until = i64::MAX;
+	program_test.add_account(reversible_session_pubkey, program_account(&reversible_session));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let mut with_reversible = sessions.clone();
+	with_reversible.push((reversible_session_pubkey, authority_pubkey));
+	let ix = employee_payroll_ix_interface::close_work_sessions_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		&with_reversible,
+		batch_id,
+		emit_archive,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::close_work_sessions_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		&sessions,
+		batch_id,
+		emit_archive,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	for (work_session_pubkey, rent_payer_pubkey) in sessions {
+		assert!(banks_client.get_account(work_session_pubkey).await.unwrap().is_none());
+		assert!(banks_client.get_balance(rent_payer_pubkey).await.unwrap() > 1_000_000);
+	}
+	assert!(banks_client.get_account(reversible_session_pubkey).await.unwrap().is_some());
+}
@@ -2,12 +2,46 @@ use {
 	employee_payroll::{
 			entry,
 			ID as PROGRAM_ID,
+			Compensation,
+			EarningsTotals,
+			Employee,
+			Employer,
+			PayrollBatch,
+			ProgramConfig,
+			WorkSession,
+			PaymentRecord,
+			DeductionRule,
+			DeductionKind,
+			PayItem,
+			PayItemKind,
+			Expense,
+			ExpenseCategory,
+			ExpenseStatus,
+			PayStream,
+			PayoutMode,
+			ClaimableBalance,
+			CreditLock,
+			LeaveRequest,
+			Department,
+			Project,
+			LeaveStatus,
+			DEFAULT_MAX_PRICE_AGE,
+			DEFAULT_MAX_PRICE_CONF_BPS,
+			SPL_ACCOUNT_COMPRESSION_ID,
+			MAX_CREDIT_LOCKS,
 	},
 	solana_sdk::{
+		account::Account,
+		clock::Clock,
 		entrypoint::{ProcessInstruction, ProgramResult},
+		keccak,
 		pubkey::Pubkey,
 	},
-	anchor_lang::prelude::AccountInfo,
+	anchor_lang::{
+		prelude::AccountInfo,
+		AccountSerialize,
+	},
+	price_oracle::PriceFeedMessage,
 	solana_program_test::*,
 };
 
@@ -35,7 +69,360 @@ pub fn get_program_test() -> ProgramTest {
 	);
 	program_test
 }
+
+// Move the cluster clock to `unix_timestamp`, forwards or backwards.
+pub async fn set_clock(context: &mut ProgramTestContext, unix_timestamp: i64) {
+	let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+	clock.unix_timestamp = unix_timestamp;
+	context.set_sysvar(&clock);
+}
+
+// Serialize program state so tests can preload it with `program_test.add_account`.
+pub fn program_account<T: AccountSerialize>(state: &T) -> Account {
+	let mut data = vec![];
+	state.try_serialize(&mut data).unwrap();
+
+	Account {
+		lamports: 1_000_000_000,
+		data,
+		owner: PROGRAM_ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+pub fn employer_state(authority: Pubkey) -> Employer {
+	Employer {
+		authority,
+		paused: false,
+		paused_by_guardian: false,
+		payroll_manager: authority,
+		daily_limit: 0,
+		period_limit: 0,
+		period_duration: 0,
+		period_start: 0,
+		period_spent: 0,
+		hourly_outflow: [0; 24],
+		last_outflow_hour: 0,
+		rate_increase_threshold_bps: 1_000,
+		rate_increase_delay: 2 * 86_400,
+		rate_approver: Pubkey::new_unique(),
+		session_tree: Pubkey::default(),
+		compressed_session_count: 0,
+		version: Employer::VERSION,
+		fiscal_year_start_month: 1,
+		advance_limit_bps: 0,
+		payout_mode: PayoutMode::Push,
+		max_price_age: DEFAULT_MAX_PRICE_AGE,
+		max_price_conf_bps: DEFAULT_MAX_PRICE_CONF_BPS,
+		pay_frequency: None,
+		next_pay_period_start: 0,
+		crank_tip: 0,
+		reversal_window: 0,
+		pto_payout_on_termination: false,
+		pay_period_opened: false,
+		_reserved: [0; 22],
+	}
+}
+
+pub fn program_config_state(guardian: Pubkey) -> (Pubkey, ProgramConfig) {
+	let (program_config, bump) = Pubkey::find_program_address(&[b"program_config"], &PROGRAM_ID);
+
+	(program_config, ProgramConfig {
+		guardian,
+		paused: false,
+		bump,
+		version: ProgramConfig::VERSION,
+		_reserved: [0; 32],
+	})
+}
+
+pub fn employee_state(employer: Pubkey, authority: Pubkey, employee_wallet: Pubkey, hourly_rate: u64) -> Employee {
+	Employee {
+		name: String::from("Alice"),
+		position: String::from("Engineer"),
+		hourly_rate,
+		total_hours_worked: 0,
+		total_paid: 0,
+		authority,
+		employee_wallet,
+		is_active: true,
+		payout_wallet: employee_wallet,
+		pending_payout_wallet: None,
+		employer,
+		pending_hourly_rate: 0,
+		pending_rate_effective_at: 0,
+		version: Employee::VERSION,
+		ytd: EarningsTotals::default(),
+		ytd_start: 0,
+		period_to_date: EarningsTotals::default(),
+		period_start: 0,
+		compensation: Compensation::Hourly,
+		last_salary_period: 0,
+		outstanding_adjustment: 0,
+		total_reimbursed: 0,
+		outstanding_advance: 0,
+		deduction_rules: vec![],
+		payout_splits: vec![],
+		rate_price_feed: Pubkey::default(),
+		pto_accrual_bps: 0,
+		pto_balance: 0,
+		unpaid_leave: 0,
+		department_id: 0,
+		is_terminated: false,
+		last_raise_at: 0,
+		rate_feed_id: [0; 32],
+		unpaid_sessions: 0,
+		withholding_credit: 0,
+		_reserved: [0; 8],
+	}
+}
+
+pub fn payroll_batch_state(employer: Pubkey, authority: Pubkey, batch_id: u64) -> PayrollBatch {
+	PayrollBatch {
+		batch_id,
+		total_amount: 0,
+		created_at: 0,
+		processed_at: 0,
+		is_processed: false,
+		authority,
+		employer,
+		version: PayrollBatch::VERSION,
+		period_start: 0,
+		period_end: 0,
+		pay_date: 0,
+		is_cancelled: false,
+		_reserved: [0; 7],
+	}
+}
+
+pub fn work_session_state(employee: Pubkey, authority: Pubkey, session_id: u64, check_in_time: i64, check_out_time: i64) -> WorkSession {
+	WorkSession {
+		employee,
+		check_in_time,
+		check_out_time,
+		duration: (check_out_time - check_in_time).max(0) as u64,
+		is_paid: false,
+		authority,
+		session_id,
+		batch: Pubkey::default(),
+		rent_payer: authority,
+		version: WorkSession::VERSION,
+		exchange_rate: 0,
+		project_id: 0,
+		credited: 0,
+		is_approved: false,
+		payment: PaymentRecord::default(),
+		hourly_rate: 0,
+		_reserved: [0; 7],
+	}
+}
+
+pub fn deduction_rule_state(employer: Pubkey, rule_id: u64, kind: DeductionKind, remittance_vault: Pubkey) -> (Pubkey, DeductionRule) {
+	let (deduction_rule, _bump) = Pubkey::find_program_address(
+		&[b"deduction_rule", employer.as_ref(), rule_id.to_le_bytes().as_ref()],
+		&PROGRAM_ID,
+	);
+
+	(deduction_rule, DeductionRule {
+		employer,
+		rule_id,
+		name: String::from("Withholding"),
+		kind,
+		remittance_vault,
+		version: DeductionRule::VERSION,
+		_reserved: [0; 32],
+	})
+}
+
+pub fn pay_item_state(employer: Pubkey, employee: Pubkey, item_id: u64, kind: PayItemKind, amount: i64, batch: Pubkey) -> (Pubkey, PayItem) {
+	let (pay_item, _bump) = Pubkey::find_program_address(
+		&[b"pay_item", employee.as_ref(), item_id.to_le_bytes().as_ref()],
+		&PROGRAM_ID,
+	);
+
+	(pay_item, PayItem {
+		employer,
+		employee,
+		item_id,
+		kind,
+		amount,
+		memo_hash: [0; 32],
+		batch,
+		is_paid: false,
+		created_at: 0,
+		version: PayItem::VERSION,
+		_reserved: [0; 32],
+	})
+}
+
+pub fn expense_state(employer: Pubkey, employee: Pubkey, expense_id: u64, amount: u64, status: ExpenseStatus) -> (Pubkey, Expense) {
+	let (expense, _bump) = Pubkey::find_program_address(
+		&[b"expense", employee.as_ref(), expense_id.to_le_bytes().as_ref()],
+		&PROGRAM_ID,
+	);
+
+	(expense, Expense {
+		employer,
+		employee,
+		expense_id,
+		category: ExpenseCategory::Travel,
+		amount,
+		receipt_hash: [0; 32],
+		status,
+		submitted_at: 0,
+		reviewed_by: Pubkey::default(),
+		reviewed_at: 0,
+		batch: Pubkey::default(),
+		version: Expense::VERSION,
+		_reserved: [0; 32],
+	})
+}
+
+pub fn leave_request_state(employer: Pubkey, employee: Pubkey, request_id: u64, duration: u64) -> (Pubkey, LeaveRequest) {
+	let (leave_request, _bump) = Pubkey::find_program_address(
+		&[b"leave_request", employee.as_ref(), request_id.to_le_bytes().as_ref()],
+		&PROGRAM_ID,
+	);
+
+	(leave_request, LeaveRequest {
+		employer,
+		employee,
+		request_id,
+		start_time: 0,
+		duration,
+		status: LeaveStatus::Requested,
+		requested_at: 0,
+		reviewed_by: Pubkey::default(),
+		reviewed_at: 0,
+		version: LeaveRequest::VERSION,
+		_reserved: [0; 32],
+	})
+}
+
+pub fn department_state(employer: Pubkey, department_id: u64, budget_per_period: u64) -> (Pubkey, Department) {
+	let (department, _bump) = Pubkey::find_program_address(
+		&[b"department", employer.as_ref(), department_id.to_le_bytes().as_ref()],
+		&PROGRAM_ID,
+	);
+
+	(department, Department {
+		employer,
+		department_id,
+		name: "Engineering".to_string(),
+		budget_per_period,
+		period_start: 0,
+		period_spent: 0,
+		total_spent: 0,
+		version: Department::VERSION,
+		_reserved: [0; 32],
+	})
+}
+
+pub fn project_state(employer: Pubkey, project_id: u64, bill_rate: u64) -> (Pubkey, Project) {
+	let (project, _bump) = Pubkey::find_program_address(
+		&[b"project", employer.as_ref(), project_id.to_le_bytes().as_ref()],
+		&PROGRAM_ID,
+	);
+
+	(project, Project {
+		employer,
+		project_id,
+		name: "Website redesign".to_string(),
+		bill_rate,
+		is_active: true,
+		seconds_worked: 0,
+		billable_seconds: 0,
+		billable_amount: 0,
+		labor_cost: 0,
+		version: Project::VERSION,
+		_reserved: [0; 32],
+	})
+}
+
+pub fn pay_stream_state(employer: Pubkey, employee: Pubkey, rate_per_second: u64, started_at: i64) -> (Pubkey, PayStream) {
+	let (pay_stream, _bump) = Pubkey::find_program_address(&[b"pay_stream", employee.as_ref()], &PROGRAM_ID);
+
+	(pay_stream, PayStream {
+		employer,
+		employee,
+		rate_per_second,
+		started_at,
+		last_accrual_at: started_at,
+		accrued: 0,
+		total_withdrawn: 0,
+		is_paused: false,
+		is_cancelled: false,
+		version: PayStream::VERSION,
+		_reserved: [0; 32],
+	})
+}
+
+pub fn claimable_balance_state(employer: Pubkey, employee: Pubkey, amount: u64) -> (Pubkey, ClaimableBalance) {
+	let (claimable_balance, _bump) = Pubkey::find_program_address(&[b"claimable_balance", employee.as_ref()], &PROGRAM_ID);
+
+	(claimable_balance, ClaimableBalance {
+		employer,
+		employee,
+		amount,
+		total_claimed: 0,
+		version: ClaimableBalance::VERSION,
+		locks: [CreditLock::default(); MAX_CREDIT_LOCKS],
+		_reserved: [0; 16],
+	})
+}
+
+// Load the stand-in price oracle, which owns the price updates payroll reads.
+pub fn add_price_oracle(program_test: &mut ProgramTest) {
+	program_test.add_program(
+		"price_oracle",
+		price_oracle::ID,
+		processor!(convert_entry!(price_oracle::entry)),
+	);
+}
+
+// Price message for `feed_id` quoting one SOL at `price * 10^exponent` units.
+pub fn price_feed_message(feed_id: [u8; 32], price: i64, conf: u64, exponent: i32, publish_time: i64) -> PriceFeedMessage {
+	PriceFeedMessage {
+		feed_id,
+		price,
+		conf,
+		exponent,
+		publish_time,
+		prev_publish_time: publish_time - 1,
+		ema_price: price,
+		ema_conf: conf,
+	}
+}
 	
+// Zeroed concurrent Merkle tree account (no canopy) for `initialize_session_tree`.
+pub fn session_tree_account(max_depth: u32, max_buffer_size: u32) -> Account {
+	let path_size = 40 + 32 * max_depth as usize;
+	let header_size = 56;
+	let tree_size = 24 + max_buffer_size as usize * path_size + path_size;
+
+	Account {
+		lamports: 1_000_000_000,
+		data: vec![0; header_size + tree_size],
+		owner: SPL_ACCOUNT_COMPRESSION_ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+// Root and proof of leaf 0 in a tree whose other leaves are all empty.
+pub fn first_leaf_proof(leaf: [u8; 32], max_depth: u32) -> ([u8; 32], Vec<Pubkey>) {
+	let mut node = leaf;
+	let mut empty = [0u8; 32];
+	let mut proof = vec![];
+	for _ in 0..max_depth {
+		proof.push(Pubkey::new_from_array(empty));
+		node = keccak::hashv(&[&node, &empty]).to_bytes();
+		empty = keccak::hashv(&[&empty, &empty]).to_bytes();
+	}
+	(node, proof)
+}
+
 pub mod employee_payroll_ix_interface {
 
 	use {
@@ -48,6 +435,14 @@ pub mod employee_payroll_ix_interface {
 		},
 		employee_payroll::{
 			ID as PROGRAM_ID,
+			CompressedWorkSession,
+			Compensation,
+			DeductionKind,
+			PayItemKind,
+			ExpenseCategory,
+			PayoutSplit,
+			PayFrequency,
+			PayoutMode,
 			accounts as employee_payroll_accounts,
 			instruction as employee_payroll_instruction,
 		},
@@ -61,6 +456,7 @@ pub mod employee_payroll_ix_interface {
 		authority: &Keypair,
 		employer: &Keypair,
 		system_program: Pubkey,
+		rate_approver: Pubkey,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = employee_payroll_accounts::InitializeEmployer {
@@ -69,7 +465,9 @@ pub mod employee_payroll_ix_interface {
 			system_program: system_program,
 		};
 
-		let data = employee_payroll_instruction::InitializeEmployer;
+		let data = employee_payroll_instruction::InitializeEmployer {
+				rate_approver,
+		};
 		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
 			&[instruction], 
@@ -86,6 +484,7 @@ pub mod employee_payroll_ix_interface {
 
 	pub fn register_employee_ix_setup(
 		authority: &Keypair,
+		employer: Pubkey,
 		employee: Pubkey,
 		system_program: Pubkey,
 		name: &String,
@@ -96,6 +495,7 @@ pub mod employee_payroll_ix_interface {
 	) -> Transaction {
 		let accounts = employee_payroll_accounts::RegisterEmployee {
 			authority: authority.pubkey(),
+			employer: employer,
 			employee: employee,
 			system_program: system_program,
 		};
@@ -120,6 +520,7 @@ pub mod employee_payroll_ix_interface {
 
 	pub fn update_employee_ix_setup(
 		authority: &Keypair,
+		employer: Pubkey,
 		employee: Pubkey,
 		name: &String,
 		position: &String,
@@ -130,6 +531,7 @@ pub mod employee_payroll_ix_interface {
 	) -> Transaction {
 		let accounts = employee_payroll_accounts::UpdateEmployee {
 			authority: authority.pubkey(),
+			employer: employer,
 			employee: employee,
 		};
 
@@ -154,8 +556,11 @@ pub mod employee_payroll_ix_interface {
 
 	pub fn check_in_ix_setup(
 		authority: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
 		employee: Pubkey,
 		work_session: Pubkey,
+		project: Option<Pubkey>,
 		system_program: Pubkey,
 		employee_wallet: Pubkey,
 		session_id: u64,
@@ -164,8 +569,11 @@ pub mod employee_payroll_ix_interface {
 	) -> Transaction {
 		let accounts = employee_payroll_accounts::CheckIn {
 			authority: authority.pubkey(),
+			employer: employer,
+			program_config: program_config,
 			employee: employee,
 			work_session: work_session,
+			project: project,
 			system_program: system_program,
 		};
 
@@ -188,8 +596,10 @@ pub mod employee_payroll_ix_interface {
 
 	pub fn check_out_ix_setup(
 		authority: &Keypair,
+		employer: Pubkey,
 		employee: Pubkey,
 		work_session: Pubkey,
+		project: Option<Pubkey>,
 		employee_wallet: Pubkey,
 		session_id: u64,
 		timestamp: i64,
@@ -197,8 +607,10 @@ pub mod employee_payroll_ix_interface {
 	) -> Transaction {
 		let accounts = employee_payroll_accounts::CheckOut {
 			authority: authority.pubkey(),
+			employer: employer,
 			employee: employee,
 			work_session: work_session,
+			project: project,
 		};
 
 		let data = 	employee_payroll_instruction::CheckOut {
@@ -220,6 +632,7 @@ pub mod employee_payroll_ix_interface {
 
 	pub fn create_payroll_batch_ix_setup(
 		authority: &Keypair,
+		employer: Pubkey,
 		payroll_batch: Pubkey,
 		system_program: Pubkey,
 		batch_id: u64,
@@ -228,6 +641,7 @@ pub mod employee_payroll_ix_interface {
 	) -> Transaction {
 		let accounts = employee_payroll_accounts::CreatePayrollBatch {
 			authority: authority.pubkey(),
+			employer: employer,
 			payroll_batch: payroll_batch,
 			system_program: system_program,
 		};
@@ -250,46 +664,92 @@ pub mod employee_payroll_ix_interface {
 
 	pub fn process_payroll_ix_setup(
 		authority: &Keypair,
+		owner: Option<&Keypair>,
+		employer: Pubkey,
+		program_config: Pubkey,
 		payroll_batch: Pubkey,
 		employee: Pubkey,
 		work_session: Pubkey,
+		vault: Pubkey,
+		payout_wallet: Pubkey,
+		claimable_balance: Option<Pubkey>,
+		price_feed: Option<Pubkey>,
+		department: Option<Pubkey>,
+		project: Option<Pubkey>,
+		system_program: Pubkey,
+		deductions: &[(Pubkey, Pubkey)],
+		payout_splits: &[Pubkey],
+		pay_items: &[Pubkey],
+		expenses: &[Pubkey],
 		batch_id: u64,
 		timestamp: i64,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = employee_payroll_accounts::ProcessPayroll {
 			authority: authority.pubkey(),
+			owner: owner.map(|owner| owner.pubkey()),
+			employer: employer,
+			program_config: program_config,
 			payroll_batch: payroll_batch,
 			employee: employee,
 			work_session: work_session,
+			vault: vault,
+			payout_wallet: payout_wallet,
+			claimable_balance: claimable_balance,
+			price_feed: price_feed,
+			department: department,
+			project: project,
+			system_program: system_program,
 		};
 
 		let data = 	employee_payroll_instruction::ProcessPayroll {
 				batch_id,
 				timestamp,
-		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for (deduction_rule, remittance_vault) in deductions {
+			account_metas.push(AccountMeta::new_readonly(*deduction_rule, false));
+			account_metas.push(AccountMeta::new(*remittance_vault, false));
+		}
+		for wallet in payout_splits {
+			account_metas.push(AccountMeta::new(*wallet, false));
+		}
+		for pay_item in pay_items.iter().chain(expenses) {
+			account_metas.push(AccountMeta::new(*pay_item, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
 		let mut transaction = Transaction::new_with_payer(
 			&[instruction], 
 			Some(&authority.pubkey()),
 		);
 
-		transaction.sign(&[
-			&authority,
-		], recent_blockhash);
+		let mut signers = vec![authority];
+		signers.extend(owner);
+		transaction.sign(&signers, recent_blockhash);
 
 		return transaction;
 	}
 
 	pub fn mark_session_paid_ix_setup(
 		authority: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
+		employee: Pubkey,
 		work_session: Pubkey,
+		payroll_batch: Option<Pubkey>,
 		employee_wallet: Pubkey,
 		session_id: u64,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = employee_payroll_accounts::MarkSessionPaid {
 			authority: authority.pubkey(),
+			employer: employer,
+			program_config: program_config,
+			employee: employee,
 			work_session: work_session,
+			payroll_batch: payroll_batch,
 		};
 
 		let data = 	employee_payroll_instruction::MarkSessionPaid {
@@ -308,4 +768,2399 @@ pub mod employee_payroll_ix_interface {
 		return transaction;
 	}
 
+	pub fn propose_payout_wallet_ix_setup(
+		authority: &Keypair,
+		employee: Pubkey,
+		employee_wallet: Pubkey,
+		new_payout_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ProposePayoutWallet {
+			authority: authority.pubkey(),
+			employee: employee,
+		};
+
+		let data = 	employee_payroll_instruction::ProposePayoutWallet {
+				employee_wallet,
+				new_payout_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn accept_payout_wallet_ix_setup(
+		authority: &Keypair,
+		employee: Pubkey,
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::AcceptPayoutWallet {
+			authority: authority.pubkey(),
+			employee: employee,
+		};
+
+		let data = 	employee_payroll_instruction::AcceptPayoutWallet {
+				employee_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn initialize_program_config_ix_setup(
+		authority: &Keypair,
+		program_config: Pubkey,
+		program: Pubkey,
+		program_data: Pubkey,
+		system_program: Pubkey,
+		guardian: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::InitializeProgramConfig {
+			authority: authority.pubkey(),
+			program_config: program_config,
+			program: program,
+			program_data: program_data,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::InitializeProgramConfig {
+				guardian,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn pause_employer_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::PauseEmployer {
+			authority: authority.pubkey(),
+			employer: employer,
+			program_config: program_config,
+		};
+
+		let data = employee_payroll_instruction::PauseEmployer;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn unpause_employer_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::UnpauseEmployer {
+			authority: authority.pubkey(),
+			employer: employer,
+			program_config: program_config,
+		};
+
+		let data = employee_payroll_instruction::UnpauseEmployer;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn pause_program_ix_setup(
+		guardian: &Keypair,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::PauseProgram {
+			guardian: guardian.pubkey(),
+			program_config: program_config,
+		};
+
+		let data = employee_payroll_instruction::PauseProgram;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&guardian.pubkey()),
+		);
+
+		transaction.sign(&[
+			&guardian,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn unpause_program_ix_setup(
+		guardian: &Keypair,
+		program_config: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::UnpauseProgram {
+			guardian: guardian.pubkey(),
+			program_config: program_config,
+		};
+
+		let data = employee_payroll_instruction::UnpauseProgram;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&guardian.pubkey()),
+		);
+
+		transaction.sign(&[
+			&guardian,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn withdraw_from_vault_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
+		vault: Pubkey,
+		destination: Pubkey,
+		system_program: Pubkey,
+		amount: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::WithdrawFromVault {
+			authority: authority.pubkey(),
+			employer: employer,
+			program_config: program_config,
+			vault: vault,
+			destination: destination,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::WithdrawFromVault {
+				amount,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_payroll_manager_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		payroll_manager: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetPayrollManager {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetPayrollManager {
+				payroll_manager,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_spending_limits_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		daily_limit: u64,
+		period_limit: u64,
+		period_duration: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetSpendingLimits {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetSpendingLimits {
+				daily_limit,
+				period_limit,
+				period_duration,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_rate_change_policy_ix_setup(
+		authority: &Keypair,
+		rate_approver_signer: &Keypair,
+		employer: Pubkey,
+		rate_increase_threshold_bps: u16,
+		rate_increase_delay: i64,
+		rate_approver: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetRateChangePolicy {
+			authority: authority.pubkey(),
+			rate_approver_signer: rate_approver_signer.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetRateChangePolicy {
+				rate_increase_threshold_bps,
+				rate_increase_delay,
+				rate_approver,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+			&rate_approver_signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn apply_rate_change_ix_setup(
+		authority: &Keypair,
+		employee: Pubkey,
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ApplyRateChange {
+			authority: authority.pubkey(),
+			employee: employee,
+		};
+
+		let data = 	employee_payroll_instruction::ApplyRateChange {
+				employee_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn veto_rate_change_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::VetoRateChange {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+		};
+
+		let data = 	employee_payroll_instruction::VetoRateChange {
+				employee_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn complete_payroll_batch_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		payroll_batch: Pubkey,
+		batch_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CompletePayrollBatch {
+			authority: authority.pubkey(),
+			employer: employer,
+			payroll_batch: payroll_batch,
+		};
+
+		let data = 	employee_payroll_instruction::CompletePayrollBatch {
+				batch_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn close_work_session_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		work_session: Pubkey,
+		payroll_batch: Option<Pubkey>,
+		rent_payer: Pubkey,
+		employee_wallet: Pubkey,
+		session_id: u64,
+		emit_archive: bool,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CloseWorkSession {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			work_session: work_session,
+			payroll_batch: payroll_batch,
+			rent_payer: rent_payer,
+		};
+
+		let data = 	employee_payroll_instruction::CloseWorkSession {
+				employee_wallet,
+				session_id,
+				emit_archive,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn close_work_sessions_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		payroll_batch: Pubkey,
+		sessions: &[(Pubkey, Pubkey)],
+		batch_id: u64,
+		emit_archive: bool,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CloseWorkSessions {
+			authority: authority.pubkey(),
+			employer: employer,
+			payroll_batch: payroll_batch,
+		};
+
+		let data = 	employee_payroll_instruction::CloseWorkSessions {
+				batch_id,
+				emit_archive,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for (work_session, rent_payer) in sessions {
+			account_metas.push(AccountMeta::new(*work_session, false));
+			account_metas.push(AccountMeta::new(*rent_payer, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn initialize_session_tree_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		merkle_tree: Pubkey,
+		tree_authority: Pubkey,
+		compression_program: Pubkey,
+		noop: Pubkey,
+		max_depth: u32,
+		max_buffer_size: u32,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::InitializeSessionTree {
+			authority: authority.pubkey(),
+			employer: employer,
+			merkle_tree: merkle_tree,
+			tree_authority: tree_authority,
+			compression_program: compression_program,
+			noop: noop,
+		};
+
+		let data = 	employee_payroll_instruction::InitializeSessionTree {
+				max_depth,
+				max_buffer_size,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn compress_work_session_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		work_session: Pubkey,
+		payroll_batch: Option<Pubkey>,
+		rent_payer: Pubkey,
+		merkle_tree: Pubkey,
+		tree_authority: Pubkey,
+		compression_program: Pubkey,
+		noop: Pubkey,
+		employee_wallet: Pubkey,
+		session_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CompressWorkSession {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			work_session: work_session,
+			payroll_batch: payroll_batch,
+			rent_payer: rent_payer,
+			merkle_tree: merkle_tree,
+			tree_authority: tree_authority,
+			compression_program: compression_program,
+			noop: noop,
+		};
+
+		let data = 	employee_payroll_instruction::CompressWorkSession {
+				employee_wallet,
+				session_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn process_compressed_payroll_ix_setup(
+		authority: &Keypair,
+		owner: Option<&Keypair>,
+		employer: Pubkey,
+		program_config: Pubkey,
+		payroll_batch: Pubkey,
+		employee: Pubkey,
+		vault: Pubkey,
+		payout_wallet: Pubkey,
+		claimable_balance: Option<Pubkey>,
+		price_feed: Option<Pubkey>,
+		department: Option<Pubkey>,
+		merkle_tree: Pubkey,
+		tree_authority: Pubkey,
+		compression_program: Pubkey,
+		noop: Pubkey,
+		system_program: Pubkey,
+		deductions: &[(Pubkey, Pubkey)],
+		payout_splits: &[Pubkey],
+		proof: &[Pubkey],
+		batch_id: u64,
+		root: [u8; 32],
+		session: &CompressedWorkSession,
+		index: u32,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ProcessCompressedPayroll {
+			authority: authority.pubkey(),
+			owner: owner.map(|owner| owner.pubkey()),
+			employer: employer,
+			program_config: program_config,
+			payroll_batch: payroll_batch,
+			employee: employee,
+			vault: vault,
+			payout_wallet: payout_wallet,
+			claimable_balance: claimable_balance,
+			price_feed: price_feed,
+			department: department,
+			merkle_tree: merkle_tree,
+			tree_authority: tree_authority,
+			compression_program: compression_program,
+			noop: noop,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::ProcessCompressedPayroll {
+				batch_id,
+				root,
+				session: session.clone(),
+				index,
+		};
+		let mut account_metas = accounts.to_account_metas(None);
+		for (deduction_rule, remittance_vault) in deductions {
+			account_metas.push(AccountMeta::new_readonly(*deduction_rule, false));
+			account_metas.push(AccountMeta::new(*remittance_vault, false));
+		}
+		for wallet in payout_splits {
+			account_metas.push(AccountMeta::new(*wallet, false));
+		}
+		account_metas.extend(proof.iter().map(|node| AccountMeta::new_readonly(*node, false)));
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		let mut signers = vec![authority];
+		signers.extend(owner);
+		transaction.sign(&signers, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn migrate_account_ix_setup(
+		payer: &Keypair,
+		account: Pubkey,
+		system_program: Pubkey,
+		employer: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::MigrateAccount {
+			payer: payer.pubkey(),
+			account: account,
+			system_program: system_program,
+		};
+
+		let data = employee_payroll_instruction::MigrateAccount;
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(employer.map(|employer| AccountMeta::new_readonly(employer, false)));
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_deduction_rule_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		deduction_rule: Pubkey,
+		system_program: Pubkey,
+		rule_id: u64,
+		name: &String,
+		kind: &DeductionKind,
+		remittance_vault: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CreateDeductionRule {
+			authority: authority.pubkey(),
+			employer: employer,
+			deduction_rule: deduction_rule,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::CreateDeductionRule {
+				rule_id,
+				name: name.clone(),
+				kind: kind.clone(),
+				remittance_vault,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn update_deduction_rule_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		deduction_rule: Pubkey,
+		rule_id: u64,
+		name: &String,
+		kind: &DeductionKind,
+		remittance_vault: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::UpdateDeductionRule {
+			authority: authority.pubkey(),
+			employer: employer,
+			deduction_rule: deduction_rule,
+		};
+
+		let data = 	employee_payroll_instruction::UpdateDeductionRule {
+				rule_id,
+				name: name.clone(),
+				kind: kind.clone(),
+				remittance_vault,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn attach_deduction_rule_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		deduction_rule: Pubkey,
+		employee_wallet: Pubkey,
+		rule_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::AttachDeductionRule {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			deduction_rule: deduction_rule,
+		};
+
+		let data = 	employee_payroll_instruction::AttachDeductionRule {
+				employee_wallet,
+				rule_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn detach_deduction_rule_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		deduction_rule: Pubkey,
+		employee_wallet: Pubkey,
+		rule_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::DetachDeductionRule {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			deduction_rule: deduction_rule,
+		};
+
+		let data = 	employee_payroll_instruction::DetachDeductionRule {
+				employee_wallet,
+				rule_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_fiscal_year_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		start_month: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetFiscalYear {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetFiscalYear {
+				start_month,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_compensation_ix_setup(
+		authority: &Keypair,
+		rate_approver: Option<&Keypair>,
+		employer: Pubkey,
+		employee: Pubkey,
+		employee_wallet: Pubkey,
+		compensation: Compensation,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetCompensation {
+			authority: authority.pubkey(),
+			rate_approver: rate_approver.map(|rate_approver| rate_approver.pubkey()),
+			employer: employer,
+			employee: employee,
+		};
+
+		let data = 	employee_payroll_instruction::SetCompensation {
+				employee_wallet,
+				compensation,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		let mut signers = vec![authority];
+		signers.extend(rate_approver);
+		transaction.sign(&signers, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn process_salary_payroll_ix_setup(
+		authority: &Keypair,
+		owner: Option<&Keypair>,
+		employer: Pubkey,
+		program_config: Pubkey,
+		payroll_batch: Pubkey,
+		employee: Pubkey,
+		vault: Pubkey,
+		payout_wallet: Pubkey,
+		claimable_balance: Option<Pubkey>,
+		department: Option<Pubkey>,
+		system_program: Pubkey,
+		deductions: &[(Pubkey, Pubkey)],
+		payout_splits: &[Pubkey],
+		pay_items: &[Pubkey],
+		expenses: &[Pubkey],
+		batch_id: u64,
+		timestamp: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ProcessSalaryPayroll {
+			authority: authority.pubkey(),
+			owner: owner.map(|owner| owner.pubkey()),
+			employer: employer,
+			program_config: program_config,
+			payroll_batch: payroll_batch,
+			employee: employee,
+			vault: vault,
+			payout_wallet: payout_wallet,
+			claimable_balance: claimable_balance,
+			department: department,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::ProcessSalaryPayroll {
+				batch_id,
+				timestamp,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for (deduction_rule, remittance_vault) in deductions {
+			account_metas.push(AccountMeta::new_readonly(*deduction_rule, false));
+			account_metas.push(AccountMeta::new(*remittance_vault, false));
+		}
+		for wallet in payout_splits {
+			account_metas.push(AccountMeta::new(*wallet, false));
+		}
+		for pay_item in pay_items.iter().chain(expenses) {
+			account_metas.push(AccountMeta::new(*pay_item, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		let mut signers = vec![authority];
+		signers.extend(owner);
+		transaction.sign(&signers, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn process_leave_payroll_ix_setup(
+		authority: &Keypair,
+		owner: Option<&Keypair>,
+		employer: Pubkey,
+		program_config: Pubkey,
+		payroll_batch: Pubkey,
+		employee: Pubkey,
+		price_feed: Option<Pubkey>,
+		vault: Pubkey,
+		payout_wallet: Pubkey,
+		claimable_balance: Option<Pubkey>,
+		department: Option<Pubkey>,
+		system_program: Pubkey,
+		deductions: &[(Pubkey, Pubkey)],
+		payout_splits: &[Pubkey],
+		pay_items: &[Pubkey],
+		expenses: &[Pubkey],
+		batch_id: u64,
+		timestamp: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ProcessLeavePayroll {
+			authority: authority.pubkey(),
+			owner: owner.map(|owner| owner.pubkey()),
+			employer: employer,
+			program_config: program_config,
+			payroll_batch: payroll_batch,
+			employee: employee,
+			price_feed: price_feed,
+			vault: vault,
+			payout_wallet: payout_wallet,
+			claimable_balance: claimable_balance,
+			department: department,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::ProcessLeavePayroll {
+				batch_id,
+				timestamp,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for (deduction_rule, remittance_vault) in deductions {
+			account_metas.push(AccountMeta::new_readonly(*deduction_rule, false));
+			account_metas.push(AccountMeta::new(*remittance_vault, false));
+		}
+		for wallet in payout_splits {
+			account_metas.push(AccountMeta::new(*wallet, false));
+		}
+		for pay_item in pay_items.iter().chain(expenses) {
+			account_metas.push(AccountMeta::new(*pay_item, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		let mut signers = vec![authority];
+		signers.extend(owner);
+		transaction.sign(&signers, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_pay_item_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		pay_item: Pubkey,
+		system_program: Pubkey,
+		employee_wallet: Pubkey,
+		item_id: u64,
+		kind: PayItemKind,
+		amount: i64,
+		memo_hash: [u8; 32],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CreatePayItem {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			pay_item: pay_item,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::CreatePayItem {
+				employee_wallet,
+				item_id,
+				kind,
+				amount,
+				memo_hash,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn attach_pay_item_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		pay_item: Pubkey,
+		payroll_batch: Pubkey,
+		employee_wallet: Pubkey,
+		item_id: u64,
+		batch_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::AttachPayItem {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			pay_item: pay_item,
+			payroll_batch: payroll_batch,
+		};
+
+		let data = 	employee_payroll_instruction::AttachPayItem {
+				employee_wallet,
+				item_id,
+				batch_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn submit_expense_ix_setup(
+		authority: &Keypair,
+		employee: Pubkey,
+		expense: Pubkey,
+		system_program: Pubkey,
+		employee_wallet: Pubkey,
+		expense_id: u64,
+		amount: u64,
+		category: ExpenseCategory,
+		receipt_hash: [u8; 32],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SubmitExpense {
+			authority: authority.pubkey(),
+			employee: employee,
+			expense: expense,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::SubmitExpense {
+				employee_wallet,
+				expense_id,
+				amount,
+				category,
+				receipt_hash,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_expense_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		expense: Pubkey,
+		employee_wallet: Pubkey,
+		expense_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ApproveExpense {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			expense: expense,
+		};
+
+		let data = 	employee_payroll_instruction::ApproveExpense {
+				employee_wallet,
+				expense_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn reject_expense_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		expense: Pubkey,
+		employee_wallet: Pubkey,
+		expense_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::RejectExpense {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			expense: expense,
+		};
+
+		let data = 	employee_payroll_instruction::RejectExpense {
+				employee_wallet,
+				expense_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_pay_stream_ix_setup(
+		authority: &Keypair,
+		rate_approver: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		pay_stream: Pubkey,
+		system_program: Pubkey,
+		employee_wallet: Pubkey,
+		rate_per_second: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CreatePayStream {
+			authority: authority.pubkey(),
+			rate_approver: rate_approver.pubkey(),
+			employer: employer,
+			employee: employee,
+			pay_stream: pay_stream,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::CreatePayStream {
+				employee_wallet,
+				rate_per_second,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+			&rate_approver,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn pause_pay_stream_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		pay_stream: Pubkey,
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::PausePayStream {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			pay_stream: pay_stream,
+		};
+
+		let data = 	employee_payroll_instruction::PausePayStream {
+				employee_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn resume_pay_stream_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		pay_stream: Pubkey,
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ResumePayStream {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			pay_stream: pay_stream,
+		};
+
+		let data = 	employee_payroll_instruction::ResumePayStream {
+				employee_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cancel_pay_stream_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		pay_stream: Pubkey,
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CancelPayStream {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			pay_stream: pay_stream,
+		};
+
+		let data = 	employee_payroll_instruction::CancelPayStream {
+				employee_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn withdraw_streamed_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
+		employee: Pubkey,
+		pay_stream: Pubkey,
+		vault: Pubkey,
+		payout_wallet: Pubkey,
+		system_program: Pubkey,
+		deductions: &[(Pubkey, Pubkey)],
+		employee_wallet: Pubkey,
+		amount: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::WithdrawStreamed {
+			authority: authority.pubkey(),
+			employer: employer,
+			program_config: program_config,
+			employee: employee,
+			pay_stream: pay_stream,
+			vault: vault,
+			payout_wallet: payout_wallet,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::WithdrawStreamed {
+				employee_wallet,
+				amount,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for (deduction_rule, remittance_vault) in deductions {
+			account_metas.push(AccountMeta::new_readonly(*deduction_rule, false));
+			account_metas.push(AccountMeta::new(*remittance_vault, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_advance_policy_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		advance_limit_bps: u16,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetAdvancePolicy {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetAdvancePolicy {
+				advance_limit_bps,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn request_advance_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
+		employee: Pubkey,
+		vault: Pubkey,
+		payout_wallet: Pubkey,
+		price_feed: Option<Pubkey>,
+		system_program: Pubkey,
+		work_sessions: &[Pubkey],
+		employee_wallet: Pubkey,
+		amount: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::RequestAdvance {
+			authority: authority.pubkey(),
+			employer: employer,
+			program_config: program_config,
+			employee: employee,
+			vault: vault,
+			payout_wallet: payout_wallet,
+			price_feed: price_feed,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::RequestAdvance {
+				employee_wallet,
+				amount,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for work_session in work_sessions {
+			account_metas.push(AccountMeta::new_readonly(*work_session, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_payout_splits_ix_setup(
+		authority: &Keypair,
+		employee: Pubkey,
+		employee_wallet: Pubkey,
+		payout_splits: &[PayoutSplit],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetPayoutSplits {
+			authority: authority.pubkey(),
+			employee: employee,
+		};
+
+		let data = 	employee_payroll_instruction::SetPayoutSplits {
+				employee_wallet,
+				payout_splits: payout_splits.to_vec(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_payout_mode_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		payout_mode: PayoutMode,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetPayoutMode {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetPayoutMode {
+				payout_mode,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn open_claimable_balance_ix_setup(
+		payer: &Keypair,
+		employee: Pubkey,
+		claimable_balance: Pubkey,
+		system_program: Pubkey,
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::OpenClaimableBalance {
+			payer: payer.pubkey(),
+			employee: employee,
+			claimable_balance: claimable_balance,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::OpenClaimableBalance {
+				employee_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claim_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
+		employee: Pubkey,
+		claimable_balance: Pubkey,
+		payout_wallet: Pubkey,
+		payout_splits: &[Pubkey],
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::Claim {
+			authority: authority.pubkey(),
+			employer: employer,
+			program_config: program_config,
+			employee: employee,
+			claimable_balance: claimable_balance,
+			payout_wallet: payout_wallet,
+		};
+
+		let data = 	employee_payroll_instruction::Claim {
+				employee_wallet,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for wallet in payout_splits {
+			account_metas.push(AccountMeta::new(*wallet, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_price_policy_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		max_price_age: i64,
+		max_price_conf_bps: u16,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetPricePolicy {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetPricePolicy {
+				max_price_age,
+				max_price_conf_bps,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_rate_currency_ix_setup(
+		authority: &Keypair,
+		rate_approver: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		employee_wallet: Pubkey,
+		price_feed: Option<Pubkey>,
+		rate_price_feed: Pubkey,
+		rate_feed_id: [u8; 32],
+		hourly_rate: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetRateCurrency {
+			authority: authority.pubkey(),
+			rate_approver: rate_approver.pubkey(),
+			employer: employer,
+			employee: employee,
+			price_feed: price_feed,
+		};
+
+		let data = 	employee_payroll_instruction::SetRateCurrency {
+				employee_wallet,
+				rate_price_feed,
+				rate_feed_id,
+				hourly_rate,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+			&rate_approver,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_pto_accrual_ix_setup(
+		authority: &Keypair,
+		employee: Pubkey,
+		employee_wallet: Pubkey,
+		pto_accrual_bps: u16,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetPtoAccrual {
+			authority: authority.pubkey(),
+			employee: employee,
+		};
+
+		let data = 	employee_payroll_instruction::SetPtoAccrual {
+				employee_wallet,
+				pto_accrual_bps,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn request_leave_ix_setup(
+		authority: &Keypair,
+		employee: Pubkey,
+		leave_request: Pubkey,
+		system_program: Pubkey,
+		employee_wallet: Pubkey,
+		request_id: u64,
+		start_time: i64,
+		duration: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::RequestLeave {
+			authority: authority.pubkey(),
+			employee: employee,
+			leave_request: leave_request,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::RequestLeave {
+				employee_wallet,
+				request_id,
+				start_time,
+				duration,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_leave_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		leave_request: Pubkey,
+		employee_wallet: Pubkey,
+		request_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ApproveLeave {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			leave_request: leave_request,
+		};
+
+		let data = 	employee_payroll_instruction::ApproveLeave {
+				employee_wallet,
+				request_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_department_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		department: Pubkey,
+		system_program: Pubkey,
+		department_id: u64,
+		name: String,
+		budget_per_period: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CreateDepartment {
+			authority: authority.pubkey(),
+			employer: employer,
+			department: department,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::CreateDepartment {
+				department_id,
+				name,
+				budget_per_period,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_department_budget_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		department: Pubkey,
+		department_id: u64,
+		budget_per_period: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetDepartmentBudget {
+			authority: authority.pubkey(),
+			employer: employer,
+			department: department,
+		};
+
+		let data = 	employee_payroll_instruction::SetDepartmentBudget {
+				department_id,
+				budget_per_period,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn assign_department_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		department: Option<Pubkey>,
+		employee_wallet: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::AssignDepartment {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			department: department,
+		};
+
+		let data = 	employee_payroll_instruction::AssignDepartment {
+				employee_wallet,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_project_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		project: Pubkey,
+		system_program: Pubkey,
+		project_id: u64,
+		name: String,
+		bill_rate: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CreateProject {
+			authority: authority.pubkey(),
+			employer: employer,
+			project: project,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::CreateProject {
+				project_id,
+				name,
+				bill_rate,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn update_project_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		project: Pubkey,
+		project_id: u64,
+		bill_rate: u64,
+		is_active: bool,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::UpdateProject {
+			authority: authority.pubkey(),
+			employer: employer,
+			project: project,
+		};
+
+		let data = 	employee_payroll_instruction::UpdateProject {
+				project_id,
+				bill_rate,
+				is_active,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_pay_schedule_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		pay_frequency: PayFrequency,
+		anchor: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetPaySchedule {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetPaySchedule {
+				pay_frequency,
+				anchor,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn open_next_pay_period_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		payroll_batch: Pubkey,
+		system_program: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::OpenNextPayPeriod {
+			authority: authority.pubkey(),
+			employer: employer,
+			payroll_batch: payroll_batch,
+			system_program: system_program,
+		};
+
+		let data = employee_payroll_instruction::OpenNextPayPeriod;
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_payroll_batch_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		payroll_batch: Pubkey,
+		batch_id: u64,
+		pay_date: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ApprovePayrollBatch {
+			authority: authority.pubkey(),
+			employer: employer,
+			payroll_batch: payroll_batch,
+		};
+
+		let data = 	employee_payroll_instruction::ApprovePayrollBatch {
+				batch_id,
+				pay_date,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_crank_tip_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		crank_tip: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetCrankTip {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetCrankTip {
+				crank_tip,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn crank_payroll_ix_setup(
+		cranker: &Keypair,
+		employer: Pubkey,
+		program_config: Pubkey,
+		payroll_batch: Pubkey,
+		employee: Pubkey,
+		work_session: Pubkey,
+		vault: Pubkey,
+		payout_wallet: Pubkey,
+		claimable_balance: Option<Pubkey>,
+		price_feed: Option<Pubkey>,
+		department: Option<Pubkey>,
+		project: Option<Pubkey>,
+		system_program: Pubkey,
+		deductions: &[(Pubkey, Pubkey)],
+		payout_splits: &[Pubkey],
+		pay_items: &[Pubkey],
+		expenses: &[Pubkey],
+		batch_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CrankPayroll {
+			cranker: cranker.pubkey(),
+			employer: employer,
+			program_config: program_config,
+			payroll_batch: payroll_batch,
+			employee: employee,
+			work_session: work_session,
+			vault: vault,
+			payout_wallet: payout_wallet,
+			claimable_balance: claimable_balance,
+			price_feed: price_feed,
+			department: department,
+			project: project,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::CrankPayroll {
+				batch_id,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for (deduction_rule, remittance_vault) in deductions {
+			account_metas.push(AccountMeta::new_readonly(*deduction_rule, false));
+			account_metas.push(AccountMeta::new(*remittance_vault, false));
+		}
+		for wallet in payout_splits {
+			account_metas.push(AccountMeta::new(*wallet, false));
+		}
+		for pay_item in pay_items.iter().chain(expenses) {
+			account_metas.push(AccountMeta::new(*pay_item, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&cranker.pubkey()),
+		);
+
+		transaction.sign(&[
+			&cranker,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cancel_batch_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		payroll_batch: Pubkey,
+		released: &[Pubkey],
+		batch_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CancelBatch {
+			authority: authority.pubkey(),
+			employer: employer,
+			payroll_batch: payroll_batch,
+		};
+
+		let data = 	employee_payroll_instruction::CancelBatch {
+				batch_id,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for account in released {
+			account_metas.push(AccountMeta::new(*account, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_reversal_window_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		reversal_window: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetReversalWindow {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetReversalWindow {
+				reversal_window,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claw_back_credit_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		payroll_batch: Pubkey,
+		employee: Pubkey,
+		work_session: Pubkey,
+		claimable_balance: Pubkey,
+		vault: Pubkey,
+		department: Option<Pubkey>,
+		project: Option<Pubkey>,
+		pay_items: &[Pubkey],
+		expenses: &[Pubkey],
+		employee_wallet: Pubkey,
+		session_id: u64,
+		batch_id: u64,
+		reason: String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ClawBackCredit {
+			authority: authority.pubkey(),
+			employer: employer,
+			payroll_batch: payroll_batch,
+			employee: employee,
+			work_session: work_session,
+			claimable_balance: claimable_balance,
+			vault: vault,
+			department: department,
+			project: project,
+		};
+
+		let data = 	employee_payroll_instruction::ClawBackCredit {
+				employee_wallet,
+				session_id,
+				batch_id,
+				reason,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		for account in pay_items.iter().chain(expenses) {
+			account_metas.push(AccountMeta::new(*account, false));
+		}
+
+		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_pto_payout_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		pto_payout_on_termination: bool,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::SetPtoPayout {
+			authority: authority.pubkey(),
+			employer: employer,
+		};
+
+		let data = 	employee_payroll_instruction::SetPtoPayout {
+				pto_payout_on_termination,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn terminate_employee_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		work_session: Option<Pubkey>,
+		project: Option<Pubkey>,
+		price_feed: Option<Pubkey>,
+		pay_stream: Pubkey,
+		final_settlement: Pubkey,
+		system_program: Pubkey,
+		employee_wallet: Pubkey,
+		item_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::TerminateEmployee {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			work_session: work_session,
+			project: project,
+			price_feed: price_feed,
+			pay_stream: pay_stream,
+			final_settlement: final_settlement,
+			system_program: system_program,
+		};
+
+		let data = 	employee_payroll_instruction::TerminateEmployee {
+				employee_wallet,
+				item_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn close_employee_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		final_settlement: Pubkey,
+		claimable_balance: Pubkey,
+		employee_wallet: Pubkey,
+		item_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::CloseEmployee {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			final_settlement: final_settlement,
+			claimable_balance: claimable_balance,
+		};
+
+		let data = 	employee_payroll_instruction::CloseEmployee {
+				employee_wallet,
+				item_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_work_session_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		work_session: Pubkey,
+		employee_wallet: Pubkey,
+		session_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::ApproveWorkSession {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			work_session: work_session,
+		};
+
+		let data = 	employee_payroll_instruction::ApproveWorkSession {
+				employee_wallet,
+				session_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn attach_work_session_ix_setup(
+		authority: &Keypair,
+		employer: Pubkey,
+		employee: Pubkey,
+		work_session: Pubkey,
+		payroll_batch: Pubkey,
+		employee_wallet: Pubkey,
+		session_id: u64,
+		batch_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = employee_payroll_accounts::AttachWorkSession {
+			authority: authority.pubkey(),
+			employer: employer,
+			employee: employee,
+			work_session: work_session,
+			payroll_batch: payroll_batch,
+		};
+
+		let data = 	employee_payroll_instruction::AttachWorkSession {
+				employee_wallet,
+				session_id,
+				batch_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+}
+
+pub mod price_oracle_ix_interface {
+
+	use {
+		solana_sdk::{
+			hash::Hash,
+			signature::{Keypair, Signer},
+			instruction::Instruction,
+			pubkey::Pubkey,
+			transaction::Transaction,
+		},
+		price_oracle::{
+			ID as PROGRAM_ID,
+			PriceFeedMessage,
+			accounts as price_oracle_accounts,
+			instruction as price_oracle_instruction,
+		},
+		anchor_lang::{
+			prelude::*,
+			InstructionData,
+		}
+	};
+
+	pub fn post_update_ix_setup(
+		write_authority: &Keypair,
+		price_update: &Keypair,
+		system_program: Pubkey,
+		price_message: PriceFeedMessage,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = price_oracle_accounts::PostUpdate {
+			write_authority: write_authority.pubkey(),
+			price_update: price_update.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	price_oracle_instruction::PostUpdate {
+				price_message,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&write_authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&write_authority,
+			&price_update,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn update_ix_setup(
+		write_authority: &Keypair,
+		price_update: Pubkey,
+		price_message: PriceFeedMessage,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = price_oracle_accounts::Update {
+			write_authority: write_authority.pubkey(),
+			price_update: price_update,
+		};
+
+		let data = 	price_oracle_instruction::Update {
+				price_message,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&write_authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&write_authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
 }
@@ -0,0 +1,210 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		work_session_state,
+		payroll_batch_state,
+		session_tree_account,
+	},
+    employee_payroll::Employer,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn compress_work_session_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	let session_id: u64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let max_depth: u32 = 14;
+	let max_buffer_size: u32 = 64;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let merkle_tree_pubkey = Pubkey::new_unique();
+	let payroll_batch_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let compression_program_pubkey = Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap();
+	let noop_pubkey = Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (tree_authority_pda, _tree_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"session_tree",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(merkle_tree_pubkey, session_tree_account(max_depth, max_buffer_size));
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate)));
+	program_test.add_account(work_session_pda, program_account(&work_session_state(employee_pda, authority_pubkey, session_id, 0, 7_200)));
+	program_test.add_account(payroll_batch_pubkey, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, 1)));
+
+	// Paid by a batch that is still open
+	let (open_batch_session_pda, _open_batch_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			1u64.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+	let mut open_batch_session = work_session_state(employee_pda, authority_pubkey, 1, 0, 3_600);
+	open_batch_session.is_paid = true;
+	open_batch_session.batch = payroll_batch_pubkey;
+	program_test.add_account(open_batch_session_pda, program_account(&open_batch_session));
+
+	// A credit that can still be clawed back
+	let (reversible_session_pda, _reversible_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			2u64.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+	let mut reversible_session = work_session_state(employee_pda, authority_pubkey, 2, 0, 3_600);
+	reversible_session.is_paid = true;
+	reversible_session.credited = 1_000;
+	reversible_session.payment.reversible_until = i64::MAX;
+	program_test.add_account(reversible_session_pda, program_account(&reversible_session));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::initialize_session_tree_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		merkle_tree_pubkey,
+		tree_authority_pda,
+		compression_program_pubkey,
+		noop_pubkey,
+		max_depth,
+		max_buffer_size,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+	let compress_ix = |work_session: Pubkey, payroll_batch: Option<Pubkey>, session_id: u64| employee_payroll_ix_interface::compress_work_session_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		work_session,
+		payroll_batch,
+		authority_pubkey,
+		merkle_tree_pubkey,
+		tree_authority_pda,
+		compression_program_pubkey,
+		noop_pubkey,
+		employee_wallet,
+		session_id,
+		recent_blockhash,
+	);
+
+	// The batch has to be passed, and has to be closed
+	let result = banks_client.process_transaction(compress_ix(open_batch_session_pda, None, 1)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(compress_ix(open_batch_session_pda, Some(payroll_batch_pubkey), 1)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(compress_ix(reversible_session_pda, None, 2)).await;
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::compress_work_session_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		work_session_pda,
+		None,
+		authority_pubkey,
+		merkle_tree_pubkey,
+		tree_authority_pda,
+		compression_program_pubkey,
+		noop_pubkey,
+		employee_wallet,
+		session_id,
+		banks_client.get_latest_blockhash().await.unwrap(),
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(banks_client.get_account(work_session_pda).await.unwrap().is_none());
+
+	let employer_account = banks_client.get_account(employer_pubkey).await.unwrap().unwrap();
+	let employer = Employer::try_deserialize(&mut employer_account.data.as_slice()).unwrap();
+	assert_eq!(employer.compressed_session_count, 1);
+	assert!(banks_client.get_account(open_batch_session_pda).await.unwrap().is_some());
+	assert!(banks_client.get_account(reversible_session_pda).await.unwrap().is_some());
+}
@@ -0,0 +1,210 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		payroll_batch_state,
+		employee_state,
+		work_session_state,
+		set_clock,
+	},
+    employee_payroll::WorkSession,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn crank_payroll_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let crank_tip: u64 = 5_000;
+	let pay_date: i64 = 1_700_000_000;
+
+	// KEYPAIR
+	let cranker_keypair = Keypair::new();
+
+	// PUBKEY
+	let cranker_pubkey = cranker_keypair.pubkey();
+	let authority_pubkey = Pubkey::new_unique();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+	let unattached_session_pubkey = Pubkey::new_unique();
+	let unapproved_batch_id: u64 = 1;
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (unapproved_batch_pda, _unapproved_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			unapproved_batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		cranker_pubkey,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.crank_tip = crank_tip;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, authority_pubkey, batch_id);
+	payroll_batch.period_end = pay_date;
+	payroll_batch.pay_date = pay_date;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+	program_test.add_account(unapproved_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, unapproved_batch_id)));
+
+	let mut work_session = work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600);
+	work_session.batch = payroll_batch_pda;
+	work_session.is_approved = true;
+	program_test.add_account(work_session_pubkey, program_account(&work_session));
+	program_test.add_account(unattached_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 1, 3_600, 7_200)));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate)));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, pay_date - 60).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let crank_ix = |payroll_batch: Pubkey, batch_id: u64, work_session: Pubkey, recent_blockhash| employee_payroll_ix_interface::crank_payroll_ix_setup(
+		&cranker_keypair,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch,
+		employee_pda,
+		work_session,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		recent_blockhash,
+	);
+
+	// Before the pay date
+	let result = context.banks_client.process_transaction(crank_ix(payroll_batch_pda, batch_id, work_session_pubkey, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	set_clock(&mut context, pay_date).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	// Batches nobody approved cannot be cranked
+	let result = context.banks_client.process_transaction(crank_ix(unapproved_batch_pda, unapproved_batch_id, work_session_pubkey, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Only sessions a manager attached to the batch are paid by a crank
+	let result = context.banks_client.process_transaction(crank_ix(payroll_batch_pda, batch_id, unattached_session_pubkey, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = context.banks_client.process_transaction(crank_ix(payroll_batch_pda, batch_id, work_session_pubkey, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	assert_eq!(context.banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate);
+	// The tip leaves the vault alongside the pay
+	assert_eq!(context.banks_client.get_balance(vault_pda).await.unwrap(), 10_000_000_000 - hourly_rate - crank_tip);
+
+	let work_session_account = context.banks_client.get_account(work_session_pubkey).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert!(work_session.is_paid);
+	assert_eq!(work_session.batch, payroll_batch_pda);
+}
@@ -0,0 +1,133 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+	},
+    employee_payroll::{DeductionKind, DeductionRule, TaxBracket},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn create_deduction_rule_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let rule_id: u64 = Default::default();
+	let name: String = String::from("Federal income tax");
+	let kind = DeductionKind::Tiered {
+		brackets: vec![
+			TaxBracket { threshold: 0, bps: 1_000 },
+			TaxBracket { threshold: 1_000_000, bps: 2_200 },
+		],
+	};
+	let remittance_vault: Pubkey = Pubkey::new_unique();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (deduction_rule_pda, _deduction_rule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"deduction_rule",
+			employer_pubkey.as_ref(),
+			rule_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	// Brackets must be sorted by threshold
+	let unsorted = DeductionKind::Tiered {
+		brackets: vec![
+			TaxBracket { threshold: 1_000_000, bps: 2_200 },
+			TaxBracket { threshold: 0, bps: 1_000 },
+		],
+	};
+
+	let ix = employee_payroll_ix_interface::create_deduction_rule_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		deduction_rule_pda,
+		system_program_pubkey,
+		rule_id,
+		&name,
+		&unsorted,
+		remittance_vault,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::create_deduction_rule_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		deduction_rule_pda,
+		system_program_pubkey,
+		rule_id,
+		&name,
+		&kind,
+		remittance_vault,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let deduction_rule_account = banks_client.get_account(deduction_rule_pda).await.unwrap().unwrap();
+	let deduction_rule = DeductionRule::try_deserialize(&mut deduction_rule_account.data.as_slice()).unwrap();
+	assert_eq!(deduction_rule.employer, employer_pubkey);
+	assert_eq!(deduction_rule.kind, kind);
+	assert_eq!(deduction_rule.remittance_vault, remittance_vault);
+}
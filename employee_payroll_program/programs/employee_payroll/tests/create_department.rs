@@ -0,0 +1,130 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+	},
+    employee_payroll::Department,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn create_department_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let department_id: u64 = 7;
+	let name = "Engineering".to_string();
+	let budget_per_period: u64 = 50_000_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let stranger_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let stranger_pubkey = stranger_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (department_pda, _department_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"department",
+			employer_pubkey.as_ref(),
+			department_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		stranger_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::create_department_ix_setup(
+		&stranger_keypair,
+		employer_pubkey,
+		department_pda,
+		system_program_pubkey,
+		department_id,
+		name.clone(),
+		budget_per_period,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::create_department_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		department_pda,
+		system_program_pubkey,
+		department_id,
+		name.clone(),
+		budget_per_period,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let department_account = banks_client.get_account(department_pda).await.unwrap().unwrap();
+	let department = Department::try_deserialize(&mut department_account.data.as_slice()).unwrap();
+	assert_eq!(department.employer, employer_pubkey);
+	assert_eq!(department.department_id, department_id);
+	assert_eq!(department.name, name);
+	assert_eq!(department.budget_per_period, budget_per_period);
+	assert_eq!(department.period_spent, 0);
+}
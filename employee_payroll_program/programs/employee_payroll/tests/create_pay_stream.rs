@@ -0,0 +1,125 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+	},
+    employee_payroll::PayStream,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn create_pay_stream_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	let rate_per_second: u64 = 1_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let rate_approver_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let rate_approver_pubkey = rate_approver_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (pay_stream_pda, _pay_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pay_stream",
+			employee_pda.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.rate_approver = rate_approver_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 0)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let stream_ix = |rate_approver, rate_per_second| employee_payroll_ix_interface::create_pay_stream_ix_setup(
+		&authority_keypair,
+		rate_approver,
+		employer_pubkey,
+		employee_pda,
+		pay_stream_pda,
+		system_program_pubkey,
+		employee_wallet,
+		rate_per_second,
+		recent_blockhash,
+	);
+
+	// A stream has to pay something
+	let result = banks_client.process_transaction(stream_ix(&rate_approver_keypair, 0)).await;
+	assert!(result.is_err());
+
+	// The employer owner cannot set a pay rate on its own
+	let result = banks_client.process_transaction(stream_ix(&authority_keypair, rate_per_second)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(stream_ix(&rate_approver_keypair, rate_per_second)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let pay_stream_account = banks_client.get_account(pay_stream_pda).await.unwrap().unwrap();
+	let pay_stream = PayStream::try_deserialize(&mut pay_stream_account.data.as_slice()).unwrap();
+	assert_eq!(pay_stream.employee, employee_pda);
+	assert_eq!(pay_stream.rate_per_second, rate_per_second);
+	assert_eq!(pay_stream.last_accrual_at, pay_stream.started_at);
+	assert_eq!(pay_stream.accrued, 0);
+}
@@ -5,6 +5,8 @@ use {
     common::{
 		get_program_test,
 		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
 	},
     solana_program_test::tokio,
     solana_sdk::{
@@ -44,11 +46,13 @@ async fn create_payroll_batch_ix_success() {
 
 	// EXECUTABLE PUBKEY
 	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+	let employer_pubkey = Pubkey::new_unique();
 
 	// PDA
 	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"payroll_batch",
+			employer_pubkey.as_ref(),
 			batch_id.to_le_bytes().as_ref(),
 		],
 		&employee_payroll::ID,
@@ -66,11 +70,15 @@ async fn create_payroll_batch_ix_success() {
 		},
 	);
 
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
 	let ix = employee_payroll_ix_interface::create_payroll_batch_ix_setup(
 		&authority_keypair,
+		employer_pubkey,
 		payroll_batch_pda,
 		system_program_pubkey,
 		batch_id,
@@ -0,0 +1,111 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+	},
+    employee_payroll::Project,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn create_project_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let project_id: u64 = 3;
+	let name = "Website redesign".to_string();
+	let bill_rate: u64 = 90_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (project_pda, _project_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"project",
+			employer_pubkey.as_ref(),
+			project_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let create_ix = |project_id: u64| employee_payroll_ix_interface::create_project_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		project_pda,
+		system_program_pubkey,
+		project_id,
+		name.clone(),
+		bill_rate,
+		recent_blockhash,
+	);
+
+	// Project id 0 stands for no project
+	let result = banks_client.process_transaction(create_ix(0)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(create_ix(project_id)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let project_account = banks_client.get_account(project_pda).await.unwrap().unwrap();
+	let project = Project::try_deserialize(&mut project_account.data.as_slice()).unwrap();
+	assert_eq!(project.employer, employer_pubkey);
+	assert_eq!(project.project_id, project_id);
+	assert_eq!(project.name, name);
+	assert_eq!(project.bill_rate, bill_rate);
+	assert!(project.is_active);
+}
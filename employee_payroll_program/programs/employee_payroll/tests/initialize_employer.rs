@@ -6,6 +6,8 @@ use {
 		get_program_test,
 		employee_payroll_ix_interface,
 	},
+    employee_payroll::Employer,
+    anchor_lang::AccountDeserialize,
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -37,6 +39,7 @@ async fn initialize_employer_ix_success() {
 	let employer_keypair = Keypair::new();
 
 	// PUBKEY
+	let rate_approver_pubkey = Pubkey::new_unique();
 	let authority_pubkey = authority_keypair.pubkey();
 	let employer_pubkey = employer_keypair.pubkey();
 
@@ -58,7 +61,7 @@ async fn initialize_employer_ix_success() {
 	program_test.add_account(
 		authority_pubkey,
 		Account {
-			lamports: 0,
+			lamports: 1_000_000_000_000,
 			data: vec![],
 			owner: system_program::ID,
 			executable: false,
@@ -80,16 +83,33 @@ async fn initialize_employer_ix_success() {
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
+	// The owner cannot approve their own raises
 	let ix = employee_payroll_ix_interface::initialize_employer_ix_setup(
 		&authority_keypair,
 		&employer_keypair,
 		system_program_pubkey,
+		authority_pubkey,
 		recent_blockhash,
 	);
 
 	let result = banks_client.process_transaction(ix).await;
 
 	// ASSERTIONS
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::initialize_employer_ix_setup(
+		&authority_keypair,
+		&employer_keypair,
+		system_program_pubkey,
+		rate_approver_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
 	assert!(result.is_ok());
 
+	let employer_account = banks_client.get_account(employer_pubkey).await.unwrap().unwrap();
+	let employer = Employer::try_deserialize(&mut employer_account.data.as_slice()).unwrap();
+	assert_eq!(employer.rate_approver, rate_approver_pubkey);
+
 }
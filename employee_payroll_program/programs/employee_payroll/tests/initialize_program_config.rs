@@ -0,0 +1,93 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn initialize_program_config_ix_rejects_non_upgrade_authority() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let guardian: Pubkey = Pubkey::new_unique();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+	let bpf_loader_upgradeable_pubkey = Pubkey::from_str("BPFLoaderUpgradeab1e11111111111111111111111").unwrap();
+
+	// PDA
+	let (program_config_pda, _program_config_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"program_config",
+		],
+		&employee_payroll::ID,
+	);
+
+	let (program_data_pda, _program_data_pda_bump) = Pubkey::find_program_address(
+		&[
+			employee_payroll::ID.as_ref(),
+		],
+		&bpf_loader_upgradeable_pubkey,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::initialize_program_config_ix_setup(
+		&authority_keypair,
+		program_config_pda,
+		employee_payroll::ID,
+		program_data_pda,
+		system_program_pubkey,
+		guardian,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	// Only the program upgrade authority may install the guardian
+	assert!(result.is_err());
+}
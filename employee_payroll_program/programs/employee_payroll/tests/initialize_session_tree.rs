@@ -0,0 +1,106 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		session_tree_account,
+	},
+    employee_payroll::Employer,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn initialize_session_tree_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let max_depth: u32 = 14;
+	let max_buffer_size: u32 = 64;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let merkle_tree_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let compression_program_pubkey = Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap();
+	let noop_pubkey = Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap();
+
+	// PDA
+	let (tree_authority_pda, _tree_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"session_tree",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(merkle_tree_pubkey, session_tree_account(max_depth, max_buffer_size));
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::initialize_session_tree_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		merkle_tree_pubkey,
+		tree_authority_pda,
+		compression_program_pubkey,
+		noop_pubkey,
+		max_depth,
+		max_buffer_size,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let employer_account = banks_client.get_account(employer_pubkey).await.unwrap().unwrap();
+	let employer = Employer::try_deserialize(&mut employer_account.data.as_slice()).unwrap();
+	assert_eq!(employer.session_tree, merkle_tree_pubkey);
+	assert_eq!(employer.compressed_session_count, 0);
+}
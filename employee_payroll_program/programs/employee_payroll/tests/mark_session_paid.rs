@@ -5,6 +5,12 @@ use {
     common::{
 		get_program_test,
 		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		employee_state,
+		work_session_state,
+		payroll_batch_state,
 	},
     solana_program_test::tokio,
     solana_sdk::{
@@ -35,6 +41,8 @@ async fn mark_session_paid_ix_success() {
 	// DATA
 	let employee_wallet: Pubkey = Pubkey::default();
 	let session_id: u64 = Default::default();
+	let checked_in_session_id: u64 = 1;
+	let batch_session_id: u64 = 2;
 
 	// KEYPAIR
 	let authority_keypair = Keypair::new();
@@ -43,6 +51,14 @@ async fn mark_session_paid_ix_success() {
 	let authority_pubkey = authority_keypair.pubkey();
 
 	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
 	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"work_session",
@@ -52,6 +68,17 @@ async fn mark_session_paid_ix_success() {
 		&employee_payroll::ID,
 	);
 
+	let work_session_pda_at = |session_id: u64| Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	).0;
+	let checked_in_session_pda = work_session_pda_at(checked_in_session_id);
+	let batch_session_pda = work_session_pda_at(batch_session_id);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		authority_pubkey,
@@ -64,12 +91,34 @@ async fn mark_session_paid_ix_success() {
 		},
 	);
 
+	// PROGRAM STATE
+	let employer_pubkey = Pubkey::new_unique();
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000)));
+	program_test.add_account(work_session_pda, program_account(&work_session_state(employee_pda, authority_pubkey, session_id, 0, 3_600)));
+	program_test.add_account(checked_in_session_pda, program_account(&work_session_state(employee_pda, authority_pubkey, checked_in_session_id, 3_600, 0)));
+
+	let payroll_batch_pubkey = Pubkey::new_unique();
+	program_test.add_account(payroll_batch_pubkey, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, 7)));
+
+	let mut batch_session = work_session_state(employee_pda, authority_pubkey, batch_session_id, 0, 3_600);
+	batch_session.batch = payroll_batch_pubkey;
+	program_test.add_account(batch_session_pda, program_account(&batch_session));
+
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
 	let ix = employee_payroll_ix_interface::mark_session_paid_ix_setup(
 		&authority_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
 		work_session_pda,
+		None,
 		employee_wallet,
 		session_id,
 		recent_blockhash,
@@ -80,4 +129,38 @@ async fn mark_session_paid_ix_success() {
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	// A session still checked in has nothing to pay yet
+	let ix = employee_payroll_ix_interface::mark_session_paid_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		checked_in_session_pda,
+		None,
+		employee_wallet,
+		checked_in_session_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	// A session attached to a batch is only marked paid against that batch
+	let batch_session_ix = |payroll_batch| employee_payroll_ix_interface::mark_session_paid_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		batch_session_pda,
+		payroll_batch,
+		employee_wallet,
+		batch_session_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(batch_session_ix(None)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(batch_session_ix(Some(payroll_batch_pubkey))).await;
+	assert!(result.is_ok());
 }
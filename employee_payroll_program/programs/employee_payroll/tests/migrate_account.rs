@@ -0,0 +1,227 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+	},
+    employee_payroll::{Compensation, Employee, PaymentRecord, PayrollBatch, WorkSession},
+    anchor_lang::{AccountDeserialize, Discriminator, Space},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+// Original layouts, written out field by field so that a change to the
+// on-chain structs cannot silently change what these fixtures contain.
+
+// Allocated with a fixed 205 bytes and without an employer. Names and
+// positions were not bounded then.
+fn employee_v0_fixture(authority: Pubkey, employee_wallet: Pubkey, name: &str) -> Vec<u8> {
+	let mut data = Employee::DISCRIMINATOR.to_vec();
+	for text in [name, "Clerk"] {
+		data.extend_from_slice(&(text.len() as u32).to_le_bytes());
+		data.extend_from_slice(text.as_bytes());
+	}
+	data.extend_from_slice(&1_000u64.to_le_bytes()); // hourly_rate
+	data.extend_from_slice(&7_200u64.to_le_bytes()); // total_hours_worked
+	data.extend_from_slice(&2_000u64.to_le_bytes()); // total_paid
+	data.extend_from_slice(authority.as_ref());
+	data.extend_from_slice(employee_wallet.as_ref());
+	data.push(1); // is_active
+	data.resize(205, 0);
+	data
+}
+
+fn work_session_v0_fixture(employee: Pubkey, authority: Pubkey, session_id: u64) -> Vec<u8> {
+	let mut data = WorkSession::DISCRIMINATOR.to_vec();
+	data.extend_from_slice(employee.as_ref());
+	data.extend_from_slice(&0i64.to_le_bytes()); // check_in_time
+	data.extend_from_slice(&3_600i64.to_le_bytes()); // check_out_time
+	data.extend_from_slice(&3_600u64.to_le_bytes()); // duration
+	data.push(1); // is_paid
+	data.extend_from_slice(authority.as_ref());
+	data.extend_from_slice(&session_id.to_le_bytes());
+	assert_eq!(data.len(), 105);
+	data
+}
+
+// Not tied to an employer either.
+fn payroll_batch_v0_fixture(authority: Pubkey, batch_id: u64) -> Vec<u8> {
+	let mut data = PayrollBatch::DISCRIMINATOR.to_vec();
+	data.extend_from_slice(&batch_id.to_le_bytes());
+	data.extend_from_slice(&42_000u64.to_le_bytes()); // total_amount
+	data.extend_from_slice(&1_700_000_000i64.to_le_bytes()); // created_at
+	data.extend_from_slice(&0i64.to_le_bytes()); // processed_at
+	data.push(0); // is_processed
+	data.extend_from_slice(authority.as_ref());
+	assert_eq!(data.len(), 73);
+	data
+}
+
+#[tokio::test]
+async fn migrate_account_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	// 69 bytes, cut at the last character boundary within 64 bytes
+	let long_name = format!("x{}", "Ð".repeat(34));
+	let session_id: u64 = 7;
+	let batch_id: u64 = 3;
+
+	// KEYPAIR
+	let payer_keypair = Keypair::new();
+
+	// PUBKEY
+	// The employer owner pays to migrate its employees and payroll batches
+	let payer_pubkey = payer_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let other_employer_pubkey = Pubkey::new_unique();
+	let employee_pubkey = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+	let payroll_batch_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(payer_pubkey)));
+	program_test.add_account(other_employer_pubkey, program_account(&employer_state(Pubkey::new_unique())));
+
+	let fixtures = [
+		(employee_pubkey, employee_v0_fixture(payer_pubkey, employee_wallet, &long_name)),
+		(work_session_pubkey, work_session_v0_fixture(employee_pubkey, payer_pubkey, session_id)),
+		(payroll_batch_pubkey, payroll_batch_v0_fixture(payer_pubkey, batch_id)),
+	];
+	for (pubkey, data) in fixtures {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: Rent::default().minimum_balance(data.len()),
+				data,
+				owner: employee_payroll::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let migrate_ix = |account, employer| employee_payroll_ix_interface::migrate_account_ix_setup(
+		&payer_keypair,
+		account,
+		system_program_pubkey,
+		employer,
+		recent_blockhash,
+	);
+
+	// Employees and payroll batches need an employer of their authority to belong to
+	let result = banks_client.process_transaction(migrate_ix(employee_pubkey, None)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(migrate_ix(payroll_batch_pubkey, Some(other_employer_pubkey))).await;
+	assert!(result.is_err());
+
+	for (pubkey, employer) in [
+		(employee_pubkey, Some(employer_pubkey)),
+		(work_session_pubkey, None),
+		(payroll_batch_pubkey, Some(employer_pubkey)),
+	] {
+		let result = banks_client.process_transaction(migrate_ix(pubkey, employer)).await;
+		assert!(result.is_ok());
+	}
+
+	// ASSERTIONS
+	let employee_account = banks_client.get_account(employee_pubkey).await.unwrap().unwrap();
+	assert_eq!(employee_account.data.len(), 8 + Employee::INIT_SPACE);
+	assert!(employee_account.lamports >= Rent::default().minimum_balance(employee_account.data.len()));
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.name, format!("x{}", "Ð".repeat(31)));
+	assert_eq!(employee.position, "Clerk");
+	assert_eq!(employee.hourly_rate, 1_000);
+	assert_eq!(employee.total_hours_worked, 7_200);
+	assert_eq!(employee.total_paid, 2_000);
+	assert_eq!(employee.authority, payer_pubkey);
+	assert_eq!(employee.employee_wallet, employee_wallet);
+	assert_eq!(employee.payout_wallet, employee_wallet);
+	assert_eq!(employee.pending_payout_wallet, None);
+	assert_eq!(employee.employer, employer_pubkey);
+	assert_eq!(employee.compensation, Compensation::Hourly);
+	assert_eq!(employee.version, Employee::VERSION);
+	assert!(employee.deduction_rules.is_empty());
+	assert!(employee.payout_splits.is_empty());
+
+	let work_session_account = banks_client.get_account(work_session_pubkey).await.unwrap().unwrap();
+	assert_eq!(work_session_account.data.len(), 8 + WorkSession::INIT_SPACE);
+	assert!(work_session_account.lamports >= Rent::default().minimum_balance(work_session_account.data.len()));
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert_eq!(work_session.employee, employee_pubkey);
+	assert_eq!(work_session.duration, 3_600);
+	assert!(work_session.is_paid);
+	assert_eq!(work_session.session_id, session_id);
+	assert_eq!(work_session.batch, Pubkey::default());
+	assert_eq!(work_session.rent_payer, payer_pubkey);
+	assert_eq!(work_session.payment, PaymentRecord::default());
+	assert_eq!(work_session.version, WorkSession::VERSION);
+
+	let payroll_batch_account = banks_client.get_account(payroll_batch_pubkey).await.unwrap().unwrap();
+	assert_eq!(payroll_batch_account.data.len(), 8 + PayrollBatch::INIT_SPACE);
+	let payroll_batch = PayrollBatch::try_deserialize(&mut payroll_batch_account.data.as_slice()).unwrap();
+	assert_eq!(payroll_batch.batch_id, batch_id);
+	assert_eq!(payroll_batch.total_amount, 42_000);
+	assert_eq!(payroll_batch.created_at, 1_700_000_000);
+	assert_eq!(payroll_batch.authority, payer_pubkey);
+	assert_eq!(payroll_batch.employer, employer_pubkey);
+	assert!(!payroll_batch.has_pay_period());
+	assert_eq!(payroll_batch.version, PayrollBatch::VERSION);
+
+	// Migrated accounts are left alone
+	let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+	for (pubkey, employer) in [(employee_pubkey, Some(employer_pubkey)), (work_session_pubkey, None)] {
+		let ix = employee_payroll_ix_interface::migrate_account_ix_setup(
+			&payer_keypair,
+			pubkey,
+			system_program_pubkey,
+			employer,
+			recent_blockhash,
+		);
+
+		let result = banks_client.process_transaction(ix).await;
+		assert!(result.is_err());
+	}
+}
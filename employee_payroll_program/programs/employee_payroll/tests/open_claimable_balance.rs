@@ -0,0 +1,107 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employee_state,
+	},
+    employee_payroll::ClaimableBalance,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn open_claimable_balance_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// KEYPAIR
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (claimable_balance_pda, _claimable_balance_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"claimable_balance",
+			employee_pda.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(
+		employee_pda,
+		program_account(&employee_state(employer_pubkey, Pubkey::new_unique(), employee_wallet, 1_000)),
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::open_claimable_balance_ix_setup(
+		&employee_wallet_keypair,
+		employee_pda,
+		claimable_balance_pda,
+		system_program_pubkey,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let claimable_balance_account = banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.employer, employer_pubkey);
+	assert_eq!(claimable_balance.employee, employee_pda);
+	assert_eq!(claimable_balance.amount, 0);
+	assert_eq!(claimable_balance.version, ClaimableBalance::VERSION);
+}
@@ -0,0 +1,128 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		set_clock,
+	},
+    employee_payroll::{Employer, PayFrequency, PayrollBatch},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn open_next_pay_period_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	// 2024-02-16 and 2024-03-01, midnight UTC
+	let period_start: i64 = 1_708_041_600;
+	let period_end: i64 = 1_709_251_200;
+
+	// KEYPAIR
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let batch_id = Employer::pay_period_batch_id(&employer_pubkey, period_start);
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(Pubkey::new_unique());
+	employer.payroll_manager = payroll_manager_pubkey;
+	employer.pay_frequency = Some(PayFrequency::SemiMonthly);
+	employer.next_pay_period_start = period_start;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	// The period has to have started
+	set_clock(&mut context, period_start - 86_400).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = employee_payroll_ix_interface::open_next_pay_period_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		system_program_pubkey,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_err());
+
+	set_clock(&mut context, period_start + 4 * 86_400).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = employee_payroll_ix_interface::open_next_pay_period_ix_setup(
+		&payroll_manager_keypair,
+		employer_pubkey,
+		payroll_batch_pda,
+		system_program_pubkey,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let payroll_batch_account = context.banks_client.get_account(payroll_batch_pda).await.unwrap().unwrap();
+	let payroll_batch = PayrollBatch::try_deserialize(&mut payroll_batch_account.data.as_slice()).unwrap();
+	assert_eq!(payroll_batch.batch_id, batch_id);
+	assert_eq!(payroll_batch.employer, employer_pubkey);
+	assert_eq!(payroll_batch.period_start, period_start);
+	assert_eq!(payroll_batch.period_end, period_end);
+
+	let employer_account = context.banks_client.get_account(employer_pubkey).await.unwrap().unwrap();
+	let employer = Employer::try_deserialize(&mut employer_account.data.as_slice()).unwrap();
+	assert_eq!(employer.next_pay_period_start, period_end);
+	assert!(employer.pay_period_opened);
+}
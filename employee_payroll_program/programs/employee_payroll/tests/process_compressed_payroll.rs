@@ -0,0 +1,245 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		employee_state,
+		payroll_batch_state,
+		work_session_state,
+		session_tree_account,
+		first_leaf_proof,
+	},
+    employee_payroll::CompressedWorkSession,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn process_compressed_payroll_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	let session_id: u64 = Default::default();
+	let batch_id: u64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let max_depth: u32 = 14;
+	let max_buffer_size: u32 = 64;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let merkle_tree_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let compression_program_pubkey = Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap();
+	let noop_pubkey = Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap();
+	let system_program_pubkey = system_program::ID;
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (tree_authority_pda, _tree_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"session_tree",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(merkle_tree_pubkey, session_tree_account(max_depth, max_buffer_size));
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+	// Raised since the session was checked out, which is still paid at the old rate
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 3 * hourly_rate)));
+	let mut work_session = work_session_state(employee_pda, authority_pubkey, session_id, 0, 7_200);
+	work_session.hourly_rate = hourly_rate;
+	program_test.add_account(work_session_pda, program_account(&work_session));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::initialize_session_tree_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		merkle_tree_pubkey,
+		tree_authority_pda,
+		compression_program_pubkey,
+		noop_pubkey,
+		max_depth,
+		max_buffer_size,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let ix = employee_payroll_ix_interface::compress_work_session_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		work_session_pda,
+		None,
+		authority_pubkey,
+		merkle_tree_pubkey,
+		tree_authority_pda,
+		compression_program_pubkey,
+		noop_pubkey,
+		employee_wallet,
+		session_id,
+		banks_client.get_latest_blockhash().await.unwrap(),
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let session = CompressedWorkSession {
+		employer: employer_pubkey,
+		employee: employee_pda,
+		session_id,
+		check_in_time: 0,
+		check_out_time: 7_200,
+		duration: 7_200,
+		is_paid: false,
+		batch: Pubkey::default(),
+		hourly_rate,
+	};
+	let (root, proof) = first_leaf_proof(session.leaf(), max_depth);
+
+	let pay_ix = |recent_blockhash| employee_payroll_ix_interface::process_compressed_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		merkle_tree_pubkey,
+		tree_authority_pda,
+		compression_program_pubkey,
+		noop_pubkey,
+		system_program_pubkey,
+		&[],
+		&[],
+		&proof,
+		batch_id,
+		root,
+		&session,
+		0,
+		recent_blockhash,
+	);
+
+	let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(pay_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + 2 * hourly_rate);
+
+	// The leaf is now marked paid, so the same proof cannot pay it again
+	let recent_blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap();
+	let result = banks_client.process_transaction(pay_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+}
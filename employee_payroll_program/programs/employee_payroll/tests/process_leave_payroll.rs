@@ -0,0 +1,186 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		payroll_batch_state,
+		employee_state,
+	},
+    employee_payroll::{Employee, PayrollBatch},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn process_leave_payroll_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	// A whole period of leave, with no work session to pay it with
+	let unpaid_leave: u64 = 80 * 3_600;
+	let leave_pay: u64 = 80 * hourly_rate;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let other_employee_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.unpaid_leave = unpaid_leave;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let other_employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	program_test.add_account(other_employee_pubkey, program_account(&other_employee));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let leave_ix = |employee: Pubkey, recent_blockhash| employee_payroll_ix_interface::process_leave_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee,
+		None,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// An employee without approved leave has nothing to be paid
+	let result = banks_client.process_transaction(leave_ix(other_employee_pubkey, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(leave_ix(employee_pda, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + leave_pay);
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, leave_pay);
+	assert_eq!(employee.period_to_date.gross, leave_pay);
+	assert_eq!(employee.unpaid_leave, 0);
+
+	let payroll_batch_account = banks_client.get_account(payroll_batch_pda).await.unwrap().unwrap();
+	let payroll_batch = PayrollBatch::try_deserialize(&mut payroll_batch_account.data.as_slice()).unwrap();
+	assert_eq!(payroll_batch.total_amount, leave_pay);
+
+	// The leave is paid once
+	let recent_blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap();
+	let result = banks_client.process_transaction(leave_ix(employee_pda, recent_blockhash)).await;
+	assert!(result.is_err());
+}
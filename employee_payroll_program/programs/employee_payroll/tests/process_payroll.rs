@@ -5,7 +5,25 @@ use {
     common::{
 		get_program_test,
 		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		payroll_batch_state,
+		employee_state,
+		work_session_state,
+		deduction_rule_state,
+		pay_item_state,
+		expense_state,
+		claimable_balance_state,
+		department_state,
+		project_state,
+		set_clock,
+		add_price_oracle,
+		price_feed_message,
+		price_oracle_ix_interface,
 	},
+    employee_payroll::{ClaimableBalance, CreditLock, DeductionKind, Department, Employee, Expense, ExpenseStatus, PayFrequency, PayItem, PayItemKind, PayoutMode, PayoutSplit, Project, TaxBracket, WorkSession, DEFAULT_MAX_PRICE_AGE},
+    anchor_lang::AccountDeserialize,
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -35,26 +53,276 @@ async fn process_payroll_ix_success() {
 	// DATA
 	let batch_id: u64 = Default::default();
 	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
 
 	// KEYPAIR
 	let authority_keypair = Keypair::new();
-	let employee_keypair = Keypair::new();
-	let work_session_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
 
 	// PUBKEY
 	let authority_pubkey = authority_keypair.pubkey();
-	let employee_pubkey = employee_keypair.pubkey();
-	let work_session_pubkey = work_session_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let work_session_pubkeys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	employer.daily_limit = 3 * hourly_rate / 2;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate)));
+
+	for (session_id, work_session_pubkey) in work_session_pubkeys.iter().enumerate() {
+		program_test.add_account(
+			*work_session_pubkey,
+			program_account(&work_session_state(employee_pda, authority_pubkey, session_id as u64, 0, 3_600)),
+		);
+	}
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
+		&payroll_manager_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkeys[0],
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// A second hour of pay exceeds the daily limit without the owner
+	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
+		&payroll_manager_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkeys[1],
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
+		&payroll_manager_keypair,
+		Some(&authority_keypair),
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkeys[1],
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn process_payroll_ix_deductions() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let kinds = [
+		DeductionKind::Flat { amount: 10_000 },
+		DeductionKind::Percentage { bps: 1_000 },
+		DeductionKind::Tiered {
+			brackets: vec![
+				TaxBracket { threshold: 0, bps: 0 },
+				TaxBracket { threshold: 500_000, bps: 2_000 },
+			],
+		},
+	];
+	// One hour of pay, less 10_000 flat, 10% and 20% of everything above 500_000
+	let withheld = [10_000, 100_000, 100_000];
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+	let remittance_vaults = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
 
 	// PDA
 	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"payroll_batch",
+			employer_pubkey.as_ref(),
 			batch_id.to_le_bytes().as_ref(),
 		],
 		&employee_payroll::ID,
 	);
 
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		authority_pubkey,
@@ -67,14 +335,112 @@ async fn process_payroll_ix_success() {
 		},
 	);
 
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	for remittance_vault in remittance_vaults {
+		program_test.add_account(
+			remittance_vault,
+			Account {
+				lamports: 1_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut deductions = vec![];
+	for (rule_id, (kind, remittance_vault)) in kinds.into_iter().zip(remittance_vaults).enumerate() {
+		let (deduction_rule_pda, deduction_rule) = deduction_rule_state(employer_pubkey, rule_id as u64, kind, remittance_vault);
+		program_test.add_account(deduction_rule_pda, program_account(&deduction_rule));
+		deductions.push((deduction_rule_pda, remittance_vault));
+	}
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.deduction_rules = deductions.iter().map(|(deduction_rule, _)| *deduction_rule).collect();
+	employee.unpaid_sessions = 1;
+	program_test.add_account(employee_pda, program_account(&employee));
+
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
+	// Leaving out a rule is rejected
+	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&deductions[..2],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
 	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
 		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
 		payroll_batch_pda,
-		employee_pubkey,
+		employee_pda,
 		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&deductions,
+		&[],
+		&[],
+		&[],
 		batch_id,
 		timestamp,
 		recent_blockhash,
@@ -85,4 +451,1952 @@ async fn process_payroll_ix_success() {
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	let total_withheld: u64 = withheld.iter().sum();
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate - total_withheld);
+	for (remittance_vault, amount) in remittance_vaults.into_iter().zip(withheld) {
+		assert_eq!(banks_client.get_balance(remittance_vault).await.unwrap(), 1_000_000_000 + amount);
+	}
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, hourly_rate);
+	assert_eq!(employee.unpaid_sessions, 0);
+	assert_eq!(employee.ytd.gross, hourly_rate);
+	assert_eq!(employee.ytd.net, hourly_rate - total_withheld);
+	assert_eq!(employee.ytd.deductions, total_withheld);
+	assert_eq!(employee.period_to_date, employee.ytd);
+}
+
+
+#[tokio::test]
+async fn process_payroll_ix_period_deductions() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let period_start: i64 = 1_708_041_600;
+	let paid_before: u64 = 400_000;
+	let kinds = [
+		DeductionKind::Flat { amount: 10_000 },
+		DeductionKind::Percentage { bps: 1_000 },
+		DeductionKind::Tiered {
+			brackets: vec![
+				TaxBracket { threshold: 0, bps: 0 },
+				TaxBracket { threshold: 500_000, bps: 2_000 },
+			],
+		},
+	];
+	// The flat amount was taken from the period's earlier payout, and only
+	// the 900_000 of the period's 1_400_000 above 500_000 is taxed at 20%
+	let withheld = [0, 100_000, 180_000];
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+	let remittance_vaults = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	for remittance_vault in remittance_vaults {
+		program_test.add_account(
+			remittance_vault,
+			Account {
+				lamports: 1_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.pay_frequency = Some(PayFrequency::Biweekly);
+	employer.next_pay_period_start = period_start;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut deductions = vec![];
+	for (rule_id, (kind, remittance_vault)) in kinds.into_iter().zip(remittance_vaults).enumerate() {
+		let (deduction_rule_pda, deduction_rule) = deduction_rule_state(employer_pubkey, rule_id as u64, kind, remittance_vault);
+		program_test.add_account(deduction_rule_pda, program_account(&deduction_rule));
+		deductions.push((deduction_rule_pda, remittance_vault));
+	}
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.deduction_rules = deductions.iter().map(|(deduction_rule, _)| *deduction_rule).collect();
+	employee.period_to_date.gross = paid_before;
+	employee.period_start = period_start;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, period_start + 3 * 86_400).await;
+	let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+
+	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&deductions[..2],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&deductions,
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let total_withheld: u64 = withheld.iter().sum();
+	assert_eq!(context.banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate - total_withheld);
+	for (remittance_vault, amount) in remittance_vaults.into_iter().zip(withheld) {
+		assert_eq!(context.banks_client.get_balance(remittance_vault).await.unwrap(), 1_000_000_000 + amount);
+	}
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.period_to_date.gross, paid_before + hourly_rate);
+	assert_eq!(employee.period_to_date.deductions, total_withheld);
+}
+
+
+
+#[tokio::test]
+async fn process_payroll_ix_pay_items() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let outstanding_adjustment: u64 = 200_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.outstanding_adjustment = outstanding_adjustment;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (bonus_pda, bonus) = pay_item_state(employer_pubkey, employee_pda, 0, PayItemKind::Bonus, 500_000, payroll_batch_pda);
+	program_test.add_account(bonus_pda, program_account(&bonus));
+
+	let (correction_pda, correction) = pay_item_state(employer_pubkey, employee_pda, 1, PayItemKind::Correction, -100_000, payroll_batch_pda);
+	program_test.add_account(correction_pda, program_account(&correction));
+
+	let (unattached_pda, unattached) = pay_item_state(employer_pubkey, employee_pda, 2, PayItemKind::Bonus, 500_000, Pubkey::default());
+	program_test.add_account(unattached_pda, program_account(&unattached));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let pay_ix = |pay_items: &[Pubkey]| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		pay_items,
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// Items must be attached to the batch being paid
+	let result = banks_client.process_transaction(pay_ix(&[bonus_pda, unattached_pda])).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(&[bonus_pda, correction_pda])).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// One hour, plus the bonus, less the correction and the earlier adjustment
+	let paid = hourly_rate + 500_000 - 100_000 - outstanding_adjustment;
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + paid);
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, paid);
+	assert_eq!(employee.outstanding_adjustment, 0);
+
+	for pay_item_pubkey in [bonus_pda, correction_pda] {
+		let pay_item_account = banks_client.get_account(pay_item_pubkey).await.unwrap().unwrap();
+		let pay_item = PayItem::try_deserialize(&mut pay_item_account.data.as_slice()).unwrap();
+		assert!(pay_item.is_paid);
+	}
+}
+
+#[tokio::test]
+async fn process_payroll_ix_expenses() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let reimbursement: u64 = 300_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+	let remittance_vault = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		remittance_vault,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let (deduction_rule_pda, deduction_rule) = deduction_rule_state(employer_pubkey, 0, DeductionKind::Percentage { bps: 1_000 }, remittance_vault);
+	program_test.add_account(deduction_rule_pda, program_account(&deduction_rule));
+	let deductions = [(deduction_rule_pda, remittance_vault)];
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.deduction_rules = vec![deduction_rule_pda];
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (approved_pda, approved) = expense_state(employer_pubkey, employee_pda, 0, reimbursement, ExpenseStatus::Approved);
+	program_test.add_account(approved_pda, program_account(&approved));
+
+	let (submitted_pda, submitted) = expense_state(employer_pubkey, employee_pda, 1, reimbursement, ExpenseStatus::Submitted);
+	program_test.add_account(submitted_pda, program_account(&submitted));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let pay_ix = |expenses: &[Pubkey]| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&deductions,
+		&[],
+		&[],
+		expenses,
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// Only approved expenses are reimbursed
+	let result = banks_client.process_transaction(pay_ix(&[approved_pda, submitted_pda])).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(&[approved_pda])).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// Withholding applies to wages only
+	let withheld = hourly_rate / 10;
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate - withheld + reimbursement);
+	assert_eq!(banks_client.get_balance(remittance_vault).await.unwrap(), 1_000_000_000 + withheld);
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, hourly_rate);
+	assert_eq!(employee.total_reimbursed, reimbursement);
+	assert_eq!(employee.ytd.gross, hourly_rate);
+
+	let expense_account = banks_client.get_account(approved_pda).await.unwrap().unwrap();
+	let expense = Expense::try_deserialize(&mut expense_account.data.as_slice()).unwrap();
+	assert_eq!(expense.status, ExpenseStatus::Paid);
+	assert_eq!(expense.batch, payroll_batch_pda);
+}
+
+#[tokio::test]
+async fn process_payroll_ix_advance_recovery() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let outstanding_advance: u64 = 300_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.outstanding_advance = outstanding_advance;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// The advance already paid out comes off the payout, not the wages
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate - outstanding_advance);
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.outstanding_advance, 0);
+	assert_eq!(employee.total_paid, hourly_rate);
+	assert_eq!(employee.ytd.net, hourly_rate);
+}
+
+#[tokio::test]
+async fn process_payroll_ix_payout_splits() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_003;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+	let hot_wallet = Pubkey::new_unique();
+	let savings_wallet = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	for wallet in [employee_wallet, hot_wallet, savings_wallet] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 1_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.payout_splits = vec![
+		PayoutSplit { wallet: hot_wallet, bps: 8_000 },
+		PayoutSplit { wallet: savings_wallet, bps: 1_500 },
+	];
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let pay_ix = |payout_splits: &[Pubkey]| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		payout_splits,
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// Split wallets out of order do not match the employee's configuration
+	let result = banks_client.process_transaction(pay_ix(&[savings_wallet, hot_wallet])).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(&[hot_wallet, savings_wallet])).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// Shares round down and the dust stays with the payout wallet
+	assert_eq!(banks_client.get_balance(hot_wallet).await.unwrap(), 1_000_000_000 + 800_002);
+	assert_eq!(banks_client.get_balance(savings_wallet).await.unwrap(), 1_000_000_000 + 150_000);
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + 50_001);
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, hourly_rate);
+	assert_eq!(employee.ytd.net, hourly_rate);
+}
+
+#[tokio::test]
+async fn process_payroll_ix_claim_mode() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payout_mode = PayoutMode::Claim;
+	employer.reversal_window = 86_400;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.payout_splits = vec![PayoutSplit { wallet: Pubkey::new_unique(), bps: 5_000 }];
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (claimable_balance_pda, claimable_balance) = claimable_balance_state(employer_pubkey, employee_pda, 0);
+	let claimable_balance_account = program_account(&claimable_balance);
+	let base_lamports = claimable_balance_account.lamports;
+	program_test.add_account(claimable_balance_pda, claimable_balance_account);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let pay_ix = |claimable_balance| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		claimable_balance,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// Without the claimable balance there is nowhere to credit the pay
+	let result = banks_client.process_transaction(pay_ix(None)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(Some(claimable_balance_pda))).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// Nothing is pushed to the payout wallet; split wallets are only needed to claim
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000);
+
+	let claimable_balance_account = banks_client.get_account(claimable_balance_pda).await.unwrap().unwrap();
+	assert_eq!(claimable_balance_account.lamports, base_lamports + hourly_rate);
+	let claimable_balance = ClaimableBalance::try_deserialize(&mut claimable_balance_account.data.as_slice()).unwrap();
+	assert_eq!(claimable_balance.amount, hourly_rate);
+
+	// Recorded so the credit can be clawed back within the reversal window
+	let work_session_account = banks_client.get_account(work_session_pubkey).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert_eq!(work_session.credited, hourly_rate);
+	assert_eq!(work_session.payment.gross, hourly_rate);
+	assert_eq!(work_session.payment.reversible_until, work_session.payment.paid_at + 86_400);
+
+	// and held from `claim` until then
+	assert_eq!(claimable_balance.locks[0], CreditLock { amount: hourly_rate, until: work_session.payment.reversible_until });
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, hourly_rate);
+	assert_eq!(employee.ytd.net, hourly_rate);
+}
+
+#[tokio::test]
+async fn process_payroll_ix_priced_rate() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	add_price_oracle(&mut program_test);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	// 30 units of the reference currency per hour, with SOL at 150.00
+	let hourly_rate: u64 = 30_000_000;
+	let price: i64 = 15_000_000_000;
+	let exponent: i32 = -8;
+	let publish_time: i64 = 1_700_000_000;
+	let rate_feed_id: [u8; 32] = [7; 32];
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let price_update_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+	let price_feed_pubkey = price_update_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.rate_price_feed = price_feed_pubkey;
+	employee.rate_feed_id = rate_feed_id;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	let ix = price_oracle_ix_interface::post_update_ix_setup(
+		&authority_keypair,
+		&price_update_keypair,
+		system_program_pubkey,
+		price_feed_message(rate_feed_id, price, 10_000_000, exponent, publish_time),
+		context.last_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let pay_ix = |price_feed, recent_blockhash| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		price_feed,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// The rate cannot be paid without its price
+	set_clock(&mut context, publish_time + 10).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(pay_ix(None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Nor at a price older than the employer allows
+	set_clock(&mut context, publish_time + DEFAULT_MAX_PRICE_AGE + 1).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(pay_ix(Some(price_feed_pubkey), recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Nor at the price of another feed posted to the same account
+	set_clock(&mut context, publish_time + 10).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let ix = price_oracle_ix_interface::update_ix_setup(
+		&authority_keypair,
+		price_feed_pubkey,
+		price_feed_message([8; 32], price / 2, 10_000_000, exponent, publish_time),
+		recent_blockhash,
+	);
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let result = context.banks_client.process_transaction(pay_ix(Some(price_feed_pubkey), recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let ix = price_oracle_ix_interface::update_ix_setup(
+		&authority_keypair,
+		price_feed_pubkey,
+		price_feed_message(rate_feed_id, price, 10_000_000, exponent, publish_time),
+		recent_blockhash,
+	);
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let result = context.banks_client.process_transaction(pay_ix(Some(price_feed_pubkey), recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// 10^9 * 10^8 / 15 * 10^9 lamports per unit, rounded down
+	let exchange_rate: u64 = 6_666_666;
+	let amount = hourly_rate * exchange_rate / 1_000_000;
+	assert_eq!(context.banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + amount);
+
+	let work_session_account = context.banks_client.get_account(work_session_pubkey).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert!(work_session.is_paid);
+	assert_eq!(work_session.exchange_rate, exchange_rate);
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, amount);
+}
+
+#[tokio::test]
+async fn process_payroll_ix_leave() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let unpaid_leave: u64 = 1_800;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.unpaid_leave = unpaid_leave;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::process_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// Half an hour of approved leave is paid with the hour worked
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate * 3 / 2);
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.unpaid_leave, 0);
+	assert_eq!(employee.total_paid, hourly_rate * 3 / 2);
+}
+
+#[tokio::test]
+async fn process_payroll_ix_department_budget() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let department_id: u64 = 7;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, payroll_manager_pubkey, batch_id)));
+	program_test.add_account(work_session_pubkey, program_account(&work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.department_id = department_id;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (department_pda, department) = department_state(employer_pubkey, department_id, hourly_rate / 2);
+	program_test.add_account(department_pda, program_account(&department));
+
+	let (other_department_pda, other_department) = department_state(employer_pubkey, department_id + 1, 0);
+	program_test.add_account(other_department_pda, program_account(&other_department));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let pay_ix = |owner: Option<&Keypair>, department: Option<Pubkey>| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&payroll_manager_keypair,
+		owner,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		department,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// The employee's department has to be passed
+	let result = banks_client.process_transaction(pay_ix(None, None)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(None, Some(other_department_pda))).await;
+	assert!(result.is_err());
+
+	// Going over the budget takes the owner's signature
+	let result = banks_client.process_transaction(pay_ix(None, Some(department_pda))).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(Some(&authority_keypair), Some(department_pda))).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate);
+
+	let department_account = banks_client.get_account(department_pda).await.unwrap().unwrap();
+	let department = Department::try_deserialize(&mut department_account.data.as_slice()).unwrap();
+	assert_eq!(department.period_spent, hourly_rate);
+	assert_eq!(department.total_spent, hourly_rate);
+}
+
+#[tokio::test]
+async fn process_payroll_ix_project_cost() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	let project_id: u64 = 3;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, payroll_manager_pubkey, batch_id)));
+	let mut work_session = work_session_state(employee_pda, authority_pubkey, 0, 0, 3_600);
+	work_session.project_id = project_id;
+	program_test.add_account(work_session_pubkey, program_account(&work_session));
+
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate)));
+
+	let (project_pda, project) = project_state(employer_pubkey, project_id, 0);
+	program_test.add_account(project_pda, program_account(&project));
+
+	let (other_project_pda, other_project) = project_state(employer_pubkey, project_id + 1, 0);
+	program_test.add_account(other_project_pda, program_account(&other_project));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let pay_ix = |project: Option<Pubkey>| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&payroll_manager_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session_pubkey,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		project,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// The session's project has to be passed
+	let result = banks_client.process_transaction(pay_ix(None)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(Some(other_project_pda))).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(Some(project_pda))).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate);
+
+	let project_account = banks_client.get_account(project_pda).await.unwrap().unwrap();
+	let project = Project::try_deserialize(&mut project_account.data.as_slice()).unwrap();
+	assert_eq!(project.labor_cost, hourly_rate);
+}
+
+#[tokio::test]
+async fn process_payroll_ix_pay_period() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let hourly_rate: u64 = 1_000_000;
+	// 2024-02-16 and 2024-03-01, midnight UTC
+	let period_start: i64 = 1_708_041_600;
+	let period_end: i64 = 1_709_251_200;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let payroll_manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let payroll_manager_pubkey = payroll_manager_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let early_work_session_pubkey = Pubkey::new_unique();
+	let work_session_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		payroll_manager_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.payroll_manager = payroll_manager_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, payroll_manager_pubkey, batch_id);
+	payroll_batch.period_start = period_start;
+	payroll_batch.period_end = period_end;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+
+	let early_work_session = work_session_state(employee_pda, authority_pubkey, 0, period_start - 1_800, period_start + 1_800);
+	program_test.add_account(early_work_session_pubkey, program_account(&early_work_session));
+	let work_session = work_session_state(employee_pda, authority_pubkey, 1, period_start, period_start + 3_600);
+	program_test.add_account(work_session_pubkey, program_account(&work_session));
+
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let pay_ix = |work_session: Pubkey| employee_payroll_ix_interface::process_payroll_ix_setup(
+		&payroll_manager_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		work_session,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// Sessions started before the period belong to an earlier batch
+	let result = banks_client.process_transaction(pay_ix(early_work_session_pubkey)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(pay_ix(work_session_pubkey)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + hourly_rate);
 }
@@ -0,0 +1,355 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		payroll_batch_state,
+		employee_state,
+		pay_item_state,
+		set_clock,
+	},
+    employee_payroll::{Compensation, Employee, PayFrequency, PayItem, PayItemKind, PayrollBatch},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn process_salary_payroll_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	// Biweekly pay from 2024-02-01
+	let period_start: i64 = 1_706_745_600;
+	// Paid after the period has ended, on the first days of the next one
+	let now: i64 = period_start + 17 * 86_400;
+	let annual_salary: u64 = 364_000_000;
+	// One of 26 biweekly periods
+	let period_pay: u64 = 14_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let hourly_employee_pubkey = Pubkey::new_unique();
+	let unscheduled_batch_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.pay_frequency = Some(PayFrequency::Biweekly);
+	employer.next_pay_period_start = period_start + 14 * 86_400;
+	// The spending-limit window does not set pay periods
+	employer.period_duration = 86_400;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut payroll_batch = payroll_batch_state(employer_pubkey, authority_pubkey, batch_id);
+	payroll_batch.period_start = period_start;
+	payroll_batch.period_end = period_start + 14 * 86_400;
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch));
+
+	program_test.add_account(unscheduled_batch_pubkey, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id + 1)));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 0);
+	employee.compensation = Compensation::Salaried { annual_salary };
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let hourly_employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000_000);
+	program_test.add_account(hourly_employee_pubkey, program_account(&hourly_employee));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, now).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let salary_ix = |payroll_batch: Pubkey, employee: Pubkey, recent_blockhash| employee_payroll_ix_interface::process_salary_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch,
+		employee,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	// Hourly employees are paid per work session instead
+	let result = context.banks_client.process_transaction(salary_ix(payroll_batch_pda, hourly_employee_pubkey, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// A salary is paid for the pay period of the batch
+	let result = context.banks_client.process_transaction(salary_ix(unscheduled_batch_pubkey, employee_pda, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = context.banks_client.process_transaction(salary_ix(payroll_batch_pda, employee_pda, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(context.banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + period_pay);
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, period_pay);
+	assert_eq!(employee.period_to_date.gross, period_pay);
+	assert_eq!(employee.last_salary_period, period_start);
+
+	let payroll_batch_account = context.banks_client.get_account(payroll_batch_pda).await.unwrap().unwrap();
+	let payroll_batch = PayrollBatch::try_deserialize(&mut payroll_batch_account.data.as_slice()).unwrap();
+	assert_eq!(payroll_batch.total_amount, period_pay);
+
+	// Only once per pay period
+	let recent_blockhash = context.banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap();
+	let result = context.banks_client.process_transaction(salary_ix(payroll_batch_pda, employee_pda, recent_blockhash)).await;
+	assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn process_salary_payroll_ix_final_settlement() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let batch_id: u64 = Default::default();
+	let timestamp: i64 = Default::default();
+	let item_id: u64 = 1;
+	let settlement: i64 = 5_400_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (payroll_batch_pda, _payroll_batch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payroll_batch",
+			employer_pubkey.as_ref(),
+			batch_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	program_test.add_account(payroll_batch_pda, program_account(&payroll_batch_state(employer_pubkey, authority_pubkey, batch_id)));
+
+	// Hourly, with no work session left to be paid alongside
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 3_600_000);
+	employee.is_active = false;
+	employee.is_terminated = true;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (final_settlement_pda, final_settlement) = pay_item_state(employer_pubkey, employee_pda, item_id, PayItemKind::FinalSettlement, settlement, payroll_batch_pda);
+	program_test.add_account(final_settlement_pda, program_account(&final_settlement));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = employee_payroll_ix_interface::process_salary_payroll_ix_setup(
+		&authority_keypair,
+		None,
+		employer_pubkey,
+		program_config_pda,
+		payroll_batch_pda,
+		employee_pda,
+		vault_pda,
+		employee_wallet,
+		None,
+		None,
+		system_program_pubkey,
+		&[],
+		&[],
+		&[final_settlement_pda],
+		&[],
+		batch_id,
+		timestamp,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(banks_client.get_balance(employee_wallet).await.unwrap(), 1_000_000_000 + settlement as u64);
+
+	let final_settlement_account = banks_client.get_account(final_settlement_pda).await.unwrap().unwrap();
+	let final_settlement = PayItem::try_deserialize(&mut final_settlement_account.data.as_slice()).unwrap();
+	assert!(final_settlement.is_paid);
+}
@@ -5,7 +5,10 @@ use {
     common::{
 		get_program_test,
 		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
 	},
+    employee_payroll::{MAX_NAME_LEN, MAX_POSITION_LEN},
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -33,8 +36,8 @@ async fn register_employee_ix_success() {
 	);
 
 	// DATA
-	let name: String = Default::default();
-	let position: String = Default::default();
+	let name: String = String::from("Alice");
+	let position: String = String::from("Engineer");
 	let hourly_rate: u64 = Default::default();
 	let employee_wallet: Pubkey = Pubkey::default();
 
@@ -60,7 +63,7 @@ async fn register_employee_ix_success() {
 	program_test.add_account(
 		authority_pubkey,
 		Account {
-			lamports: 0,
+			lamports: 1_000_000_000_000,
 			data: vec![],
 			owner: system_program::ID,
 			executable: false,
@@ -68,11 +71,16 @@ async fn register_employee_ix_success() {
 		},
 	);
 
+	// PROGRAM STATE
+	let employer_pubkey = Pubkey::new_unique();
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
 	let ix = employee_payroll_ix_interface::register_employee_ix_setup(
 		&authority_keypair,
+		employer_pubkey,
 		employee_pda,
 		system_program_pubkey,
 		&name,
@@ -88,3 +96,93 @@ async fn register_employee_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn register_employee_ix_profile_limits() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let hourly_rate: u64 = 1_000;
+	// Limits are in bytes, so two-byte characters hit them at half the length
+	let cases: Vec<(String, String, bool)> = vec![
+		("é".repeat(MAX_NAME_LEN / 2), "a".repeat(MAX_POSITION_LEN), true),
+		("a".repeat(MAX_NAME_LEN), "é".repeat(MAX_POSITION_LEN / 2), true),
+		("a".repeat(MAX_NAME_LEN + 1), String::from("Engineer"), false),
+		(format!("{}a", "é".repeat(MAX_NAME_LEN / 2)), String::from("Engineer"), false),
+		(String::from("Alice"), "a".repeat(MAX_POSITION_LEN + 1), false),
+		(String::from("   "), String::from("Engineer"), false),
+		(String::from("Ali\nce"), String::from("Engineer"), false),
+		(String::from("Alice"), String::from("Engi\u{0}neer"), false),
+	];
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let employer_pubkey = Pubkey::new_unique();
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	for (name, position, valid) in cases {
+		let employee_wallet = Pubkey::new_unique();
+		let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+			&[
+				b"employee",
+				employee_wallet.as_ref(),
+			],
+			&employee_payroll::ID,
+		);
+
+		let ix = employee_payroll_ix_interface::register_employee_ix_setup(
+			&authority_keypair,
+			employer_pubkey,
+			employee_pda,
+			system_program_pubkey,
+			&name,
+			&position,
+			hourly_rate,
+			employee_wallet,
+			recent_blockhash,
+		);
+
+		let result = banks_client.process_transaction(ix).await;
+
+		// ASSERTIONS
+		assert_eq!(result.is_ok(), valid, "name {:?}, position {:?}", name, position);
+	}
+}
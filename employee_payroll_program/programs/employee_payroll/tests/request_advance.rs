@@ -0,0 +1,364 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		employee_state,
+		work_session_state,
+		set_clock,
+		add_price_oracle,
+		price_feed_message,
+		price_oracle_ix_interface,
+	},
+    employee_payroll::Employee,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::{tokio, ProgramTestBanksClientExt},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn request_advance_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let hourly_rate: u64 = 1_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let payout_wallet = Pubkey::new_unique();
+	let work_session_pubkeys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+	let unapproved_session_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		payout_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.advance_limit_bps = 5_000;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.payout_wallet = payout_wallet;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// Two approved hours unpaid, one hour already paid and one not yet approved
+	for (session_id, work_session_pubkey) in work_session_pubkeys.iter().enumerate() {
+		let check_in_time = session_id as i64 * 3_600;
+		let mut work_session = work_session_state(employee_pda, employee_wallet, session_id as u64, check_in_time, check_in_time + 3_600);
+		work_session.is_approved = true;
+		work_session.is_paid = session_id == 2;
+		program_test.add_account(*work_session_pubkey, program_account(&work_session));
+	}
+	program_test.add_account(unapproved_session_pubkey, program_account(&work_session_state(employee_pda, employee_wallet, 3, 10_800, 14_400)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let advance_ix = |work_sessions: &[Pubkey], amount, recent_blockhash| employee_payroll_ix_interface::request_advance_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		vault_pda,
+		payout_wallet,
+		None,
+		system_program_pubkey,
+		work_sessions,
+		employee_wallet,
+		amount,
+		recent_blockhash,
+	);
+
+	// Paid sessions and repeated sessions do not count towards the earned amount
+	let result = banks_client.process_transaction(advance_ix(&work_session_pubkeys, 600_000, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(advance_ix(&[work_session_pubkeys[0], work_session_pubkeys[0]], 600_000, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Hours nobody reviewed do not back an advance
+	let result = banks_client.process_transaction(advance_ix(&[work_session_pubkeys[0], unapproved_session_pubkey], 600_000, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(advance_ix(&work_session_pubkeys[..2], 600_000, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(banks_client.get_balance(payout_wallet).await.unwrap(), 1_000_000_000 + 600_000);
+
+	// Half of the two hours earned, less what was already advanced
+	let recent_blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await.unwrap();
+	let result = banks_client.process_transaction(advance_ix(&work_session_pubkeys[..2], 400_001, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(advance_ix(&work_session_pubkeys[..2], 400_000, recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.outstanding_advance, 1_000_000);
+}
+
+#[tokio::test]
+async fn request_advance_ix_priced_rate() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	add_price_oracle(&mut program_test);
+
+	// DATA
+	// 30 units of the reference currency per hour, with SOL at 150.00
+	let hourly_rate: u64 = 30_000_000;
+	let price: i64 = 15_000_000_000;
+	let exponent: i32 = -8;
+	let publish_time: i64 = 1_700_000_000;
+	let rate_feed_id: [u8; 32] = [7; 32];
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let employee_wallet_keypair = Keypair::new();
+	let price_update_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let payout_wallet = Pubkey::new_unique();
+	let work_session_pubkeys = [Pubkey::new_unique(), Pubkey::new_unique()];
+	let price_feed_pubkey = price_update_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		payout_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.advance_limit_bps = 5_000;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.payout_wallet = payout_wallet;
+	employee.rate_price_feed = price_feed_pubkey;
+	employee.rate_feed_id = rate_feed_id;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// Two approved hours unpaid
+	for (session_id, work_session_pubkey) in work_session_pubkeys.iter().enumerate() {
+		let check_in_time = session_id as i64 * 3_600;
+		let mut work_session = work_session_state(employee_pda, employee_wallet, session_id as u64, check_in_time, check_in_time + 3_600);
+		work_session.is_approved = true;
+		program_test.add_account(*work_session_pubkey, program_account(&work_session));
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	let ix = price_oracle_ix_interface::post_update_ix_setup(
+		&authority_keypair,
+		&price_update_keypair,
+		system_program_pubkey,
+		price_feed_message(rate_feed_id, price, 10_000_000, exponent, publish_time),
+		context.last_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	set_clock(&mut context, publish_time + 10).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let advance_ix = |price_feed, amount, recent_blockhash| employee_payroll_ix_interface::request_advance_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		vault_pda,
+		payout_wallet,
+		price_feed,
+		system_program_pubkey,
+		&work_session_pubkeys,
+		employee_wallet,
+		amount,
+		recent_blockhash,
+	);
+
+	// The rate cannot be valued without its price
+	let result = context.banks_client.process_transaction(advance_ix(None, 1_000_000, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Half of two hours at 6_666_666 lamports per 10^6 units
+	let limit: u64 = 2 * hourly_rate * 6_666_666 / 1_000_000 / 2;
+	let result = context.banks_client.process_transaction(advance_ix(Some(price_feed_pubkey), limit + 1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = context.banks_client.process_transaction(advance_ix(Some(price_feed_pubkey), limit, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(context.banks_client.get_balance(payout_wallet).await.unwrap(), 1_000_000_000 + limit);
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.outstanding_advance, limit);
+}
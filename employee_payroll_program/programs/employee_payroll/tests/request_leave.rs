@@ -0,0 +1,136 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employee_state,
+	},
+    employee_payroll::{Employee, LeaveRequest, LeaveStatus},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn request_leave_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let request_id: u64 = 1;
+	let start_time: i64 = 1_700_000_000;
+	let pto_balance: u64 = 8 * 3_600;
+
+	// KEYPAIR
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let leave_request_pda = |request_id: u64| Pubkey::find_program_address(
+		&[
+			b"leave_request",
+			employee_pda.as_ref(),
+			request_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employee = employee_state(employer_pubkey, Pubkey::new_unique(), employee_wallet, 1_000);
+	employee.pto_balance = pto_balance;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	// No more leave than accrued
+	let ix = employee_payroll_ix_interface::request_leave_ix_setup(
+		&employee_wallet_keypair,
+		employee_pda,
+		leave_request_pda(request_id),
+		system_program_pubkey,
+		employee_wallet,
+		request_id,
+		start_time,
+		pto_balance + 1,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::request_leave_ix_setup(
+		&employee_wallet_keypair,
+		employee_pda,
+		leave_request_pda(request_id),
+		system_program_pubkey,
+		employee_wallet,
+		request_id,
+		start_time,
+		pto_balance,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let leave_request_account = banks_client.get_account(leave_request_pda(request_id)).await.unwrap().unwrap();
+	let leave_request = LeaveRequest::try_deserialize(&mut leave_request_account.data.as_slice()).unwrap();
+	assert_eq!(leave_request.employee, employee_pda);
+	assert_eq!(leave_request.start_time, start_time);
+	assert_eq!(leave_request.duration, pto_balance);
+	assert_eq!(leave_request.status, LeaveStatus::Requested);
+
+	// The balance is only taken on approval
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.pto_balance, pto_balance);
+}
@@ -0,0 +1,110 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		pay_stream_state,
+		set_clock,
+	},
+    employee_payroll::PayStream,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn resume_pay_stream_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let employee_wallet: Pubkey = Pubkey::new_unique();
+	let rate_per_second: u64 = 1_000;
+	let started_at: i64 = 1_800_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 0)));
+
+	let (pay_stream_pda, mut pay_stream) = pay_stream_state(employer_pubkey, employee_pda, rate_per_second, started_at);
+	pay_stream.is_paused = true;
+	pay_stream.accrued = 200 * rate_per_second;
+	pay_stream.last_accrual_at = started_at + 200;
+	program_test.add_account(pay_stream_pda, program_account(&pay_stream));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, started_at + 500).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = employee_payroll_ix_interface::resume_pay_stream_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		pay_stream_pda,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let pay_stream_account = context.banks_client.get_account(pay_stream_pda).await.unwrap().unwrap();
+	let pay_stream = PayStream::try_deserialize(&mut pay_stream_account.data.as_slice()).unwrap();
+	assert!(!pay_stream.is_paused);
+	// Time spent paused does not accrue
+	assert_eq!(pay_stream.accrued, 200 * rate_per_second);
+	assert_eq!(pay_stream.last_accrual_at, started_at + 500);
+}
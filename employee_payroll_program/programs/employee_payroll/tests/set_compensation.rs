@@ -0,0 +1,121 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+	},
+    employee_payroll::{Compensation, Employee, PayFrequency},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn set_compensation_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let period_start: i64 = 1_706_745_600;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let rate_approver_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let rate_approver_pubkey = rate_approver_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.pay_frequency = Some(PayFrequency::Biweekly);
+	employer.next_pay_period_start = period_start;
+	employer.rate_approver = rate_approver_pubkey;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000_000);
+	employee.compensation = Compensation::ContractorFixed { period_amount: 1_000_000 };
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let compensation_ix = |compensation, rate_approver, recent_blockhash| employee_payroll_ix_interface::set_compensation_ix_setup(
+		&authority_keypair,
+		rate_approver,
+		employer_pubkey,
+		employee_pda,
+		employee_wallet,
+		compensation,
+		recent_blockhash,
+	);
+
+	// Within the default 10% threshold
+	let compensation = Compensation::ContractorFixed { period_amount: 1_100_000 };
+	let result = banks_client.process_transaction(compensation_ix(compensation, None, recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	// but another small raise right after it would compound past the threshold
+	let compensation = Compensation::ContractorFixed { period_amount: 1_200_000 };
+	let result = banks_client.process_transaction(compensation_ix(compensation, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Moving to a salary worth more per period needs the rate approver
+	let salaried = Compensation::Salaried { annual_salary: 365_000_000 };
+	let result = banks_client.process_transaction(compensation_ix(salaried, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(compensation_ix(salaried, Some(&rate_approver_keypair), recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.compensation, salaried);
+}
@@ -0,0 +1,147 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+	},
+    employee_payroll::{Employee, PayoutSplit},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn set_payout_splits_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let hot_wallet: Pubkey = Pubkey::new_unique();
+	let savings_wallet: Pubkey = Pubkey::new_unique();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = employee_wallet_keypair.pubkey();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let employer_pubkey = Pubkey::new_unique();
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+	program_test.add_account(
+		employee_pda,
+		program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000)),
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let payout_splits = [
+		PayoutSplit { wallet: hot_wallet, bps: 8_000 },
+		PayoutSplit { wallet: savings_wallet, bps: 1_500 },
+	];
+	let ix = employee_payroll_ix_interface::set_payout_splits_ix_setup(
+		&employee_wallet_keypair,
+		employee_pda,
+		employee_wallet,
+		&payout_splits,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.payout_splits, payout_splits);
+
+	// Shares above 100%, duplicate wallets and the employer signing are all rejected
+	let rejected = [
+		(&employee_wallet_keypair, vec![
+			PayoutSplit { wallet: hot_wallet, bps: 8_000 },
+			PayoutSplit { wallet: savings_wallet, bps: 2_001 },
+		]),
+		(&employee_wallet_keypair, vec![
+			PayoutSplit { wallet: hot_wallet, bps: 1_000 },
+			PayoutSplit { wallet: hot_wallet, bps: 1_000 },
+		]),
+		(&authority_keypair, vec![
+			PayoutSplit { wallet: hot_wallet, bps: 1_000 },
+		]),
+	];
+	for (signer, payout_splits) in rejected {
+		let ix = employee_payroll_ix_interface::set_payout_splits_ix_setup(
+			signer,
+			employee_pda,
+			employee_wallet,
+			&payout_splits,
+			recent_blockhash,
+		);
+
+		let result = banks_client.process_transaction(ix).await;
+		assert!(result.is_err());
+	}
+
+	// Clearing the splits sends everything back to the payout wallet
+	let ix = employee_payroll_ix_interface::set_payout_splits_ix_setup(
+		&employee_wallet_keypair,
+		employee_pda,
+		employee_wallet,
+		&[],
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert!(employee.payout_splits.is_empty());
+}
@@ -0,0 +1,168 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		employee_state,
+		add_price_oracle,
+		price_feed_message,
+		price_oracle_ix_interface,
+	},
+    employee_payroll::Employee,
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn set_rate_currency_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	add_price_oracle(&mut program_test);
+
+	// DATA
+	let hourly_rate: u64 = 30_000_000;
+	let rate_feed_id: [u8; 32] = [7; 32];
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let rate_approver_keypair = Keypair::new();
+	let price_update_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+	let rate_price_feed = price_update_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.rate_approver = rate_approver_keypair.pubkey();
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 1_000);
+	employee.pending_hourly_rate = 2_000;
+	employee.pending_rate_effective_at = 1_700_000_000;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = price_oracle_ix_interface::post_update_ix_setup(
+		&authority_keypair,
+		&price_update_keypair,
+		system_program_pubkey,
+		price_feed_message(rate_feed_id, 15_000_000_000, 10_000_000, -8, 1_700_000_000),
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	// The employer cannot move the rate without the rate approver
+	let ix = employee_payroll_ix_interface::set_rate_currency_ix_setup(
+		&authority_keypair,
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		employee_wallet,
+		Some(rate_price_feed),
+		rate_price_feed,
+		rate_feed_id,
+		hourly_rate,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	// Nor to a feed id the price account does not carry
+	let ix = employee_payroll_ix_interface::set_rate_currency_ix_setup(
+		&authority_keypair,
+		&rate_approver_keypair,
+		employer_pubkey,
+		employee_pda,
+		employee_wallet,
+		Some(rate_price_feed),
+		rate_price_feed,
+		[8; 32],
+		hourly_rate,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let ix = employee_payroll_ix_interface::set_rate_currency_ix_setup(
+		&authority_keypair,
+		&rate_approver_keypair,
+		employer_pubkey,
+		employee_pda,
+		employee_wallet,
+		Some(rate_price_feed),
+		rate_price_feed,
+		rate_feed_id,
+		hourly_rate,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.rate_price_feed, rate_price_feed);
+	assert_eq!(employee.rate_feed_id, rate_feed_id);
+	assert_eq!(employee.hourly_rate, hourly_rate);
+	// The pending increase was in lamports and no longer applies
+	assert_eq!(employee.pending_hourly_rate, 0);
+	assert_eq!(employee.pending_rate_effective_at, 0);
+}
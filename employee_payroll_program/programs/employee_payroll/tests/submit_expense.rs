@@ -0,0 +1,120 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employee_state,
+	},
+    employee_payroll::{Expense, ExpenseCategory, ExpenseStatus},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn submit_expense_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let expense_id: u64 = 1;
+	let amount: u64 = 420_000;
+	let receipt_hash: [u8; 32] = [3; 32];
+
+	// KEYPAIR
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (expense_pda, _expense_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"expense",
+			employee_pda.as_ref(),
+			expense_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, Pubkey::new_unique(), employee_wallet, 1_000_000)));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let expense_ix = |amount| employee_payroll_ix_interface::submit_expense_ix_setup(
+		&employee_wallet_keypair,
+		employee_pda,
+		expense_pda,
+		system_program_pubkey,
+		employee_wallet,
+		expense_id,
+		amount,
+		ExpenseCategory::Equipment,
+		receipt_hash,
+		recent_blockhash,
+	);
+
+	// Zero amounts are rejected
+	let result = banks_client.process_transaction(expense_ix(0)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(expense_ix(amount)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let expense_account = banks_client.get_account(expense_pda).await.unwrap().unwrap();
+	let expense = Expense::try_deserialize(&mut expense_account.data.as_slice()).unwrap();
+	assert_eq!(expense.employer, employer_pubkey);
+	assert_eq!(expense.employee, employee_pda);
+	assert_eq!(expense.category, ExpenseCategory::Equipment);
+	assert_eq!(expense.amount, amount);
+	assert_eq!(expense.receipt_hash, receipt_hash);
+	assert_eq!(expense.status, ExpenseStatus::Submitted);
+}
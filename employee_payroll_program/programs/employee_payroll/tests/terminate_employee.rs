@@ -0,0 +1,417 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		set_clock,
+		employer_state,
+		employee_state,
+		work_session_state,
+		pay_stream_state,
+	},
+    employee_payroll::{Compensation, Employee, PayFrequency, PayItem, PayItemKind, PayStream, WorkSession},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn terminate_employee_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let session_id: u64 = 1;
+	let item_id: u64 = 1;
+	let now: i64 = 1_700_000_000;
+	// A thousand lamports a second
+	let hourly_rate: u64 = 3_600_000;
+	let rate_per_second: u64 = 10;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (work_session_pda, _work_session_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"work_session",
+			employee_wallet.as_ref(),
+			session_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (final_settlement_pda, _final_settlement_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pay_item",
+			employee_pda.as_ref(),
+			item_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.pto_payout_on_termination = true;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate);
+	employee.pto_balance = 1_800;
+	employee.unpaid_leave = 3_600;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	program_test.add_account(
+		work_session_pda,
+		program_account(&work_session_state(employee_pda, employee_wallet, session_id, now - 7_200, 0)),
+	);
+
+	let (pay_stream_pda, pay_stream) = pay_stream_state(employer_pubkey, employee_pda, rate_per_second, now - 100);
+	program_test.add_account(pay_stream_pda, program_account(&pay_stream));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, now).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let terminate_ix = |item_id: u64, recent_blockhash| employee_payroll_ix_interface::terminate_employee_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		Some(work_session_pda),
+		None,
+		None,
+		pay_stream_pda,
+		Pubkey::find_program_address(
+			&[b"pay_item", employee_pda.as_ref(), item_id.to_le_bytes().as_ref()],
+			&employee_payroll::ID,
+		).0,
+		system_program_pubkey,
+		employee_wallet,
+		item_id,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(terminate_ix(item_id, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// The open session is checked out at termination
+	let work_session_account = context.banks_client.get_account(work_session_pda).await.unwrap().unwrap();
+	let work_session = WorkSession::try_deserialize(&mut work_session_account.data.as_slice()).unwrap();
+	assert_eq!(work_session.check_out_time, now);
+	assert_eq!(work_session.duration, 7_200);
+
+	// So is the pay stream, keeping what accrued until now withdrawable
+	let pay_stream_account = context.banks_client.get_account(pay_stream_pda).await.unwrap().unwrap();
+	let pay_stream = PayStream::try_deserialize(&mut pay_stream_account.data.as_slice()).unwrap();
+	assert!(pay_stream.is_cancelled);
+	assert_eq!(pay_stream.accrued, 100 * rate_per_second);
+	assert_eq!(pay_stream.last_accrual_at, now);
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert!(employee.is_terminated);
+	assert!(!employee.is_active);
+	assert_eq!(employee.total_hours_worked, 7_200);
+	assert_eq!(employee.pto_balance, 0);
+	assert_eq!(employee.unpaid_leave, 0);
+
+	// Approved leave and the remaining balance, at the hourly rate
+	let final_settlement_account = context.banks_client.get_account(final_settlement_pda).await.unwrap().unwrap();
+	let final_settlement = PayItem::try_deserialize(&mut final_settlement_account.data.as_slice()).unwrap();
+	assert_eq!(final_settlement.kind, PayItemKind::FinalSettlement);
+	assert_eq!(final_settlement.amount, 5_400_000);
+	assert!(!final_settlement.is_paid);
+
+	// Only once
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(terminate_ix(item_id + 1, recent_blockhash)).await;
+	assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn terminate_employee_ix_prorates_salary() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let item_id: u64 = 1;
+	// Monthly pay on the 1st; February 2024 has 29 days
+	let period_start: i64 = 1_706_745_600;
+	let now: i64 = period_start + 7 * 86_400;
+	// One of twelve monthly periods pays 29_000
+	let annual_salary: u64 = 12 * 29 * 1_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (final_settlement_pda, _final_settlement_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pay_item",
+			employee_pda.as_ref(),
+			item_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.pay_frequency = Some(PayFrequency::Monthly);
+	employer.next_pay_period_start = period_start + 29 * 86_400;
+	// The spending-limit window does not set pay periods
+	employer.period_duration = 86_400;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 0);
+	employee.compensation = Compensation::Salaried { annual_salary };
+	employee.pto_balance = 1_800;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, now).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = employee_payroll_ix_interface::terminate_employee_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		None,
+		None,
+		None,
+		Pubkey::find_program_address(&[b"pay_stream", employee_pda.as_ref()], &employee_payroll::ID).0,
+		final_settlement_pda,
+		system_program_pubkey,
+		employee_wallet,
+		item_id,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// Seven of the period's 29 days had been worked; leave is kept as the
+	// employer does not pay it out
+	let final_settlement_account = context.banks_client.get_account(final_settlement_pda).await.unwrap().unwrap();
+	let final_settlement = PayItem::try_deserialize(&mut final_settlement_account.data.as_slice()).unwrap();
+	assert_eq!(final_settlement.amount, 7_000);
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.pto_balance, 1_800);
+	assert_eq!(employee.last_salary_period, period_start);
+}
+
+#[tokio::test]
+async fn terminate_employee_ix_takes_back_paid_salary() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let item_id: u64 = 1;
+	// Monthly pay on the 1st; February 2024 has 29 days
+	let period_start: i64 = 1_706_745_600;
+	let now: i64 = period_start + 7 * 86_400;
+	// One of twelve monthly periods pays 29_000
+	let annual_salary: u64 = 12 * 29 * 1_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (final_settlement_pda, _final_settlement_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pay_item",
+			employee_pda.as_ref(),
+			item_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let mut employer = employer_state(authority_pubkey);
+	employer.pay_frequency = Some(PayFrequency::Monthly);
+	employer.next_pay_period_start = period_start + 29 * 86_400;
+	// The spending-limit window does not set pay periods
+	employer.period_duration = 86_400;
+	program_test.add_account(employer_pubkey, program_account(&employer));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 0);
+	employee.compensation = Compensation::Salaried { annual_salary };
+	employee.pto_balance = 1_800;
+	// The whole period was paid on its first day
+	employee.last_salary_period = period_start;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	set_clock(&mut context, now).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = employee_payroll_ix_interface::terminate_employee_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		None,
+		None,
+		None,
+		Pubkey::find_program_address(&[b"pay_stream", employee_pda.as_ref()], &employee_payroll::ID).0,
+		final_settlement_pda,
+		system_program_pubkey,
+		employee_wallet,
+		item_id,
+		recent_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// The 22 days left of the period are recovered from the final settlement
+	let final_settlement_account = context.banks_client.get_account(final_settlement_pda).await.unwrap().unwrap();
+	let final_settlement = PayItem::try_deserialize(&mut final_settlement_account.data.as_slice()).unwrap();
+	assert_eq!(final_settlement.amount, -22_000);
+	assert!(!final_settlement.is_paid);
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.pto_balance, 1_800);
+	assert_eq!(employee.last_salary_period, period_start);
+}
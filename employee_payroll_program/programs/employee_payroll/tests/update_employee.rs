@@ -5,7 +5,13 @@ use {
     common::{
 		get_program_test,
 		employee_payroll_ix_interface,
+		program_account,
+		set_clock,
+		employer_state,
+		employee_state,
 	},
+    employee_payroll::Employee,
+    anchor_lang::AccountDeserialize,
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -33,17 +39,18 @@ async fn update_employee_ix_success() {
 	);
 
 	// DATA
-	let name: String = Default::default();
-	let position: String = Default::default();
-	let hourly_rate: u64 = Default::default();
-	let is_active: bool = Default::default();
-	let employee_wallet: Pubkey = Pubkey::default();
+	let name: String = String::from("Alice");
+	let position: String = String::from("Senior Engineer");
+	let hourly_rate: u64 = 1_000;
+	let is_active: bool = true;
+	let employee_wallet: Pubkey = Pubkey::new_unique();
 
 	// KEYPAIR
 	let authority_keypair = Keypair::new();
 
 	// PUBKEY
 	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
 
 	// PDA
 	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
@@ -66,15 +73,21 @@ async fn update_employee_ix_success() {
 		},
 	);
 
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate)));
+
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
+	// A 100x raise is queued behind the timelock instead of applied
 	let ix = employee_payroll_ix_interface::update_employee_ix_setup(
 		&authority_keypair,
+		employer_pubkey,
 		employee_pda,
 		&name,
 		&position,
-		hourly_rate,
+		hourly_rate * 100,
 		is_active,
 		employee_wallet,
 		recent_blockhash,
@@ -85,4 +98,105 @@ async fn update_employee_ix_success() {
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	let employee_account = banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.hourly_rate, hourly_rate);
+	assert_eq!(employee.pending_hourly_rate, hourly_rate * 100);
+	assert!(employee.pending_rate_effective_at > 0);
+	assert_eq!(employee.position, position);
+}
+
+#[tokio::test]
+async fn update_employee_ix_raise_cooldown() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name: String = String::from("Alice");
+	let position: String = String::from("Engineer");
+	let hourly_rate: u64 = 1_000;
+	let now: i64 = 1_700_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let employee_wallet = Pubkey::new_unique();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	let employer = employer_state(authority_pubkey);
+	program_test.add_account(employer_pubkey, program_account(&employer));
+	program_test.add_account(employee_pda, program_account(&employee_state(employer_pubkey, authority_pubkey, employee_wallet, hourly_rate)));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	let update_ix = |hourly_rate, recent_blockhash| employee_payroll_ix_interface::update_employee_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		&name,
+		&position,
+		hourly_rate,
+		true,
+		employee_wallet,
+		recent_blockhash,
+	);
+
+	set_clock(&mut context, now).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	// A raise within the threshold applies at once
+	let result = context.banks_client.process_transaction(update_ix(1_050, recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	// Another one inside the delay is queued, however small
+	let result = context.banks_client.process_transaction(update_ix(1_100, recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.hourly_rate, 1_050);
+	assert_eq!(employee.pending_hourly_rate, 1_100);
+	assert_eq!(employee.pending_rate_effective_at, now + employer.rate_increase_delay);
+	assert_eq!(employee.last_raise_at, now);
+
+	set_clock(&mut context, now + employer.rate_increase_delay).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = context.banks_client.process_transaction(update_ix(1_150, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.hourly_rate, 1_150);
+	assert_eq!(employee.pending_rate_effective_at, 0);
+	assert_eq!(employee.last_raise_at, now + employer.rate_increase_delay);
 }
@@ -0,0 +1,262 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		employee_payroll_ix_interface,
+		program_account,
+		employer_state,
+		program_config_state,
+		employee_state,
+		pay_stream_state,
+		set_clock,
+	},
+    employee_payroll::{Employee, PayStream},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+
+#[tokio::test]
+async fn withdraw_streamed_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	program_test.add_program(
+		"account_compression",
+		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+		None,
+	);
+
+	program_test.add_program(
+		"noop",
+		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+		None,
+	);
+
+	// DATA
+	let rate_per_second: u64 = 1_000;
+	let started_at: i64 = 1_800_000_000;
+	let item_id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let employee_wallet_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let employee_wallet = employee_wallet_keypair.pubkey();
+	let employer_pubkey = Pubkey::new_unique();
+	let payout_wallet = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (employee_pda, _employee_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"employee",
+			employee_wallet.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (vault_pda, _vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vault",
+			employer_pubkey.as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	let (final_settlement_pda, _final_settlement_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pay_item",
+			employee_pda.as_ref(),
+			item_id.to_le_bytes().as_ref(),
+		],
+		&employee_payroll::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		employee_wallet,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		payout_wallet,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// PROGRAM STATE
+	program_test.add_account(employer_pubkey, program_account(&employer_state(authority_pubkey)));
+
+	let (program_config_pda, program_config) = program_config_state(Pubkey::new_unique());
+	program_test.add_account(program_config_pda, program_account(&program_config));
+
+	let mut employee = employee_state(employer_pubkey, authority_pubkey, employee_wallet, 0);
+	employee.payout_wallet = payout_wallet;
+	program_test.add_account(employee_pda, program_account(&employee));
+
+	let (pay_stream_pda, pay_stream) = pay_stream_state(employer_pubkey, employee_pda, rate_per_second, started_at);
+	program_test.add_account(pay_stream_pda, program_account(&pay_stream));
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	let withdraw_ix = |amount, recent_blockhash| employee_payroll_ix_interface::withdraw_streamed_ix_setup(
+		&employee_wallet_keypair,
+		employer_pubkey,
+		program_config_pda,
+		employee_pda,
+		pay_stream_pda,
+		vault_pda,
+		payout_wallet,
+		system_program_pubkey,
+		&[],
+		employee_wallet,
+		amount,
+		recent_blockhash,
+	);
+
+	// 100 seconds in, no more than has accrued, in as many parts as the employee likes
+	set_clock(&mut context, started_at + 100).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(withdraw_ix(100 * rate_per_second + 1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = context.banks_client.process_transaction(withdraw_ix(0, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = context.banks_client.process_transaction(withdraw_ix(60 * rate_per_second, recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	let result = context.banks_client.process_transaction(withdraw_ix(40 * rate_per_second, recent_blockhash)).await;
+	assert!(result.is_ok());
+	assert_eq!(context.banks_client.get_balance(payout_wallet).await.unwrap(), 1_000_000_000 + 100 * rate_per_second);
+
+	// Nothing more accrues without time passing, nor when the clock goes back
+	set_clock(&mut context, started_at + 40).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(withdraw_ix(rate_per_second, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Paused 250 seconds in and resumed at 1_000
+	set_clock(&mut context, started_at + 250).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let ix = employee_payroll_ix_interface::pause_pay_stream_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		pay_stream_pda,
+		employee_wallet,
+		recent_blockhash,
+	);
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	set_clock(&mut context, started_at + 1_000).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let ix = employee_payroll_ix_interface::resume_pay_stream_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		pay_stream_pda,
+		employee_wallet,
+		recent_blockhash,
+	);
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	set_clock(&mut context, started_at + 1_060).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(withdraw_ix(210 * rate_per_second, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// 100 + 150 before the pause + 60 after resuming
+	let streamed = 310 * rate_per_second;
+	assert_eq!(context.banks_client.get_balance(payout_wallet).await.unwrap(), 1_000_000_000 + streamed);
+
+	let pay_stream_account = context.banks_client.get_account(pay_stream_pda).await.unwrap().unwrap();
+	let pay_stream = PayStream::try_deserialize(&mut pay_stream_account.data.as_slice()).unwrap();
+	assert_eq!(pay_stream.accrued, 0);
+	assert_eq!(pay_stream.total_withdrawn, streamed);
+	assert_eq!(pay_stream.last_accrual_at, started_at + 1_060);
+
+	let employee_account = context.banks_client.get_account(employee_pda).await.unwrap().unwrap();
+	let employee = Employee::try_deserialize(&mut employee_account.data.as_slice()).unwrap();
+	assert_eq!(employee.total_paid, streamed);
+	assert_eq!(employee.ytd.gross, streamed);
+
+	// Terminated 40 seconds later, the stream ends with the employment
+	set_clock(&mut context, started_at + 1_100).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let ix = employee_payroll_ix_interface::terminate_employee_ix_setup(
+		&authority_keypair,
+		employer_pubkey,
+		employee_pda,
+		None,
+		None,
+		None,
+		pay_stream_pda,
+		final_settlement_pda,
+		system_program_pubkey,
+		employee_wallet,
+		item_id,
+		recent_blockhash,
+	);
+	let result = context.banks_client.process_transaction(ix).await;
+	assert!(result.is_ok());
+
+	set_clock(&mut context, started_at + 2_000).await;
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = context.banks_client.process_transaction(withdraw_ix(40 * rate_per_second, recent_blockhash)).await;
+	assert!(result.is_ok());
+	assert_eq!(
+		context.banks_client.get_balance(payout_wallet).await.unwrap(),
+		1_000_000_000 + streamed + 40 * rate_per_second,
+	);
+}
@@ -0,0 +1,88 @@
+//! Local stand-in for the Pyth Solana receiver. It owns `PriceUpdateV2`
+//! accounts laid out like the receiver's, so payroll can be tested against
+//! prices without the real program. Anyone can post, as on a test validator.
+#![allow(deprecated)]
+
+use anchor_lang::prelude::*;
+
+declare_id!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+#[program]
+pub mod price_oracle {
+	use super::*;
+
+	/// Create a fully verified price update in a new account
+	pub fn post_update(ctx: Context<PostUpdate>, price_message: PriceFeedMessage) -> Result<()> {
+		let price_update = &mut ctx.accounts.price_update;
+		price_update.write_authority = ctx.accounts.write_authority.key();
+		price_update.verification_level = VerificationLevel::Full;
+		price_update.price_message = price_message;
+		price_update.posted_slot = Clock::get()?.slot;
+
+		Ok(())
+	}
+
+	/// Overwrite a price update, with a message for any feed, as its write
+	/// authority can on the receiver
+	pub fn update(ctx: Context<Update>, price_message: PriceFeedMessage) -> Result<()> {
+		let price_update = &mut ctx.accounts.price_update;
+		price_update.price_message = price_message;
+		price_update.posted_slot = Clock::get()?.slot;
+
+		Ok(())
+	}
+}
+
+#[derive(Accounts)]
+pub struct PostUpdate<'info> {
+	#[account(mut)]
+	pub write_authority: Signer<'info>,
+
+	#[account(
+		init,
+		space = 8 + PriceUpdateV2::INIT_SPACE,
+		payer = write_authority,
+	)]
+	pub price_update: Account<'info, PriceUpdateV2>,
+
+	pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Update<'info> {
+	pub write_authority: Signer<'info>,
+
+	#[account(
+		mut,
+		has_one = write_authority,
+	)]
+	pub price_update: Account<'info, PriceUpdateV2>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationLevel {
+	Partial { num_signatures: u8 },
+	Full,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, PartialEq, Eq)]
+pub struct PriceFeedMessage {
+	pub feed_id: [u8; 32],
+	pub price: i64,
+	pub conf: u64,
+	pub exponent: i32,
+	pub publish_time: i64,
+	pub prev_publish_time: i64,
+	pub ema_price: i64,
+	pub ema_conf: u64,
+}
+
+/// Named as on the receiver, so the account discriminator is the same.
+#[account]
+#[derive(InitSpace)]
+pub struct PriceUpdateV2 {
+	pub write_authority: Pubkey,
+	pub verification_level: VerificationLevel,
+	pub price_message: PriceFeedMessage,
+	pub posted_slot: u64,
+}